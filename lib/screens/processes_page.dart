@@ -272,16 +272,16 @@ class _ProcessesPageState extends State<ProcessesPage> {
           TextButton(
             onPressed: () {
               Navigator.pop(context);
-              final success = killProcess(pid: process.pid);
-              if (success) {
+              try {
+                killProcess(pid: process.pid);
                 ScaffoldMessenger.of(
                   context,
                 ).showSnackBar(const SnackBar(content: Text('进程已结束')));
                 _loadProcesses();
-              } else {
+              } on ProcessControlError catch (e) {
                 ScaffoldMessenger.of(
                   context,
-                ).showSnackBar(const SnackBar(content: Text('结束进程失败')));
+                ).showSnackBar(SnackBar(content: Text('结束进程失败: ${e.message}')));
               }
             },
             child: const Text('结束'),
@@ -6,7 +6,8 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `from_nice`, `label`, `new`, `nice`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 String greet({required String name}) =>
     RustLib.instance.api.crateApiSimpleGreet(name: name);
@@ -19,82 +20,1539 @@ String getBackendVersion() =>
 List<ProcessInfo> getProcesses() =>
     RustLib.instance.api.crateApiSimpleGetProcesses();
 
+/// Get capabilities, seccomp mode and LSM label of a process
+SecurityContext getSecurityContext({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetSecurityContext(pid: pid);
+
+/// Choose how `ProcessInfo.cpu_usage` is normalised on subsequent calls
+void setCpuUsageMode({required CpuUsageMode mode}) =>
+    RustLib.instance.api.crateApiSimpleSetCpuUsageMode(mode: mode);
+
+/// Choose what `ProcessInfo.memory_usage` reports on subsequent calls. Pss and Uss
+/// read smaps_rollup for every process, which costs noticeably more than Rss; each
+/// process is re-read at most every 5 seconds.
+void setMemoryMetric({required MemoryMetric metric}) =>
+    RustLib.instance.api.crateApiSimpleSetMemoryMetric(metric: metric);
+
+/// Full memory breakdown of one process, read fresh on every call (Linux only).
+/// Reading another user's process needs root.
+ProcessMemory getProcessMemory({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetProcessMemory(pid: pid);
+
+/// Memory mappings of a process with per-region and per-file usage, like `pmap -X`
+/// (Linux only). Reads /proc/<pid>/smaps, so call it for one process at a time.
+MemoryMaps getMemoryMaps({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetMemoryMaps(pid: pid);
+
+/// Executable, working and root directory, argv and environment of a process. Fails
+/// only if the process does not exist; fields that cannot be read (usually the
+/// environment of another user's process) are reported in `errors`.
+ProcessDetails getProcessDetails({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetProcessDetails(pid: pid);
+
 /// Get system resource usage
 SystemResourceInfo getSystemResources() =>
     RustLib.instance.api.crateApiSimpleGetSystemResources();
 
-/// Get system information
-SystemInfo getSystemInfo() =>
-    RustLib.instance.api.crateApiSimpleGetSystemInfo();
+/// Get mounted filesystems, filtered by `filter`.
+/// `get_system_resources` uses the default filter.
+List<DiskInfo> getDisks({required MountFilter filter}) =>
+    RustLib.instance.api.crateApiSimpleGetDisks(filter: filter);
+
+/// Get per-device disk throughput, IOPS, latency and utilization
+List<DiskActivity> getDiskActivity() =>
+    RustLib.instance.api.crateApiSimpleGetDiskActivity();
+
+/// Get per-interface network statistics
+List<NetworkInterfaceInfo> getNetworkInterfaces() =>
+    RustLib.instance.api.crateApiSimpleGetNetworkInterfaces();
+
+/// Get every cgroup with its CPU, memory, I/O and pressure accounting (Linux only)
+List<CgroupInfo> getCgroupTree() =>
+    RustLib.instance.api.crateApiSimpleGetCgroupTree();
+
+/// Get system information
+SystemInfo getSystemInfo() =>
+    RustLib.instance.api.crateApiSimpleGetSystemInfo();
+
+/// Get all TCP/UDP sockets with their owning processes
+Future<List<ConnectionInfo>> getConnections() =>
+    RustLib.instance.api.crateApiSimpleGetConnections();
+
+/// Name of the user this app runs as, in the form used by `ProcessInfo.user`
+String getCurrentUser() => RustLib.instance.api.crateApiSimpleGetCurrentUser();
+
+/// Get per-process TCP traffic, for finding what is saturating the link
+Future<List<ProcessNetworkUsage>> getProcessNetworkUsage() =>
+    RustLib.instance.api.crateApiSimpleGetProcessNetworkUsage();
+
+/// Kill a process by PID
+void killProcess({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleKillProcess(pid: pid);
+
+/// Send a signal to a process
+void sendSignal({required int pid, required ProcessSignal signal}) =>
+    RustLib.instance.api.crateApiSimpleSendSignal(pid: pid, signal: signal);
+
+/// Kill a process and all of its descendants. The tree is stopped first so nothing
+/// can fork while it is collected, and re-scanned until no new children show up.
+/// Fails only if the root itself cannot be targeted; per-process results are reported.
+Future<List<ProcessKillResult>> killProcessTree({
+  required int rootPid,
+  required KillTreeStrategy strategy,
+}) => RustLib.instance.api.crateApiSimpleKillProcessTree(
+  rootPid: rootPid,
+  strategy: strategy,
+);
+
+/// Scheduling and I/O priority of a process
+ProcessPriority getPriority({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetPriority(pid: pid);
+
+/// Change the scheduling priority of a process (all of its threads on Linux).
+/// Raising it above Normal usually needs root / administrator rights.
+void setPriority({required int pid, required PriorityLevel level}) =>
+    RustLib.instance.api.crateApiSimpleSetPriority(pid: pid, level: level);
+
+/// Change the I/O priority of a process. Linux only; the Realtime class needs root.
+void setIoPriority({required int pid, required IoPriority priority}) =>
+    RustLib.instance.api.crateApiSimpleSetIoPriority(
+      pid: pid,
+      priority: priority,
+    );
+
+/// Threads of a process. CPU usage is the delta since the previous call for the same
+/// process, so poll this like `get_processes`. Not available on macOS.
+List<ThreadInfo> getThreads({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetThreads(pid: pid);
+
+/// Open file descriptors of a process, like `lsof -p` (Linux only). Reading another
+/// user's descriptors needs root.
+List<OpenFileInfo> getOpenFiles({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetOpenFiles(pid: pid);
+
+/// Processes using a file, directory or mount point through open descriptors, their
+/// working or root directory, executable or memory mappings, like `fuser -v` (Linux only).
+/// A directory matches everything below it; a mount point everything on that filesystem.
+Future<List<PathUsage>> findProcessesUsing({required String path}) =>
+    RustLib.instance.api.crateApiSimpleFindProcessesUsing(path: path);
+
+/// CPU ids a process may run on. On Linux `pid` may also be a thread id.
+Uint32List getCpuAffinity({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetCpuAffinity(pid: pid);
+
+/// Restrict a process to the given CPU ids. With `all_threads` every existing
+/// thread is pinned too; otherwise only the thread `pid` (and threads it creates later).
+/// Windows always applies the mask to the whole process and supports CPUs 0-63.
+void setCpuAffinity({
+  required int pid,
+  required List<int> cpus,
+  required bool allThreads,
+}) => RustLib.instance.api.crateApiSimpleSetCpuAffinity(
+  pid: pid,
+  cpus: cpus,
+  allThreads: allThreads,
+);
+
+/// Scheduling policy of a process or thread (Linux only)
+SchedulerInfo getScheduler({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetScheduler(pid: pid);
+
+/// Change the scheduling policy (Linux only). Real-time policies need root or CAP_SYS_NICE.
+void setScheduler({
+  required int pid,
+  required SchedulerInfo scheduler,
+  required bool allThreads,
+}) => RustLib.instance.api.crateApiSimpleSetScheduler(
+  pid: pid,
+  scheduler: scheduler,
+  allThreads: allThreads,
+);
+
+/// Affinity and scheduling policy of every thread of a process (Linux only)
+List<ThreadScheduling> getThreadScheduling({required int pid}) =>
+    RustLib.instance.api.crateApiSimpleGetThreadScheduling(pid: pid);
+
+/// Pause a process, and with `include_children` all of its descendants. A process
+/// (tree) that has a cgroup v2 of its own is frozen through cgroup.freeze, so children
+/// forked meanwhile cannot escape; otherwise SIGSTOP is used.
+void suspendProcess({required int pid, required bool includeChildren}) =>
+    RustLib.instance.api.crateApiSimpleSuspendProcess(
+      pid: pid,
+      includeChildren: includeChildren,
+    );
+
+/// Undo `suspend_process`
+void resumeProcess({required int pid, required bool includeChildren}) =>
+    RustLib.instance.api.crateApiSimpleResumeProcess(
+      pid: pid,
+      includeChildren: includeChildren,
+    );
+
+/// Ask a process to exit (SIGTERM / WM_CLOSE), wait up to `timeout_ms` for it to
+/// do so, then kill it. Blocks while waiting, so it is not a sync call.
+Future<TerminationOutcome> terminateGracefully({
+  required int pid,
+  required BigInt timeoutMs,
+}) => RustLib.instance.api.crateApiSimpleTerminateGracefully(
+  pid: pid,
+  timeoutMs: timeoutMs,
+);
+
+/// A capability mask with the names of its bits, e.g. "CAP_NET_ADMIN"
+class CapabilitySet {
+  final BigInt mask;
+  final List<String> names;
+
+  const CapabilitySet({required this.mask, required this.names});
+
+  static Future<CapabilitySet> default_() =>
+      RustLib.instance.api.crateApiSimpleCapabilitySetDefault();
+
+  @override
+  int get hashCode => mask.hashCode ^ names.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CapabilitySet &&
+          runtimeType == other.runtimeType &&
+          mask == other.mask &&
+          names == other.names;
+}
+
+/// cpu.stat of a cgroup, in microseconds
+class CgroupCpuStat {
+  final BigInt usageUsec;
+  final BigInt userUsec;
+  final BigInt systemUsec;
+  /// Enforcement periods of cpu.max, and how many of them hit the limit
+  final BigInt nrPeriods;
+  final BigInt nrThrottled;
+  final BigInt throttledUsec;
+
+  const CgroupCpuStat({
+    required this.usageUsec,
+    required this.userUsec,
+    required this.systemUsec,
+    required this.nrPeriods,
+    required this.nrThrottled,
+    required this.throttledUsec,
+  });
+
+  static Future<CgroupCpuStat> default_() =>
+      RustLib.instance.api.crateApiSimpleCgroupCpuStatDefault();
+
+  @override
+  int get hashCode =>
+      usageUsec.hashCode ^
+      userUsec.hashCode ^
+      systemUsec.hashCode ^
+      nrPeriods.hashCode ^
+      nrThrottled.hashCode ^
+      throttledUsec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CgroupCpuStat &&
+          runtimeType == other.runtimeType &&
+          usageUsec == other.usageUsec &&
+          userUsec == other.userUsec &&
+          systemUsec == other.systemUsec &&
+          nrPeriods == other.nrPeriods &&
+          nrThrottled == other.nrThrottled &&
+          throttledUsec == other.throttledUsec;
+}
+
+/// One cgroup of the v2 hierarchy, from `get_cgroup_tree`. Counters cover the cgroup
+/// and everything below it; fields are None when the controller is not enabled there.
+class CgroupInfo {
+  /// Relative to the hierarchy root, which is "/"
+  final String path;
+  final String name;
+  final String? parent;
+  final CgroupKind kind;
+  /// Processes directly in this cgroup, not in its children
+  final int processCount;
+  final CgroupCpuStat? cpu;
+  /// Over the interval since the previous call, normalised like `ProcessInfo.cpu_usage`
+  final double cpuUsage;
+  final BigInt? memoryCurrent;
+  /// None when unlimited
+  final BigInt? memoryMax;
+  final CgroupMemoryEvents? memoryEvents;
+  final List<CgroupIoStat> io;
+  /// Sums of `io` over all devices
+  final BigInt ioReadBytes;
+  final BigInt ioWriteBytes;
+  final BigInt? pidsCurrent;
+  final Pressure? cpuPressure;
+  final Pressure? memoryPressure;
+  final Pressure? ioPressure;
+
+  const CgroupInfo({
+    required this.path,
+    required this.name,
+    this.parent,
+    required this.kind,
+    required this.processCount,
+    this.cpu,
+    required this.cpuUsage,
+    this.memoryCurrent,
+    this.memoryMax,
+    this.memoryEvents,
+    required this.io,
+    required this.ioReadBytes,
+    required this.ioWriteBytes,
+    this.pidsCurrent,
+    this.cpuPressure,
+    this.memoryPressure,
+    this.ioPressure,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      name.hashCode ^
+      parent.hashCode ^
+      kind.hashCode ^
+      processCount.hashCode ^
+      cpu.hashCode ^
+      cpuUsage.hashCode ^
+      memoryCurrent.hashCode ^
+      memoryMax.hashCode ^
+      memoryEvents.hashCode ^
+      io.hashCode ^
+      ioReadBytes.hashCode ^
+      ioWriteBytes.hashCode ^
+      pidsCurrent.hashCode ^
+      cpuPressure.hashCode ^
+      memoryPressure.hashCode ^
+      ioPressure.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CgroupInfo &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          name == other.name &&
+          parent == other.parent &&
+          kind == other.kind &&
+          processCount == other.processCount &&
+          cpu == other.cpu &&
+          cpuUsage == other.cpuUsage &&
+          memoryCurrent == other.memoryCurrent &&
+          memoryMax == other.memoryMax &&
+          memoryEvents == other.memoryEvents &&
+          io == other.io &&
+          ioReadBytes == other.ioReadBytes &&
+          ioWriteBytes == other.ioWriteBytes &&
+          pidsCurrent == other.pidsCurrent &&
+          cpuPressure == other.cpuPressure &&
+          memoryPressure == other.memoryPressure &&
+          ioPressure == other.ioPressure;
+}
+
+/// One device line of io.stat
+class CgroupIoStat {
+  /// "major:minor"
+  final String device;
+  final BigInt readBytes;
+  final BigInt writeBytes;
+  final BigInt readIos;
+  final BigInt writeIos;
+  final BigInt discardBytes;
+  final BigInt discardIos;
+
+  const CgroupIoStat({
+    required this.device,
+    required this.readBytes,
+    required this.writeBytes,
+    required this.readIos,
+    required this.writeIos,
+    required this.discardBytes,
+    required this.discardIos,
+  });
+
+  static Future<CgroupIoStat> default_() =>
+      RustLib.instance.api.crateApiSimpleCgroupIoStatDefault();
+
+  @override
+  int get hashCode =>
+      device.hashCode ^
+      readBytes.hashCode ^
+      writeBytes.hashCode ^
+      readIos.hashCode ^
+      writeIos.hashCode ^
+      discardBytes.hashCode ^
+      discardIos.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CgroupIoStat &&
+          runtimeType == other.runtimeType &&
+          device == other.device &&
+          readBytes == other.readBytes &&
+          writeBytes == other.writeBytes &&
+          readIos == other.readIos &&
+          writeIos == other.writeIos &&
+          discardBytes == other.discardBytes &&
+          discardIos == other.discardIos;
+}
+
+/// systemd unit type of a cgroup, from its name
+enum CgroupKind {
+  root,
+  slice,
+  service,
+  /// Also containers, e.g. docker-<id>.scope
+  scope,
+  other,
+}
+
+/// memory.events: how often each limit was hit
+class CgroupMemoryEvents {
+  final BigInt low;
+  final BigInt high;
+  final BigInt max;
+  final BigInt oom;
+  final BigInt oomKill;
+
+  const CgroupMemoryEvents({
+    required this.low,
+    required this.high,
+    required this.max,
+    required this.oom,
+    required this.oomKill,
+  });
+
+  static Future<CgroupMemoryEvents> default_() =>
+      RustLib.instance.api.crateApiSimpleCgroupMemoryEventsDefault();
+
+  @override
+  int get hashCode =>
+      low.hashCode ^
+      high.hashCode ^
+      max.hashCode ^
+      oom.hashCode ^
+      oomKill.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CgroupMemoryEvents &&
+          runtimeType == other.runtimeType &&
+          low == other.low &&
+          high == other.high &&
+          max == other.max &&
+          oom == other.oom &&
+          oomKill == other.oomKill;
+}
+
+/// One TCP/UDP socket, like a row of `ss -tuanp`
+class ConnectionInfo {
+  final TransportProtocol protocol;
+  final bool ipv6;
+  final String localAddress;
+  final int localPort;
+  final String remoteAddress;
+  final int remotePort;
+  final ConnectionState state;
+  final BigInt sendQueue;
+  final BigInt receiveQueue;
+  /// Owning process; None if it could not be determined (e.g. no permission)
+  final int? pid;
+  final int uid;
+  final BigInt inode;
+
+  const ConnectionInfo({
+    required this.protocol,
+    required this.ipv6,
+    required this.localAddress,
+    required this.localPort,
+    required this.remoteAddress,
+    required this.remotePort,
+    required this.state,
+    required this.sendQueue,
+    required this.receiveQueue,
+    this.pid,
+    required this.uid,
+    required this.inode,
+  });
+
+  @override
+  int get hashCode =>
+      protocol.hashCode ^
+      ipv6.hashCode ^
+      localAddress.hashCode ^
+      localPort.hashCode ^
+      remoteAddress.hashCode ^
+      remotePort.hashCode ^
+      state.hashCode ^
+      sendQueue.hashCode ^
+      receiveQueue.hashCode ^
+      pid.hashCode ^
+      uid.hashCode ^
+      inode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConnectionInfo &&
+          runtimeType == other.runtimeType &&
+          protocol == other.protocol &&
+          ipv6 == other.ipv6 &&
+          localAddress == other.localAddress &&
+          localPort == other.localPort &&
+          remoteAddress == other.remoteAddress &&
+          remotePort == other.remotePort &&
+          state == other.state &&
+          sendQueue == other.sendQueue &&
+          receiveQueue == other.receiveQueue &&
+          pid == other.pid &&
+          uid == other.uid &&
+          inode == other.inode;
+}
+
+/// TCP connection state. UDP sockets are either Established (connected) or Unconnected.
+enum ConnectionState {
+  established,
+  synSent,
+  synReceived,
+  finWait1,
+  finWait2,
+  timeWait,
+  closed,
+  closeWait,
+  lastAck,
+  listen,
+  closing,
+  unconnected,
+  unknown,
+}
+
+/// Share of CPU time per category over the sample interval, in percent.
+/// The categories add up to 100; guest time is reported separately from user/nice.
+/// Categories a platform does not track are left at 0.
+class CpuTimesBreakdown {
+  final double user;
+  final double nice;
+  final double system;
+  final double idle;
+  final double iowait;
+  /// Hardware interrupts (interrupt time on Windows)
+  final double irq;
+  /// Soft interrupts (DPC time on Windows)
+  final double softirq;
+  /// Time stolen by the hypervisor for other guests
+  final double steal;
+  /// Time spent running a virtual CPU for a guest OS
+  final double guest;
+  final double guestNice;
+
+  const CpuTimesBreakdown({
+    required this.user,
+    required this.nice,
+    required this.system,
+    required this.idle,
+    required this.iowait,
+    required this.irq,
+    required this.softirq,
+    required this.steal,
+    required this.guest,
+    required this.guestNice,
+  });
+
+  static Future<CpuTimesBreakdown> default_() =>
+      RustLib.instance.api.crateApiSimpleCpuTimesBreakdownDefault();
+
+  @override
+  int get hashCode =>
+      user.hashCode ^
+      nice.hashCode ^
+      system.hashCode ^
+      idle.hashCode ^
+      iowait.hashCode ^
+      irq.hashCode ^
+      softirq.hashCode ^
+      steal.hashCode ^
+      guest.hashCode ^
+      guestNice.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CpuTimesBreakdown &&
+          runtimeType == other.runtimeType &&
+          user == other.user &&
+          nice == other.nice &&
+          system == other.system &&
+          idle == other.idle &&
+          iowait == other.iowait &&
+          irq == other.irq &&
+          softirq == other.softirq &&
+          steal == other.steal &&
+          guest == other.guest &&
+          guestNice == other.guestNice;
+}
+
+/// How per-process CPU usage is normalised
+enum CpuUsageMode {
+  /// Share of the whole machine, 0-100 regardless of core count
+  machine,
+  /// Share of a single core, up to 100 * cores (like top)
+  singleCore,
+}
+
+/// I/O activity of one block device. Rates cover the interval since the
+/// previous `get_disk_activity` call and are 0 on the first call.
+class DiskActivity {
+  final String name;
+  /// Disk this partition belongs to; None for whole disks
+  final String? parent;
+  final BigInt readBytes;
+  final BigInt writeBytes;
+  final double readBytesPerSec;
+  final double writeBytesPerSec;
+  final double readIops;
+  final double writeIops;
+  /// Average time an I/O request took, queueing included
+  final double avgWaitMs;
+  /// Percentage of the interval the device was busy ("active time")
+  final double utilization;
+
+  const DiskActivity({
+    required this.name,
+    this.parent,
+    required this.readBytes,
+    required this.writeBytes,
+    required this.readBytesPerSec,
+    required this.writeBytesPerSec,
+    required this.readIops,
+    required this.writeIops,
+    required this.avgWaitMs,
+    required this.utilization,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      parent.hashCode ^
+      readBytes.hashCode ^
+      writeBytes.hashCode ^
+      readBytesPerSec.hashCode ^
+      writeBytesPerSec.hashCode ^
+      readIops.hashCode ^
+      writeIops.hashCode ^
+      avgWaitMs.hashCode ^
+      utilization.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiskActivity &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          parent == other.parent &&
+          readBytes == other.readBytes &&
+          writeBytes == other.writeBytes &&
+          readBytesPerSec == other.readBytesPerSec &&
+          writeBytesPerSec == other.writeBytesPerSec &&
+          readIops == other.readIops &&
+          writeIops == other.writeIops &&
+          avgWaitMs == other.avgWaitMs &&
+          utilization == other.utilization;
+}
+
+class DiskInfo {
+  final String name;
+  final String mountPoint;
+  final BigInt totalSpace;
+  final BigInt usedSpace;
+  final BigInt availableSpace;
+  final String fsType;
+  /// Mount source, e.g. /dev/sda1 or server:/export
+  final String device;
+  final List<String> mountOptions;
+  final bool readOnly;
+  final BigInt inodesTotal;
+  final BigInt inodesUsed;
+  final BigInt inodesFree;
+
+  const DiskInfo({
+    required this.name,
+    required this.mountPoint,
+    required this.totalSpace,
+    required this.usedSpace,
+    required this.availableSpace,
+    required this.fsType,
+    required this.device,
+    required this.mountOptions,
+    required this.readOnly,
+    required this.inodesTotal,
+    required this.inodesUsed,
+    required this.inodesFree,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      mountPoint.hashCode ^
+      totalSpace.hashCode ^
+      usedSpace.hashCode ^
+      availableSpace.hashCode ^
+      fsType.hashCode ^
+      device.hashCode ^
+      mountOptions.hashCode ^
+      readOnly.hashCode ^
+      inodesTotal.hashCode ^
+      inodesUsed.hashCode ^
+      inodesFree.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiskInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          mountPoint == other.mountPoint &&
+          totalSpace == other.totalSpace &&
+          usedSpace == other.usedSpace &&
+          availableSpace == other.availableSpace &&
+          fsType == other.fsType &&
+          device == other.device &&
+          mountOptions == other.mountOptions &&
+          readOnly == other.readOnly &&
+          inodesTotal == other.inodesTotal &&
+          inodesUsed == other.inodesUsed &&
+          inodesFree == other.inodesFree;
+}
+
+class EnvironmentVariable {
+  final String name;
+  final String value;
+
+  const EnvironmentVariable({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EnvironmentVariable &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// What an open file descriptor refers to
+enum FileDescriptorKind {
+  file,
+  directory,
+  /// Character or block device
+  device,
+  socket,
+  pipe,
+  /// anon_inode: eventfd, epoll, inotify, timerfd, ...
+  anonInode,
+  other,
+}
+
+class IoPriority {
+  final IoPriorityClass class_;
+  /// 0 (highest) to 7 within the Realtime and BestEffort classes
+  final int level;
+
+  const IoPriority({required this.class_, required this.level});
+
+  static Future<IoPriority> default_() =>
+      RustLib.instance.api.crateApiSimpleIoPriorityDefault();
+
+  @override
+  int get hashCode => class_.hashCode ^ level.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IoPriority &&
+          runtimeType == other.runtimeType &&
+          class_ == other.class_ &&
+          level == other.level;
+}
+
+/// I/O scheduling class (see ioprio_set(2))
+enum IoPriorityClass {
+  /// Not set; the kernel derives best-effort priority from the nice value
+  none,
+  realtime,
+  bestEffort,
+  /// Only gets disk time when no one else needs it
+  idle,
+}
+
+/// What happened to one process of a `kill_process_tree` call
+enum KillOutcome {
+  killed,
+  /// Exited (or its pid was reused) before it was signalled
+  alreadyExited,
+  failed,
+}
+
+/// How `kill_process_tree` delivers the kill
+enum KillTreeStrategy {
+  /// Kill each process individually, deepest descendants first
+  leavesFirst,
+  /// Kill whole process groups led by a member of the tree in one call, then any
+  /// remaining members individually. Same as LeavesFirst on Windows.
+  processGroup,
+}
+
+/// Totals of all regions mapping the same path
+class MappedFileSummary {
+  final String path;
+  final int regions;
+  final BigInt size;
+  final BigInt rss;
+  final BigInt pss;
+  final BigInt private;
+  final BigInt swap;
+
+  const MappedFileSummary({
+    required this.path,
+    required this.regions,
+    required this.size,
+    required this.rss,
+    required this.pss,
+    required this.private,
+    required this.swap,
+  });
+
+  static Future<MappedFileSummary> default_() =>
+      RustLib.instance.api.crateApiSimpleMappedFileSummaryDefault();
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      regions.hashCode ^
+      size.hashCode ^
+      rss.hashCode ^
+      pss.hashCode ^
+      private.hashCode ^
+      swap.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MappedFileSummary &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          regions == other.regions &&
+          size == other.size &&
+          rss == other.rss &&
+          pss == other.pss &&
+          private == other.private &&
+          swap == other.swap;
+}
+
+/// Result of `get_memory_maps`
+class MemoryMaps {
+  /// In address order
+  final List<MemoryRegion> regions;
+  /// Per path, largest PSS first
+  final List<MappedFileSummary> files;
+
+  const MemoryMaps({required this.regions, required this.files});
+
+  @override
+  int get hashCode => regions.hashCode ^ files.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryMaps &&
+          runtimeType == other.runtimeType &&
+          regions == other.regions &&
+          files == other.files;
+}
+
+/// Which figure backs `ProcessInfo.memory_usage`
+enum MemoryMetric {
+  /// Resident set size; shared libraries count in full for every process mapping them
+  rss,
+  /// Proportional set size: each shared page is split between the processes sharing it,
+  /// so the values add up to the memory actually in use (Linux only)
+  pss,
+  /// Unique set size: pages private to the process, i.e. what killing it would free (Linux only)
+  uss,
+}
+
+/// One mapping of a process's address space, from `get_memory_maps`. Sizes in bytes.
+class MemoryRegion {
+  final BigInt start;
+  final BigInt end;
+  final BigInt size;
+  /// "r-xp" style: read, write, execute, then p(rivate) or s(hared)
+  final String permissions;
+  /// Offset into the mapped file
+  final BigInt offset;
+  /// "major:minor" of the device holding the file, "00:00" for anonymous memory
+  final String device;
+  final BigInt inode;
+  /// File path, a pseudo-name such as [heap], [stack] or [vdso], or [anon] for
+  /// anonymous memory
+  final String path;
+  /// The mapped file was unlinked (e.g. a library replaced by an upgrade)
+  final bool deleted;
+  final BigInt rss;
+  final BigInt pss;
+  /// Private clean + dirty pages
+  final BigInt private;
+  final BigInt swap;
+
+  const MemoryRegion({
+    required this.start,
+    required this.end,
+    required this.size,
+    required this.permissions,
+    required this.offset,
+    required this.device,
+    required this.inode,
+    required this.path,
+    required this.deleted,
+    required this.rss,
+    required this.pss,
+    required this.private,
+    required this.swap,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^
+      end.hashCode ^
+      size.hashCode ^
+      permissions.hashCode ^
+      offset.hashCode ^
+      device.hashCode ^
+      inode.hashCode ^
+      path.hashCode ^
+      deleted.hashCode ^
+      rss.hashCode ^
+      pss.hashCode ^
+      private.hashCode ^
+      swap.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryRegion &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          size == other.size &&
+          permissions == other.permissions &&
+          offset == other.offset &&
+          device == other.device &&
+          inode == other.inode &&
+          path == other.path &&
+          deleted == other.deleted &&
+          rss == other.rss &&
+          pss == other.pss &&
+          private == other.private &&
+          swap == other.swap;
+}
+
+/// Which mounts `get_disks` reports
+class MountFilter {
+  /// Include proc, sysfs, cgroup, overlay layers and other virtual filesystems
+  final bool includePseudo;
+  /// Report a device mounted in several places (bind mounts) only once
+  final bool collapseBindMounts;
+
+  const MountFilter({
+    required this.includePseudo,
+    required this.collapseBindMounts,
+  });
+
+  static Future<MountFilter> default_() =>
+      RustLib.instance.api.crateApiSimpleMountFilterDefault();
+
+  @override
+  int get hashCode => includePseudo.hashCode ^ collapseBindMounts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MountFilter &&
+          runtimeType == other.runtimeType &&
+          includePseudo == other.includePseudo &&
+          collapseBindMounts == other.collapseBindMounts;
+}
+
+/// Where the traffic of a `ProcessNetworkUsage` row was measured
+enum NetworkAttribution {
+  /// Summed over the TCP sockets the process holds
+  socket,
+  /// Interface totals of the process's network namespace, e.g. a container.
+  /// Only its lowest pid gets a row.
+  namespace,
+}
+
+class NetworkInfo {
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+  final BigInt packetsSent;
+  final BigInt packetsReceived;
+
+  const NetworkInfo({
+    required this.bytesSent,
+    required this.bytesReceived,
+    required this.packetsSent,
+    required this.packetsReceived,
+  });
+
+  @override
+  int get hashCode =>
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      packetsSent.hashCode ^
+      packetsReceived.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetworkInfo &&
+          runtimeType == other.runtimeType &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          packetsSent == other.packetsSent &&
+          packetsReceived == other.packetsReceived;
+}
+
+/// One network interface. Counters are totals since the interface came up; the
+/// rates cover the interval since the previous `get_network_interfaces` call.
+class NetworkInterfaceInfo {
+  final String name;
+  /// ethernet, wireless, loopback, bridge, veth, tun, bond, vlan, virtual, ...
+  final String kind;
+  final bool isLoopback;
+  /// Not backed by hardware (veth, docker0, bridges, tunnels)
+  final bool isVirtual;
+  /// up, down, dormant, unknown, ...
+  final String operstate;
+  final BigInt? linkSpeedMbps;
+  final int mtu;
+  final String macAddress;
+  final List<String> ipv4Addresses;
+  final List<String> ipv6Addresses;
+  final BigInt bytesReceived;
+  final BigInt bytesSent;
+  final BigInt packetsReceived;
+  final BigInt packetsSent;
+  final BigInt receiveErrors;
+  final BigInt sendErrors;
+  final BigInt receiveDropped;
+  final BigInt sendDropped;
+  final double receiveBytesPerSec;
+  final double sendBytesPerSec;
+
+  const NetworkInterfaceInfo({
+    required this.name,
+    required this.kind,
+    required this.isLoopback,
+    required this.isVirtual,
+    required this.operstate,
+    this.linkSpeedMbps,
+    required this.mtu,
+    required this.macAddress,
+    required this.ipv4Addresses,
+    required this.ipv6Addresses,
+    required this.bytesReceived,
+    required this.bytesSent,
+    required this.packetsReceived,
+    required this.packetsSent,
+    required this.receiveErrors,
+    required this.sendErrors,
+    required this.receiveDropped,
+    required this.sendDropped,
+    required this.receiveBytesPerSec,
+    required this.sendBytesPerSec,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      kind.hashCode ^
+      isLoopback.hashCode ^
+      isVirtual.hashCode ^
+      operstate.hashCode ^
+      linkSpeedMbps.hashCode ^
+      mtu.hashCode ^
+      macAddress.hashCode ^
+      ipv4Addresses.hashCode ^
+      ipv6Addresses.hashCode ^
+      bytesReceived.hashCode ^
+      bytesSent.hashCode ^
+      packetsReceived.hashCode ^
+      packetsSent.hashCode ^
+      receiveErrors.hashCode ^
+      sendErrors.hashCode ^
+      receiveDropped.hashCode ^
+      sendDropped.hashCode ^
+      receiveBytesPerSec.hashCode ^
+      sendBytesPerSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetworkInterfaceInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          kind == other.kind &&
+          isLoopback == other.isLoopback &&
+          isVirtual == other.isVirtual &&
+          operstate == other.operstate &&
+          linkSpeedMbps == other.linkSpeedMbps &&
+          mtu == other.mtu &&
+          macAddress == other.macAddress &&
+          ipv4Addresses == other.ipv4Addresses &&
+          ipv6Addresses == other.ipv6Addresses &&
+          bytesReceived == other.bytesReceived &&
+          bytesSent == other.bytesSent &&
+          packetsReceived == other.packetsReceived &&
+          packetsSent == other.packetsSent &&
+          receiveErrors == other.receiveErrors &&
+          sendErrors == other.sendErrors &&
+          receiveDropped == other.receiveDropped &&
+          sendDropped == other.sendDropped &&
+          receiveBytesPerSec == other.receiveBytesPerSec &&
+          sendBytesPerSec == other.sendBytesPerSec;
+}
+
+/// One open file descriptor, from `get_open_files`
+class OpenFileInfo {
+  final int fd;
+  final FileDescriptorKind kind;
+  /// Target path, or the link text for sockets, pipes and anon inodes ("socket:[1234]",
+  /// "anon_inode:[eventfd]")
+  final String path;
+  /// open(2) flags as listed in fdinfo
+  final int flags;
+  final bool readable;
+  final bool writable;
+  /// Current file offset
+  final BigInt position;
+  /// The file was unlinked while still open
+  final bool deleted;
+  /// Inode of sockets and pipes, to match them with `ConnectionInfo.inode`; 0 otherwise
+  final BigInt inode;
+
+  const OpenFileInfo({
+    required this.fd,
+    required this.kind,
+    required this.path,
+    required this.flags,
+    required this.readable,
+    required this.writable,
+    required this.position,
+    required this.deleted,
+    required this.inode,
+  });
+
+  @override
+  int get hashCode =>
+      fd.hashCode ^
+      kind.hashCode ^
+      path.hashCode ^
+      flags.hashCode ^
+      readable.hashCode ^
+      writable.hashCode ^
+      position.hashCode ^
+      deleted.hashCode ^
+      inode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpenFileInfo &&
+          runtimeType == other.runtimeType &&
+          fd == other.fd &&
+          kind == other.kind &&
+          path == other.path &&
+          flags == other.flags &&
+          readable == other.readable &&
+          writable == other.writable &&
+          position == other.position &&
+          deleted == other.deleted &&
+          inode == other.inode;
+}
+
+/// One process holding a path, from `find_processes_using`
+class PathUsage {
+  final int pid;
+  final String name;
+  final PathUsageKind kind;
+  /// What the process holds; may be below the queried directory or mount point
+  final String path;
+  /// Descriptor number for OpenFile
+  final int? fd;
+
+  const PathUsage({
+    required this.pid,
+    required this.name,
+    required this.kind,
+    required this.path,
+    this.fd,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      name.hashCode ^
+      kind.hashCode ^
+      path.hashCode ^
+      fd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PathUsage &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          name == other.name &&
+          kind == other.kind &&
+          path == other.path &&
+          fd == other.fd;
+}
+
+/// How a process uses the path given to `find_processes_using`
+enum PathUsageKind {
+  /// Held through an open file descriptor
+  openFile,
+  workingDirectory,
+  /// Root directory of the process (chroot or container)
+  rootDirectory,
+  /// The process is running this executable
+  executable,
+  /// Mapped into memory, e.g. a shared library
+  memoryMapped,
+}
+
+class Pressure {
+  /// At least one task was stalled
+  final PressureLine some;
+  /// All non-idle tasks were stalled at once; missing for CPU before Linux 5.13
+  final PressureLine? full;
+
+  const Pressure({required this.some, this.full});
 
-/// Kill a process by PID
-bool killProcess({required int pid}) =>
-    RustLib.instance.api.crateApiSimpleKillProcess(pid: pid);
+  static Future<Pressure> default_() =>
+      RustLib.instance.api.crateApiSimplePressureDefault();
 
-class DiskInfo {
-  final String name;
-  final String mountPoint;
-  final BigInt totalSpace;
-  final BigInt usedSpace;
-  final BigInt availableSpace;
+  @override
+  int get hashCode => some.hashCode ^ full.hashCode;
 
-  const DiskInfo({
-    required this.name,
-    required this.mountPoint,
-    required this.totalSpace,
-    required this.usedSpace,
-    required this.availableSpace,
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Pressure &&
+          runtimeType == other.runtimeType &&
+          some == other.some &&
+          full == other.full;
+}
+
+/// One line of a pressure stall (PSI) file: percentage of time stalled over the last
+/// 10, 60 and 300 seconds, and total stall time
+class PressureLine {
+  final double avg10;
+  final double avg60;
+  final double avg300;
+  final BigInt totalUsec;
+
+  const PressureLine({
+    required this.avg10,
+    required this.avg60,
+    required this.avg300,
+    required this.totalUsec,
   });
 
+  static Future<PressureLine> default_() =>
+      RustLib.instance.api.crateApiSimplePressureLineDefault();
+
   @override
   int get hashCode =>
-      name.hashCode ^
-      mountPoint.hashCode ^
-      totalSpace.hashCode ^
-      usedSpace.hashCode ^
-      availableSpace.hashCode;
+      avg10.hashCode ^ avg60.hashCode ^ avg300.hashCode ^ totalUsec.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is DiskInfo &&
+      other is PressureLine &&
           runtimeType == other.runtimeType &&
-          name == other.name &&
-          mountPoint == other.mountPoint &&
-          totalSpace == other.totalSpace &&
-          usedSpace == other.usedSpace &&
-          availableSpace == other.availableSpace;
+          avg10 == other.avg10 &&
+          avg60 == other.avg60 &&
+          avg300 == other.avg300 &&
+          totalUsec == other.totalUsec;
 }
 
-class NetworkInfo {
-  final BigInt bytesSent;
-  final BigInt bytesReceived;
-  final BigInt packetsSent;
-  final BigInt packetsReceived;
+/// Named scheduling priority, mapped to nice values on Linux/macOS and to priority
+/// classes on Windows
+enum PriorityLevel { idle, belowNormal, normal, aboveNormal, high }
 
-  const NetworkInfo({
-    required this.bytesSent,
-    required this.bytesReceived,
-    required this.packetsSent,
-    required this.packetsReceived,
+/// Error of the process control functions, thrown as an exception on the Dart side
+class ProcessControlError implements FrbException {
+  final ProcessControlErrorKind kind;
+  /// errno on Linux/macOS, GetLastError() on Windows; 0 when the error is not from the OS
+  final int code;
+  final String message;
+
+  const ProcessControlError({
+    required this.kind,
+    required this.code,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ code.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessControlError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          code == other.code &&
+          message == other.message;
+}
+
+/// Why a process control call failed
+enum ProcessControlErrorKind {
+  /// EPERM / ERROR_ACCESS_DENIED
+  permissionDenied,
+  /// ESRCH: the process does not exist or has already exited
+  noSuchProcess,
+  /// Kernel threads and critical system processes that must not be touched
+  protectedProcess,
+  invalidArgument,
+  /// The platform has no equivalent of the requested operation
+  unsupported,
+  /// The process was signalled but did not go away in time
+  timedOut,
+  other,
+}
+
+/// User and group ids of a process, with their names. Names fall back to the
+/// number when the id has no entry.
+class ProcessCredentials {
+  final int realUid;
+  /// The uid used for permission checks; differs from real_uid for setuid programs
+  final int effectiveUid;
+  final int savedUid;
+  final int realGid;
+  final int effectiveGid;
+  final int savedGid;
+  /// Empty on macOS
+  final Uint32List supplementaryGroups;
+  final String realUser;
+  final String effectiveUser;
+  /// Name of effective_gid
+  final String group;
+  /// In the order of supplementary_groups
+  final List<String> supplementaryGroupNames;
+
+  const ProcessCredentials({
+    required this.realUid,
+    required this.effectiveUid,
+    required this.savedUid,
+    required this.realGid,
+    required this.effectiveGid,
+    required this.savedGid,
+    required this.supplementaryGroups,
+    required this.realUser,
+    required this.effectiveUser,
+    required this.group,
+    required this.supplementaryGroupNames,
   });
 
+  static Future<ProcessCredentials> default_() =>
+      RustLib.instance.api.crateApiSimpleProcessCredentialsDefault();
+
   @override
   int get hashCode =>
-      bytesSent.hashCode ^
-      bytesReceived.hashCode ^
-      packetsSent.hashCode ^
-      packetsReceived.hashCode;
+      realUid.hashCode ^
+      effectiveUid.hashCode ^
+      savedUid.hashCode ^
+      realGid.hashCode ^
+      effectiveGid.hashCode ^
+      savedGid.hashCode ^
+      supplementaryGroups.hashCode ^
+      realUser.hashCode ^
+      effectiveUser.hashCode ^
+      group.hashCode ^
+      supplementaryGroupNames.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is NetworkInfo &&
+      other is ProcessCredentials &&
           runtimeType == other.runtimeType &&
-          bytesSent == other.bytesSent &&
-          bytesReceived == other.bytesReceived &&
-          packetsSent == other.packetsSent &&
-          packetsReceived == other.packetsReceived;
+          realUid == other.realUid &&
+          effectiveUid == other.effectiveUid &&
+          savedUid == other.savedUid &&
+          realGid == other.realGid &&
+          effectiveGid == other.effectiveGid &&
+          savedGid == other.savedGid &&
+          supplementaryGroups == other.supplementaryGroups &&
+          realUser == other.realUser &&
+          effectiveUser == other.effectiveUser &&
+          group == other.group &&
+          supplementaryGroupNames == other.supplementaryGroupNames;
+}
+
+class ProcessDetailError {
+  final ProcessDetailField field;
+  final ProcessControlError error;
+
+  const ProcessDetailError({required this.field, required this.error});
+
+  @override
+  int get hashCode => field.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessDetailError &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          error == other.error;
+}
+
+/// Fields of `ProcessDetails` that are read separately and may fail on their own
+enum ProcessDetailField {
+  executable,
+  workingDirectory,
+  rootDirectory,
+  commandLine,
+  environment,
+}
+
+/// Result of `get_process_details`. A field is None when it could not be read (see
+/// `errors`) or does not exist, e.g. the executable of a kernel thread.
+class ProcessDetails {
+  final int pid;
+  /// Resolved path of the running executable
+  final String? exe;
+  /// The executable was deleted or replaced on disk since the process started
+  final bool exeDeleted;
+  final String? cwd;
+  /// Root directory; differs from "/" inside a chroot or container
+  final String? root;
+  /// Arguments as the process sees them; empty for kernel threads
+  final List<String>? argv;
+  /// Environment the process was started with. Changes it made later with setenv()
+  /// are not visible.
+  final List<EnvironmentVariable>? environment;
+  final List<ProcessDetailError> errors;
+
+  const ProcessDetails({
+    required this.pid,
+    this.exe,
+    required this.exeDeleted,
+    this.cwd,
+    this.root,
+    this.argv,
+    this.environment,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      exe.hashCode ^
+      exeDeleted.hashCode ^
+      cwd.hashCode ^
+      root.hashCode ^
+      argv.hashCode ^
+      environment.hashCode ^
+      errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessDetails &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          exe == other.exe &&
+          exeDeleted == other.exeDeleted &&
+          cwd == other.cwd &&
+          root == other.root &&
+          argv == other.argv &&
+          environment == other.environment &&
+          errors == other.errors;
+}
+
+/// I/O counters of a process since it started. Rates cover the interval since the
+/// previous `get_processes` call and are 0 the first time a process is seen.
+/// On Windows the byte counts include all I/O (files, devices, network), not only disk.
+class ProcessDiskIo {
+  /// Bytes fetched from storage (page cache hits are not counted)
+  final BigInt readBytes;
+  /// Bytes sent to storage, including later-cancelled writeback
+  final BigInt writeBytes;
+  /// Dirty page cache bytes that were truncated away before reaching storage
+  final BigInt cancelledWriteBytes;
+  /// Bytes passed to read()-like syscalls, including cache hits, pipes and sockets
+  final BigInt readChars;
+  final BigInt writeChars;
+  final BigInt readSyscalls;
+  final BigInt writeSyscalls;
+  final double readBytesPerSec;
+  /// Net of cancelled writes
+  final double writeBytesPerSec;
+
+  const ProcessDiskIo({
+    required this.readBytes,
+    required this.writeBytes,
+    required this.cancelledWriteBytes,
+    required this.readChars,
+    required this.writeChars,
+    required this.readSyscalls,
+    required this.writeSyscalls,
+    required this.readBytesPerSec,
+    required this.writeBytesPerSec,
+  });
+
+  static Future<ProcessDiskIo> default_() =>
+      RustLib.instance.api.crateApiSimpleProcessDiskIoDefault();
+
+  @override
+  int get hashCode =>
+      readBytes.hashCode ^
+      writeBytes.hashCode ^
+      cancelledWriteBytes.hashCode ^
+      readChars.hashCode ^
+      writeChars.hashCode ^
+      readSyscalls.hashCode ^
+      writeSyscalls.hashCode ^
+      readBytesPerSec.hashCode ^
+      writeBytesPerSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessDiskIo &&
+          runtimeType == other.runtimeType &&
+          readBytes == other.readBytes &&
+          writeBytes == other.writeBytes &&
+          cancelledWriteBytes == other.cancelledWriteBytes &&
+          readChars == other.readChars &&
+          writeChars == other.writeChars &&
+          readSyscalls == other.readSyscalls &&
+          writeSyscalls == other.writeSyscalls &&
+          readBytesPerSec == other.readBytesPerSec &&
+          writeBytesPerSec == other.writeBytesPerSec;
 }
 
 class ProcessInfo {
@@ -102,20 +1560,60 @@ class ProcessInfo {
   final String name;
   final double cpuUsage;
   final BigInt memoryUsage;
+  /// What `memory_usage` measures: the metric chosen with `set_memory_metric`, or Rss
+  /// where that is unavailable (other users' processes, non-Linux platforms)
+  final MemoryMetric memoryMetric;
   final int? parentPid;
   final String status;
+  final ProcessState state;
   final String command;
   final BigInt startTime;
+  final BigInt age;
+  final PriorityLevel priority;
+  /// Nice value, -20 (highest) to 19; derived from the priority class on Windows
+  final int nice;
+  final IoPriority ioPriority;
+  /// CPU the process last ran on (Linux only)
+  final int? lastCpu;
+  final int threadCount;
+  /// None when the I/O counters of the process cannot be read (other users' processes
+  /// without root)
+  final ProcessDiskIo? diskIo;
+  /// Effective user name ("DOMAIN\\name" on Windows), or the numeric uid when it has no
+  /// name. None when the owner cannot be read, such as other users' processes on Windows
+  /// without administrator rights. Compare with `get_current_user` to pick out the caller's
+  /// own processes; None never matches.
+  final String? user;
+  /// None where unavailable (Windows)
+  final ProcessCredentials? credentials;
+  /// Holds any effective capability on Linux, runs as root on macOS, has an elevated token
+  /// on Windows; see `get_security_context`
+  final bool privileged;
+  /// cgroup of the process as in /proc/<pid>/cgroup, e.g. "/system.slice/sshd.service" (Linux only)
+  final String? cgroup;
 
   const ProcessInfo({
     required this.pid,
     required this.name,
     required this.cpuUsage,
     required this.memoryUsage,
+    required this.memoryMetric,
     this.parentPid,
     required this.status,
+    required this.state,
     required this.command,
     required this.startTime,
+    required this.age,
+    required this.priority,
+    required this.nice,
+    required this.ioPriority,
+    this.lastCpu,
+    required this.threadCount,
+    this.diskIo,
+    this.user,
+    this.credentials,
+    required this.privileged,
+    this.cgroup,
   });
 
   @override
@@ -124,10 +1622,23 @@ class ProcessInfo {
       name.hashCode ^
       cpuUsage.hashCode ^
       memoryUsage.hashCode ^
+      memoryMetric.hashCode ^
       parentPid.hashCode ^
       status.hashCode ^
+      state.hashCode ^
       command.hashCode ^
-      startTime.hashCode;
+      startTime.hashCode ^
+      age.hashCode ^
+      priority.hashCode ^
+      nice.hashCode ^
+      ioPriority.hashCode ^
+      lastCpu.hashCode ^
+      threadCount.hashCode ^
+      diskIo.hashCode ^
+      user.hashCode ^
+      credentials.hashCode ^
+      privileged.hashCode ^
+      cgroup.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -138,10 +1649,360 @@ class ProcessInfo {
           name == other.name &&
           cpuUsage == other.cpuUsage &&
           memoryUsage == other.memoryUsage &&
+          memoryMetric == other.memoryMetric &&
           parentPid == other.parentPid &&
           status == other.status &&
+          state == other.state &&
           command == other.command &&
-          startTime == other.startTime;
+          startTime == other.startTime &&
+          age == other.age &&
+          priority == other.priority &&
+          nice == other.nice &&
+          ioPriority == other.ioPriority &&
+          lastCpu == other.lastCpu &&
+          threadCount == other.threadCount &&
+          diskIo == other.diskIo &&
+          user == other.user &&
+          credentials == other.credentials &&
+          privileged == other.privileged &&
+          cgroup == other.cgroup;
+}
+
+class ProcessKillResult {
+  final int pid;
+  final int? parentPid;
+  final String name;
+  final KillOutcome outcome;
+  /// Set when outcome is Failed
+  final ProcessControlError? error;
+
+  const ProcessKillResult({
+    required this.pid,
+    this.parentPid,
+    required this.name,
+    required this.outcome,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      parentPid.hashCode ^
+      name.hashCode ^
+      outcome.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessKillResult &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          parentPid == other.parentPid &&
+          name == other.name &&
+          outcome == other.outcome &&
+          error == other.error;
+}
+
+/// Memory breakdown of a process from /proc/<pid>/smaps_rollup, in bytes
+class ProcessMemory {
+  final BigInt rss;
+  final BigInt pss;
+  /// private_clean + private_dirty
+  final BigInt uss;
+  final BigInt sharedClean;
+  final BigInt sharedDirty;
+  final BigInt privateClean;
+  final BigInt privateDirty;
+  final BigInt swap;
+  /// Swap split between the processes sharing it, like pss
+  final BigInt swapPss;
+  /// mlock()ed memory
+  final BigInt locked;
+  /// Transparent and hugetlbfs huge pages
+  final BigInt hugePages;
+
+  const ProcessMemory({
+    required this.rss,
+    required this.pss,
+    required this.uss,
+    required this.sharedClean,
+    required this.sharedDirty,
+    required this.privateClean,
+    required this.privateDirty,
+    required this.swap,
+    required this.swapPss,
+    required this.locked,
+    required this.hugePages,
+  });
+
+  static Future<ProcessMemory> default_() =>
+      RustLib.instance.api.crateApiSimpleProcessMemoryDefault();
+
+  @override
+  int get hashCode =>
+      rss.hashCode ^
+      pss.hashCode ^
+      uss.hashCode ^
+      sharedClean.hashCode ^
+      sharedDirty.hashCode ^
+      privateClean.hashCode ^
+      privateDirty.hashCode ^
+      swap.hashCode ^
+      swapPss.hashCode ^
+      locked.hashCode ^
+      hugePages.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessMemory &&
+          runtimeType == other.runtimeType &&
+          rss == other.rss &&
+          pss == other.pss &&
+          uss == other.uss &&
+          sharedClean == other.sharedClean &&
+          sharedDirty == other.sharedDirty &&
+          privateClean == other.privateClean &&
+          privateDirty == other.privateDirty &&
+          swap == other.swap &&
+          swapPss == other.swapPss &&
+          locked == other.locked &&
+          hugePages == other.hugePages;
+}
+
+/// Network traffic of one process, from `get_process_network_usage`. Byte counts
+/// are totals of the sockets currently open (or of the namespace's interfaces); the
+/// rates cover the interval since the previous call.
+class ProcessNetworkUsage {
+  final int pid;
+  final String name;
+  final BigInt receivedBytes;
+  /// Bytes acknowledged by the peer, so retransmissions count once
+  final BigInt sentBytes;
+  final double receiveBytesPerSec;
+  final double sendBytesPerSec;
+  /// TCP sockets counted; 0 for Namespace rows
+  final int connections;
+  final NetworkAttribution source;
+  /// Inode of the network namespace, if known
+  final BigInt? netNamespace;
+
+  const ProcessNetworkUsage({
+    required this.pid,
+    required this.name,
+    required this.receivedBytes,
+    required this.sentBytes,
+    required this.receiveBytesPerSec,
+    required this.sendBytesPerSec,
+    required this.connections,
+    required this.source,
+    this.netNamespace,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      name.hashCode ^
+      receivedBytes.hashCode ^
+      sentBytes.hashCode ^
+      receiveBytesPerSec.hashCode ^
+      sendBytesPerSec.hashCode ^
+      connections.hashCode ^
+      source.hashCode ^
+      netNamespace.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessNetworkUsage &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          name == other.name &&
+          receivedBytes == other.receivedBytes &&
+          sentBytes == other.sentBytes &&
+          receiveBytesPerSec == other.receiveBytesPerSec &&
+          sendBytesPerSec == other.sendBytesPerSec &&
+          connections == other.connections &&
+          source == other.source &&
+          netNamespace == other.netNamespace;
+}
+
+/// Result of `get_priority`
+class ProcessPriority {
+  final PriorityLevel level;
+  final int nice;
+  final IoPriority ioPriority;
+
+  const ProcessPriority({
+    required this.level,
+    required this.nice,
+    required this.ioPriority,
+  });
+
+  @override
+  int get hashCode => level.hashCode ^ nice.hashCode ^ ioPriority.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessPriority &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          nice == other.nice &&
+          ioPriority == other.ioPriority;
+}
+
+/// Signals that can be sent with `send_signal`.
+/// On Windows, Terminate/Hangup/Quit close the process's windows (WM_CLOSE) or send
+/// CTRL_BREAK to its console, Interrupt sends CTRL_C and Kill calls TerminateProcess.
+enum ProcessSignal {
+  hangup,
+  interrupt,
+  quit,
+  abort,
+  kill,
+  user1,
+  user2,
+  terminate,
+  continue_,
+  stop,
+  terminalStop,
+}
+
+/// Scheduler state of a process
+enum ProcessState {
+  running,
+  sleeping,
+  /// Uninterruptible sleep, usually waiting on I/O
+  diskSleep,
+  stopped,
+  /// Suspended with `suspend_process` (stopped or in a frozen cgroup)
+  suspended,
+  /// Stopped by a debugger
+  traced,
+  zombie,
+  /// Exiting, after the zombie has been reaped
+  dead,
+  /// Idle kernel thread
+  idle,
+  /// Kernel thread parked while its CPU is offline
+  parked,
+  unknown,
+}
+
+class SchedulerInfo {
+  final SchedulerPolicy policy;
+  /// Real-time priority, 1-99 for Fifo and RoundRobin, 0 otherwise
+  final int priority;
+
+  const SchedulerInfo({required this.policy, required this.priority});
+
+  @override
+  int get hashCode => policy.hashCode ^ priority.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchedulerInfo &&
+          runtimeType == other.runtimeType &&
+          policy == other.policy &&
+          priority == other.priority;
+}
+
+/// Linux scheduling policy (see sched(7))
+enum SchedulerPolicy {
+  /// SCHED_OTHER, the default time-sharing policy
+  other,
+  /// SCHED_BATCH, for CPU-bound non-interactive work
+  batch,
+  /// SCHED_IDLE, runs only when the CPU has nothing else to do
+  idle,
+  /// SCHED_FIFO real-time
+  fifo,
+  /// SCHED_RR real-time
+  roundRobin,
+  /// SCHED_DEADLINE; cannot be set through this API
+  deadline,
+  unknown,
+}
+
+enum SeccompMode {
+  disabled,
+  /// Only read, write, _exit and sigreturn are allowed
+  strict,
+  /// Syscalls are checked by BPF filters
+  filter,
+}
+
+/// Privileges and confinement of a process, from `get_security_context`
+class SecurityContext {
+  final int pid;
+  final CapabilitySet effective;
+  final CapabilitySet permitted;
+  final CapabilitySet inheritable;
+  /// Upper limit of capabilities the process and its children can ever gain
+  final CapabilitySet bounding;
+  /// Kept across execve of unprivileged programs (Linux 4.3+)
+  final CapabilitySet ambient;
+  /// None when the kernel is built without seccomp
+  final SeccompMode? seccomp;
+  /// Number of attached filters (Linux 5.9+)
+  final int? seccompFilters;
+  /// Set when execve can no longer grant privileges (setuid bits, file capabilities)
+  final bool? noNewPrivs;
+  /// selinux, apparmor, smack or tomoyo
+  final String? lsm;
+  /// Label from /proc/<pid>/attr/current, e.g. "unconfined" or "system_u:system_r:sshd_t:s0"
+  final String? lsmLabel;
+
+  const SecurityContext({
+    required this.pid,
+    required this.effective,
+    required this.permitted,
+    required this.inheritable,
+    required this.bounding,
+    required this.ambient,
+    this.seccomp,
+    this.seccompFilters,
+    this.noNewPrivs,
+    this.lsm,
+    this.lsmLabel,
+  });
+
+  static Future<SecurityContext> default_() =>
+      RustLib.instance.api.crateApiSimpleSecurityContextDefault();
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      effective.hashCode ^
+      permitted.hashCode ^
+      inheritable.hashCode ^
+      bounding.hashCode ^
+      ambient.hashCode ^
+      seccomp.hashCode ^
+      seccompFilters.hashCode ^
+      noNewPrivs.hashCode ^
+      lsm.hashCode ^
+      lsmLabel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SecurityContext &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          effective == other.effective &&
+          permitted == other.permitted &&
+          inheritable == other.inheritable &&
+          bounding == other.bounding &&
+          ambient == other.ambient &&
+          seccomp == other.seccomp &&
+          seccompFilters == other.seccompFilters &&
+          noNewPrivs == other.noNewPrivs &&
+          lsm == other.lsm &&
+          lsmLabel == other.lsmLabel;
 }
 
 class SystemInfo {
@@ -198,6 +2059,8 @@ class SystemInfo {
 class SystemResourceInfo {
   final double cpuUsage;
   final Float64List cpuPerCore;
+  final CpuTimesBreakdown cpuTimes;
+  final List<CpuTimesBreakdown> cpuTimesPerCore;
   final BigInt memoryTotal;
   final BigInt memoryUsed;
   final BigInt memoryAvailable;
@@ -210,6 +2073,8 @@ class SystemResourceInfo {
   const SystemResourceInfo({
     required this.cpuUsage,
     required this.cpuPerCore,
+    required this.cpuTimes,
+    required this.cpuTimesPerCore,
     required this.memoryTotal,
     required this.memoryUsed,
     required this.memoryAvailable,
@@ -224,6 +2089,8 @@ class SystemResourceInfo {
   int get hashCode =>
       cpuUsage.hashCode ^
       cpuPerCore.hashCode ^
+      cpuTimes.hashCode ^
+      cpuTimesPerCore.hashCode ^
       memoryTotal.hashCode ^
       memoryUsed.hashCode ^
       memoryAvailable.hashCode ^
@@ -240,6 +2107,8 @@ class SystemResourceInfo {
           runtimeType == other.runtimeType &&
           cpuUsage == other.cpuUsage &&
           cpuPerCore == other.cpuPerCore &&
+          cpuTimes == other.cpuTimes &&
+          cpuTimesPerCore == other.cpuTimesPerCore &&
           memoryTotal == other.memoryTotal &&
           memoryUsed == other.memoryUsed &&
           memoryAvailable == other.memoryAvailable &&
@@ -249,3 +2118,116 @@ class SystemResourceInfo {
           diskUsage == other.diskUsage &&
           networkUsage == other.networkUsage;
 }
+
+/// Which step of `terminate_gracefully` ended the process
+enum TerminationOutcome {
+  /// Exited within the timeout after the polite request (SIGTERM / WM_CLOSE)
+  terminated,
+  /// Had to be killed (SIGKILL / TerminateProcess)
+  killed,
+}
+
+/// One thread of a process, from `get_threads`
+class ThreadInfo {
+  final int tid;
+  final String name;
+  final ProcessState state;
+  final String status;
+  /// Same scale as `ProcessInfo.cpu_usage`; 0 on the first call for a process
+  final double cpuUsage;
+  final BigInt userTimeMs;
+  final BigInt systemTimeMs;
+  final int? lastCpu;
+  /// Kernel priority (20 + nice, negative for real-time) on Linux, base priority on Windows
+  final int priority;
+  final int nice;
+  final BigInt voluntaryContextSwitches;
+  final BigInt involuntaryContextSwitches;
+
+  const ThreadInfo({
+    required this.tid,
+    required this.name,
+    required this.state,
+    required this.status,
+    required this.cpuUsage,
+    required this.userTimeMs,
+    required this.systemTimeMs,
+    this.lastCpu,
+    required this.priority,
+    required this.nice,
+    required this.voluntaryContextSwitches,
+    required this.involuntaryContextSwitches,
+  });
+
+  @override
+  int get hashCode =>
+      tid.hashCode ^
+      name.hashCode ^
+      state.hashCode ^
+      status.hashCode ^
+      cpuUsage.hashCode ^
+      userTimeMs.hashCode ^
+      systemTimeMs.hashCode ^
+      lastCpu.hashCode ^
+      priority.hashCode ^
+      nice.hashCode ^
+      voluntaryContextSwitches.hashCode ^
+      involuntaryContextSwitches.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThreadInfo &&
+          runtimeType == other.runtimeType &&
+          tid == other.tid &&
+          name == other.name &&
+          state == other.state &&
+          status == other.status &&
+          cpuUsage == other.cpuUsage &&
+          userTimeMs == other.userTimeMs &&
+          systemTimeMs == other.systemTimeMs &&
+          lastCpu == other.lastCpu &&
+          priority == other.priority &&
+          nice == other.nice &&
+          voluntaryContextSwitches == other.voluntaryContextSwitches &&
+          involuntaryContextSwitches == other.involuntaryContextSwitches;
+}
+
+/// Affinity and scheduling of one thread, from `get_thread_scheduling`
+class ThreadScheduling {
+  final int tid;
+  final String name;
+  /// CPU ids the thread may run on
+  final Uint32List affinity;
+  final SchedulerInfo scheduler;
+  final int? lastCpu;
+
+  const ThreadScheduling({
+    required this.tid,
+    required this.name,
+    required this.affinity,
+    required this.scheduler,
+    this.lastCpu,
+  });
+
+  @override
+  int get hashCode =>
+      tid.hashCode ^
+      name.hashCode ^
+      affinity.hashCode ^
+      scheduler.hashCode ^
+      lastCpu.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThreadScheduling &&
+          runtimeType == other.runtimeType &&
+          tid == other.tid &&
+          name == other.name &&
+          affinity == other.affinity &&
+          scheduler == other.scheduler &&
+          lastCpu == other.lastCpu;
+}
+
+enum TransportProtocol { tcp, udp }
@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1670184670;
+  int get rustContentHash => 1424805137;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,19 +77,136 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<CapabilitySet> crateApiSimpleCapabilitySetDefault();
+
+  Future<CgroupCpuStat> crateApiSimpleCgroupCpuStatDefault();
+
+  Future<CgroupIoStat> crateApiSimpleCgroupIoStatDefault();
+
+  Future<CgroupMemoryEvents> crateApiSimpleCgroupMemoryEventsDefault();
+
+  Future<CpuTimesBreakdown> crateApiSimpleCpuTimesBreakdownDefault();
+
+  Future<List<PathUsage>> crateApiSimpleFindProcessesUsing({
+    required String path,
+  });
+
   String crateApiSimpleGetBackendVersion();
 
+  List<CgroupInfo> crateApiSimpleGetCgroupTree();
+
+  Future<List<ConnectionInfo>> crateApiSimpleGetConnections();
+
+  Uint32List crateApiSimpleGetCpuAffinity({required int pid});
+
+  String crateApiSimpleGetCurrentUser();
+
+  List<DiskActivity> crateApiSimpleGetDiskActivity();
+
+  List<DiskInfo> crateApiSimpleGetDisks({required MountFilter filter});
+
+  MemoryMaps crateApiSimpleGetMemoryMaps({required int pid});
+
+  List<NetworkInterfaceInfo> crateApiSimpleGetNetworkInterfaces();
+
+  List<OpenFileInfo> crateApiSimpleGetOpenFiles({required int pid});
+
+  ProcessPriority crateApiSimpleGetPriority({required int pid});
+
+  ProcessDetails crateApiSimpleGetProcessDetails({required int pid});
+
+  ProcessMemory crateApiSimpleGetProcessMemory({required int pid});
+
+  Future<List<ProcessNetworkUsage>> crateApiSimpleGetProcessNetworkUsage();
+
   List<ProcessInfo> crateApiSimpleGetProcesses();
 
+  SchedulerInfo crateApiSimpleGetScheduler({required int pid});
+
+  SecurityContext crateApiSimpleGetSecurityContext({required int pid});
+
   SystemInfo crateApiSimpleGetSystemInfo();
 
   SystemResourceInfo crateApiSimpleGetSystemResources();
 
+  List<ThreadScheduling> crateApiSimpleGetThreadScheduling({required int pid});
+
+  List<ThreadInfo> crateApiSimpleGetThreads({required int pid});
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
-  bool crateApiSimpleKillProcess({required int pid});
+  Future<IoPriority> crateApiSimpleIoPriorityDefault();
+
+  void crateApiSimpleKillProcess({required int pid});
+
+  Future<List<ProcessKillResult>> crateApiSimpleKillProcessTree({
+    required int rootPid,
+    required KillTreeStrategy strategy,
+  });
+
+  Future<MappedFileSummary> crateApiSimpleMappedFileSummaryDefault();
+
+  Future<MountFilter> crateApiSimpleMountFilterDefault();
+
+  Future<Pressure> crateApiSimplePressureDefault();
+
+  Future<PressureLine> crateApiSimplePressureLineDefault();
+
+  Future<ProcessCredentials> crateApiSimpleProcessCredentialsDefault();
+
+  Future<ProcessDiskIo> crateApiSimpleProcessDiskIoDefault();
+
+  Future<ProcessMemory> crateApiSimpleProcessMemoryDefault();
+
+  void crateApiSimpleResumeProcess({
+    required int pid,
+    required bool includeChildren,
+  });
+
+  Future<SecurityContext> crateApiSimpleSecurityContextDefault();
+
+  void crateApiSimpleSendSignal({
+    required int pid,
+    required ProcessSignal signal,
+  });
+
+  void crateApiSimpleSetCpuAffinity({
+    required int pid,
+    required List<int> cpus,
+    required bool allThreads,
+  });
+
+  void crateApiSimpleSetCpuUsageMode({required CpuUsageMode mode});
+
+  void crateApiSimpleSetIoPriority({
+    required int pid,
+    required IoPriority priority,
+  });
+
+  void crateApiSimpleSetMemoryMetric({required MemoryMetric metric});
+
+  void crateApiSimpleSetPriority({
+    required int pid,
+    required PriorityLevel level,
+  });
+
+  void crateApiSimpleSetScheduler({
+    required int pid,
+    required SchedulerInfo scheduler,
+    required bool allThreads,
+  });
+
+  void crateApiSimpleSuspendProcess({
+    required int pid,
+    required bool includeChildren,
+  });
+
+  Future<TerminationOutcome> crateApiSimpleTerminateGracefully({
+    required int pid,
+    required BigInt timeoutMs,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -101,118 +218,225 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  String crateApiSimpleGetBackendVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CapabilitySet> crateApiSimpleCapabilitySetDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_capability_set,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleCapabilitySetDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCapabilitySetDefaultConstMeta =>
+      const TaskConstMeta(debugName: "capability_set_default", argNames: []);
+
+  @override
+  Future<CgroupCpuStat> crateApiSimpleCgroupCpuStatDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_cgroup_cpu_stat,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetBackendVersionConstMeta,
+        constMeta: kCrateApiSimpleCgroupCpuStatDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetBackendVersionConstMeta =>
-      const TaskConstMeta(debugName: "get_backend_version", argNames: []);
+  TaskConstMeta get kCrateApiSimpleCgroupCpuStatDefaultConstMeta =>
+      const TaskConstMeta(debugName: "cgroup_cpu_stat_default", argNames: []);
 
   @override
-  List<ProcessInfo> crateApiSimpleGetProcesses() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CgroupIoStat> crateApiSimpleCgroupIoStatDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_process_info,
+          decodeSuccessData: sse_decode_cgroup_io_stat,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetProcessesConstMeta,
+        constMeta: kCrateApiSimpleCgroupIoStatDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetProcessesConstMeta =>
-      const TaskConstMeta(debugName: "get_processes", argNames: []);
+  TaskConstMeta get kCrateApiSimpleCgroupIoStatDefaultConstMeta =>
+      const TaskConstMeta(debugName: "cgroup_io_stat_default", argNames: []);
 
   @override
-  SystemInfo crateApiSimpleGetSystemInfo() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CgroupMemoryEvents> crateApiSimpleCgroupMemoryEventsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_system_info,
+          decodeSuccessData: sse_decode_cgroup_memory_events,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetSystemInfoConstMeta,
+        constMeta: kCrateApiSimpleCgroupMemoryEventsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetSystemInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_system_info", argNames: []);
+  TaskConstMeta get kCrateApiSimpleCgroupMemoryEventsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "cgroup_memory_events_default",
+        argNames: [],
+      );
 
   @override
-  SystemResourceInfo crateApiSimpleGetSystemResources() {
+  Future<CpuTimesBreakdown> crateApiSimpleCpuTimesBreakdownDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cpu_times_breakdown,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleCpuTimesBreakdownDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCpuTimesBreakdownDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_times_breakdown_default",
+        argNames: [],
+      );
+
+  @override
+  Future<List<PathUsage>> crateApiSimpleFindProcessesUsing({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_path_usage,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleFindProcessesUsingConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleFindProcessesUsingConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_processes_using",
+        argNames: ["path"],
+      );
+
+  @override
+  String crateApiSimpleGetBackendVersion() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_system_resource_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetSystemResourcesConstMeta,
+        constMeta: kCrateApiSimpleGetBackendVersionConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetSystemResourcesConstMeta =>
-      const TaskConstMeta(debugName: "get_system_resources", argNames: []);
+  TaskConstMeta get kCrateApiSimpleGetBackendVersionConstMeta =>
+      const TaskConstMeta(debugName: "get_backend_version", argNames: []);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
+  List<CgroupInfo> crateApiSimpleGetCgroupTree() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_cgroup_info,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiSimpleGetCgroupTreeConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiSimpleGetCgroupTreeConstMeta =>
+      const TaskConstMeta(debugName: "get_cgroup_tree", argNames: []);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<List<ConnectionInfo>> crateApiSimpleGetConnections() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -220,531 +444,5005 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 9,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_connection_info,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
+        constMeta: kCrateApiSimpleGetConnectionsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSimpleGetConnectionsConstMeta =>
+      const TaskConstMeta(debugName: "get_connections", argNames: []);
 
   @override
-  bool crateApiSimpleKillProcess({required int pid}) {
+  Uint32List crateApiSimpleGetCpuAffinity({required int pid}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_u_32(pid, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: sse_decode_process_control_error,
         ),
-        constMeta: kCrateApiSimpleKillProcessConstMeta,
+        constMeta: kCrateApiSimpleGetCpuAffinityConstMeta,
         argValues: [pid],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleKillProcessConstMeta =>
-      const TaskConstMeta(debugName: "kill_process", argNames: ["pid"]);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiSimpleGetCpuAffinityConstMeta =>
+      const TaskConstMeta(debugName: "get_cpu_affinity", argNames: ["pid"]);
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  String crateApiSimpleGetCurrentUser() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetCurrentUserConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
+  TaskConstMeta get kCrateApiSimpleGetCurrentUserConstMeta =>
+      const TaskConstMeta(debugName: "get_current_user", argNames: []);
 
-  @protected
-  DiskInfo dco_decode_disk_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return DiskInfo(
-      name: dco_decode_String(arr[0]),
-      mountPoint: dco_decode_String(arr[1]),
-      totalSpace: dco_decode_u_64(arr[2]),
-      usedSpace: dco_decode_u_64(arr[3]),
-      availableSpace: dco_decode_u_64(arr[4]),
+  @override
+  List<DiskActivity> crateApiSimpleGetDiskActivity() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_disk_activity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetDiskActivityConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
+  TaskConstMeta get kCrateApiSimpleGetDiskActivityConstMeta =>
+      const TaskConstMeta(debugName: "get_disk_activity", argNames: []);
 
-  @protected
-  List<DiskInfo> dco_decode_list_disk_info(dynamic raw) {
+  @override
+  List<DiskInfo> crateApiSimpleGetDisks({required MountFilter filter}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_mount_filter(filter, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_disk_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetDisksConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetDisksConstMeta =>
+      const TaskConstMeta(debugName: "get_disks", argNames: ["filter"]);
+
+  @override
+  MemoryMaps crateApiSimpleGetMemoryMaps({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_memory_maps,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetMemoryMapsConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetMemoryMapsConstMeta =>
+      const TaskConstMeta(debugName: "get_memory_maps", argNames: ["pid"]);
+
+  @override
+  List<NetworkInterfaceInfo> crateApiSimpleGetNetworkInterfaces() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_network_interface_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetNetworkInterfacesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetNetworkInterfacesConstMeta =>
+      const TaskConstMeta(debugName: "get_network_interfaces", argNames: []);
+
+  @override
+  List<OpenFileInfo> crateApiSimpleGetOpenFiles({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_open_file_info,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetOpenFilesConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetOpenFilesConstMeta =>
+      const TaskConstMeta(debugName: "get_open_files", argNames: ["pid"]);
+
+  @override
+  ProcessPriority crateApiSimpleGetPriority({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_priority,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetPriorityConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetPriorityConstMeta =>
+      const TaskConstMeta(debugName: "get_priority", argNames: ["pid"]);
+
+  @override
+  ProcessDetails crateApiSimpleGetProcessDetails({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_details,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetProcessDetailsConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetProcessDetailsConstMeta =>
+      const TaskConstMeta(debugName: "get_process_details", argNames: ["pid"]);
+
+  @override
+  ProcessMemory crateApiSimpleGetProcessMemory({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_memory,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetProcessMemoryConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetProcessMemoryConstMeta =>
+      const TaskConstMeta(debugName: "get_process_memory", argNames: ["pid"]);
+
+  @override
+  Future<List<ProcessNetworkUsage>> crateApiSimpleGetProcessNetworkUsage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_process_network_usage,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetProcessNetworkUsageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetProcessNetworkUsageConstMeta =>
+      const TaskConstMeta(debugName: "get_process_network_usage", argNames: []);
+
+  @override
+  List<ProcessInfo> crateApiSimpleGetProcesses() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_process_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetProcessesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetProcessesConstMeta =>
+      const TaskConstMeta(debugName: "get_processes", argNames: []);
+
+  @override
+  SchedulerInfo crateApiSimpleGetScheduler({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scheduler_info,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetSchedulerConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSchedulerConstMeta =>
+      const TaskConstMeta(debugName: "get_scheduler", argNames: ["pid"]);
+
+  @override
+  SecurityContext crateApiSimpleGetSecurityContext({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_security_context,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetSecurityContextConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSecurityContextConstMeta =>
+      const TaskConstMeta(debugName: "get_security_context", argNames: ["pid"]);
+
+  @override
+  SystemInfo crateApiSimpleGetSystemInfo() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_system_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetSystemInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSystemInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_system_info", argNames: []);
+
+  @override
+  SystemResourceInfo crateApiSimpleGetSystemResources() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_system_resource_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGetSystemResourcesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSystemResourcesConstMeta =>
+      const TaskConstMeta(debugName: "get_system_resources", argNames: []);
+
+  @override
+  List<ThreadScheduling> crateApiSimpleGetThreadScheduling({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_thread_scheduling,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetThreadSchedulingConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetThreadSchedulingConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_thread_scheduling",
+        argNames: ["pid"],
+      );
+
+  @override
+  List<ThreadInfo> crateApiSimpleGetThreads({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_thread_info,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleGetThreadsConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetThreadsConstMeta =>
+      const TaskConstMeta(debugName: "get_threads", argNames: ["pid"]);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<IoPriority> crateApiSimpleIoPriorityDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_io_priority,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleIoPriorityDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleIoPriorityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "io_priority_default", argNames: []);
+
+  @override
+  void crateApiSimpleKillProcess({required int pid}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleKillProcessConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleKillProcessConstMeta =>
+      const TaskConstMeta(debugName: "kill_process", argNames: ["pid"]);
+
+  @override
+  Future<List<ProcessKillResult>> crateApiSimpleKillProcessTree({
+    required int rootPid,
+    required KillTreeStrategy strategy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(rootPid, serializer);
+          sse_encode_kill_tree_strategy(strategy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_process_kill_result,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleKillProcessTreeConstMeta,
+        argValues: [rootPid, strategy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleKillProcessTreeConstMeta =>
+      const TaskConstMeta(
+        debugName: "kill_process_tree",
+        argNames: ["rootPid", "strategy"],
+      );
+
+  @override
+  Future<MappedFileSummary> crateApiSimpleMappedFileSummaryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mapped_file_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMappedFileSummaryDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMappedFileSummaryDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "mapped_file_summary_default",
+        argNames: [],
+      );
+
+  @override
+  Future<MountFilter> crateApiSimpleMountFilterDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mount_filter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMountFilterDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMountFilterDefaultConstMeta =>
+      const TaskConstMeta(debugName: "mount_filter_default", argNames: []);
+
+  @override
+  Future<Pressure> crateApiSimplePressureDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pressure,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimplePressureDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePressureDefaultConstMeta =>
+      const TaskConstMeta(debugName: "pressure_default", argNames: []);
+
+  @override
+  Future<PressureLine> crateApiSimplePressureLineDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pressure_line,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimplePressureLineDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePressureLineDefaultConstMeta =>
+      const TaskConstMeta(debugName: "pressure_line_default", argNames: []);
+
+  @override
+  Future<ProcessCredentials> crateApiSimpleProcessCredentialsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_credentials,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleProcessCredentialsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleProcessCredentialsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_credentials_default",
+        argNames: [],
+      );
+
+  @override
+  Future<ProcessDiskIo> crateApiSimpleProcessDiskIoDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_disk_io,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleProcessDiskIoDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleProcessDiskIoDefaultConstMeta =>
+      const TaskConstMeta(debugName: "process_disk_io_default", argNames: []);
+
+  @override
+  Future<ProcessMemory> crateApiSimpleProcessMemoryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_process_memory,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleProcessMemoryDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleProcessMemoryDefaultConstMeta =>
+      const TaskConstMeta(debugName: "process_memory_default", argNames: []);
+
+  @override
+  void crateApiSimpleResumeProcess({
+    required int pid,
+    required bool includeChildren,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_bool(includeChildren, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleResumeProcessConstMeta,
+        argValues: [pid, includeChildren],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleResumeProcessConstMeta =>
+      const TaskConstMeta(
+        debugName: "resume_process",
+        argNames: ["pid", "includeChildren"],
+      );
+
+  @override
+  Future<SecurityContext> crateApiSimpleSecurityContextDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_security_context,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleSecurityContextDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSecurityContextDefaultConstMeta =>
+      const TaskConstMeta(debugName: "security_context_default", argNames: []);
+
+  @override
+  void crateApiSimpleSendSignal({
+    required int pid,
+    required ProcessSignal signal,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_process_signal(signal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSendSignalConstMeta,
+        argValues: [pid, signal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendSignalConstMeta => const TaskConstMeta(
+    debugName: "send_signal",
+    argNames: ["pid", "signal"],
+  );
+
+  @override
+  void crateApiSimpleSetCpuAffinity({
+    required int pid,
+    required List<int> cpus,
+    required bool allThreads,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_list_prim_u_32_loose(cpus, serializer);
+          sse_encode_bool(allThreads, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSetCpuAffinityConstMeta,
+        argValues: [pid, cpus, allThreads],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetCpuAffinityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cpu_affinity",
+        argNames: ["pid", "cpus", "allThreads"],
+      );
+
+  @override
+  void crateApiSimpleSetCpuUsageMode({required CpuUsageMode mode}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_cpu_usage_mode(mode, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleSetCpuUsageModeConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetCpuUsageModeConstMeta =>
+      const TaskConstMeta(debugName: "set_cpu_usage_mode", argNames: ["mode"]);
+
+  @override
+  void crateApiSimpleSetIoPriority({
+    required int pid,
+    required IoPriority priority,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_box_autoadd_io_priority(priority, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSetIoPriorityConstMeta,
+        argValues: [pid, priority],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetIoPriorityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_io_priority",
+        argNames: ["pid", "priority"],
+      );
+
+  @override
+  void crateApiSimpleSetMemoryMetric({required MemoryMetric metric}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_memory_metric(metric, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleSetMemoryMetricConstMeta,
+        argValues: [metric],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetMemoryMetricConstMeta =>
+      const TaskConstMeta(debugName: "set_memory_metric", argNames: ["metric"]);
+
+  @override
+  void crateApiSimpleSetPriority({
+    required int pid,
+    required PriorityLevel level,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_priority_level(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSetPriorityConstMeta,
+        argValues: [pid, level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetPriorityConstMeta => const TaskConstMeta(
+    debugName: "set_priority",
+    argNames: ["pid", "level"],
+  );
+
+  @override
+  void crateApiSimpleSetScheduler({
+    required int pid,
+    required SchedulerInfo scheduler,
+    required bool allThreads,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_box_autoadd_scheduler_info(scheduler, serializer);
+          sse_encode_bool(allThreads, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSetSchedulerConstMeta,
+        argValues: [pid, scheduler, allThreads],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetSchedulerConstMeta => const TaskConstMeta(
+    debugName: "set_scheduler",
+    argNames: ["pid", "scheduler", "allThreads"],
+  );
+
+  @override
+  void crateApiSimpleSuspendProcess({
+    required int pid,
+    required bool includeChildren,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_bool(includeChildren, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleSuspendProcessConstMeta,
+        argValues: [pid, includeChildren],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSuspendProcessConstMeta =>
+      const TaskConstMeta(
+        debugName: "suspend_process",
+        argNames: ["pid", "includeChildren"],
+      );
+
+  @override
+  Future<TerminationOutcome> crateApiSimpleTerminateGracefully({
+    required int pid,
+    required BigInt timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          sse_encode_u_64(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_termination_outcome,
+          decodeErrorData: sse_decode_process_control_error,
+        ),
+        constMeta: kCrateApiSimpleTerminateGracefullyConstMeta,
+        argValues: [pid, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleTerminateGracefullyConstMeta =>
+      const TaskConstMeta(
+        debugName: "terminate_gracefully",
+        argNames: ["pid", "timeoutMs"],
+      );
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CgroupCpuStat dco_decode_box_autoadd_cgroup_cpu_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cgroup_cpu_stat(raw);
+  }
+
+  @protected
+  CgroupMemoryEvents dco_decode_box_autoadd_cgroup_memory_events(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cgroup_memory_events(raw);
+  }
+
+  @protected
+  IoPriority dco_decode_box_autoadd_io_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_io_priority(raw);
+  }
+
+  @protected
+  MountFilter dco_decode_box_autoadd_mount_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mount_filter(raw);
+  }
+
+  @protected
+  Pressure dco_decode_box_autoadd_pressure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pressure(raw);
+  }
+
+  @protected
+  PressureLine dco_decode_box_autoadd_pressure_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pressure_line(raw);
+  }
+
+  @protected
+  ProcessControlError dco_decode_box_autoadd_process_control_error(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_control_error(raw);
+  }
+
+  @protected
+  ProcessCredentials dco_decode_box_autoadd_process_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_credentials(raw);
+  }
+
+  @protected
+  ProcessDiskIo dco_decode_box_autoadd_process_disk_io(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_disk_io(raw);
+  }
+
+  @protected
+  SchedulerInfo dco_decode_box_autoadd_scheduler_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scheduler_info(raw);
+  }
+
+  @protected
+  SeccompMode dco_decode_box_autoadd_seccomp_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_seccomp_mode(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CapabilitySet dco_decode_capability_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CapabilitySet(
+      mask: dco_decode_u_64(arr[0]),
+      names: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  CgroupCpuStat dco_decode_cgroup_cpu_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CgroupCpuStat(
+      usageUsec: dco_decode_u_64(arr[0]),
+      userUsec: dco_decode_u_64(arr[1]),
+      systemUsec: dco_decode_u_64(arr[2]),
+      nrPeriods: dco_decode_u_64(arr[3]),
+      nrThrottled: dco_decode_u_64(arr[4]),
+      throttledUsec: dco_decode_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  CgroupInfo dco_decode_cgroup_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return CgroupInfo(
+      path: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      parent: dco_decode_opt_String(arr[2]),
+      kind: dco_decode_cgroup_kind(arr[3]),
+      processCount: dco_decode_u_32(arr[4]),
+      cpu: dco_decode_opt_box_autoadd_cgroup_cpu_stat(arr[5]),
+      cpuUsage: dco_decode_f_64(arr[6]),
+      memoryCurrent: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      memoryMax: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      memoryEvents: dco_decode_opt_box_autoadd_cgroup_memory_events(arr[9]),
+      io: dco_decode_list_cgroup_io_stat(arr[10]),
+      ioReadBytes: dco_decode_u_64(arr[11]),
+      ioWriteBytes: dco_decode_u_64(arr[12]),
+      pidsCurrent: dco_decode_opt_box_autoadd_u_64(arr[13]),
+      cpuPressure: dco_decode_opt_box_autoadd_pressure(arr[14]),
+      memoryPressure: dco_decode_opt_box_autoadd_pressure(arr[15]),
+      ioPressure: dco_decode_opt_box_autoadd_pressure(arr[16]),
+    );
+  }
+
+  @protected
+  CgroupIoStat dco_decode_cgroup_io_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CgroupIoStat(
+      device: dco_decode_String(arr[0]),
+      readBytes: dco_decode_u_64(arr[1]),
+      writeBytes: dco_decode_u_64(arr[2]),
+      readIos: dco_decode_u_64(arr[3]),
+      writeIos: dco_decode_u_64(arr[4]),
+      discardBytes: dco_decode_u_64(arr[5]),
+      discardIos: dco_decode_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  CgroupKind dco_decode_cgroup_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CgroupKind.values[raw as int];
+  }
+
+  @protected
+  CgroupMemoryEvents dco_decode_cgroup_memory_events(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CgroupMemoryEvents(
+      low: dco_decode_u_64(arr[0]),
+      high: dco_decode_u_64(arr[1]),
+      max: dco_decode_u_64(arr[2]),
+      oom: dco_decode_u_64(arr[3]),
+      oomKill: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  ConnectionInfo dco_decode_connection_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ConnectionInfo(
+      protocol: dco_decode_transport_protocol(arr[0]),
+      ipv6: dco_decode_bool(arr[1]),
+      localAddress: dco_decode_String(arr[2]),
+      localPort: dco_decode_u_16(arr[3]),
+      remoteAddress: dco_decode_String(arr[4]),
+      remotePort: dco_decode_u_16(arr[5]),
+      state: dco_decode_connection_state(arr[6]),
+      sendQueue: dco_decode_u_64(arr[7]),
+      receiveQueue: dco_decode_u_64(arr[8]),
+      pid: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      uid: dco_decode_u_32(arr[10]),
+      inode: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  ConnectionState dco_decode_connection_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ConnectionState.values[raw as int];
+  }
+
+  @protected
+  CpuTimesBreakdown dco_decode_cpu_times_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return CpuTimesBreakdown(
+      user: dco_decode_f_64(arr[0]),
+      nice: dco_decode_f_64(arr[1]),
+      system: dco_decode_f_64(arr[2]),
+      idle: dco_decode_f_64(arr[3]),
+      iowait: dco_decode_f_64(arr[4]),
+      irq: dco_decode_f_64(arr[5]),
+      softirq: dco_decode_f_64(arr[6]),
+      steal: dco_decode_f_64(arr[7]),
+      guest: dco_decode_f_64(arr[8]),
+      guestNice: dco_decode_f_64(arr[9]),
+    );
+  }
+
+  @protected
+  CpuUsageMode dco_decode_cpu_usage_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CpuUsageMode.values[raw as int];
+  }
+
+  @protected
+  DiskActivity dco_decode_disk_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return DiskActivity(
+      name: dco_decode_String(arr[0]),
+      parent: dco_decode_opt_String(arr[1]),
+      readBytes: dco_decode_u_64(arr[2]),
+      writeBytes: dco_decode_u_64(arr[3]),
+      readBytesPerSec: dco_decode_f_64(arr[4]),
+      writeBytesPerSec: dco_decode_f_64(arr[5]),
+      readIops: dco_decode_f_64(arr[6]),
+      writeIops: dco_decode_f_64(arr[7]),
+      avgWaitMs: dco_decode_f_64(arr[8]),
+      utilization: dco_decode_f_64(arr[9]),
+    );
+  }
+
+  @protected
+  DiskInfo dco_decode_disk_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return DiskInfo(
+      name: dco_decode_String(arr[0]),
+      mountPoint: dco_decode_String(arr[1]),
+      totalSpace: dco_decode_u_64(arr[2]),
+      usedSpace: dco_decode_u_64(arr[3]),
+      availableSpace: dco_decode_u_64(arr[4]),
+      fsType: dco_decode_String(arr[5]),
+      device: dco_decode_String(arr[6]),
+      mountOptions: dco_decode_list_String(arr[7]),
+      readOnly: dco_decode_bool(arr[8]),
+      inodesTotal: dco_decode_u_64(arr[9]),
+      inodesUsed: dco_decode_u_64(arr[10]),
+      inodesFree: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  EnvironmentVariable dco_decode_environment_variable(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EnvironmentVariable(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FileDescriptorKind dco_decode_file_descriptor_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileDescriptorKind.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IoPriority dco_decode_io_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return IoPriority(
+      class_: dco_decode_io_priority_class(arr[0]),
+      level: dco_decode_u_8(arr[1]),
+    );
+  }
+
+  @protected
+  IoPriorityClass dco_decode_io_priority_class(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IoPriorityClass.values[raw as int];
+  }
+
+  @protected
+  KillOutcome dco_decode_kill_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KillOutcome.values[raw as int];
+  }
+
+  @protected
+  KillTreeStrategy dco_decode_kill_tree_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KillTreeStrategy.values[raw as int];
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<CgroupInfo> dco_decode_list_cgroup_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cgroup_info).toList();
+  }
+
+  @protected
+  List<CgroupIoStat> dco_decode_list_cgroup_io_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cgroup_io_stat).toList();
+  }
+
+  @protected
+  List<ConnectionInfo> dco_decode_list_connection_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_connection_info).toList();
+  }
+
+  @protected
+  List<CpuTimesBreakdown> dco_decode_list_cpu_times_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cpu_times_breakdown).toList();
+  }
+
+  @protected
+  List<DiskActivity> dco_decode_list_disk_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_disk_activity).toList();
+  }
+
+  @protected
+  List<DiskInfo> dco_decode_list_disk_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_disk_info).toList();
+  }
+
+  @protected
+  List<EnvironmentVariable> dco_decode_list_environment_variable(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_environment_variable).toList();
+  }
+
+  @protected
+  List<MappedFileSummary> dco_decode_list_mapped_file_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mapped_file_summary).toList();
+  }
+
+  @protected
+  List<MemoryRegion> dco_decode_list_memory_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_memory_region).toList();
+  }
+
+  @protected
+  List<NetworkInterfaceInfo> dco_decode_list_network_interface_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_network_interface_info)
+        .toList();
+  }
+
+  @protected
+  List<OpenFileInfo> dco_decode_list_open_file_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_open_file_info).toList();
+  }
+
+  @protected
+  List<PathUsage> dco_decode_list_path_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_path_usage).toList();
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ProcessDetailError> dco_decode_list_process_detail_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_process_detail_error).toList();
+  }
+
+  @protected
+  List<ProcessInfo> dco_decode_list_process_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_process_info).toList();
+  }
+
+  @protected
+  List<ProcessKillResult> dco_decode_list_process_kill_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_process_kill_result).toList();
+  }
+
+  @protected
+  List<ProcessNetworkUsage> dco_decode_list_process_network_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_process_network_usage)
+        .toList();
+  }
+
+  @protected
+  List<ThreadInfo> dco_decode_list_thread_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_thread_info).toList();
+  }
+
+  @protected
+  List<ThreadScheduling> dco_decode_list_thread_scheduling(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_thread_scheduling).toList();
+  }
+
+  @protected
+  MappedFileSummary dco_decode_mapped_file_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MappedFileSummary(
+      path: dco_decode_String(arr[0]),
+      regions: dco_decode_u_32(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      rss: dco_decode_u_64(arr[3]),
+      pss: dco_decode_u_64(arr[4]),
+      private: dco_decode_u_64(arr[5]),
+      swap: dco_decode_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  MemoryMaps dco_decode_memory_maps(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MemoryMaps(
+      regions: dco_decode_list_memory_region(arr[0]),
+      files: dco_decode_list_mapped_file_summary(arr[1]),
+    );
+  }
+
+  @protected
+  MemoryMetric dco_decode_memory_metric(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MemoryMetric.values[raw as int];
+  }
+
+  @protected
+  MemoryRegion dco_decode_memory_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return MemoryRegion(
+      start: dco_decode_u_64(arr[0]),
+      end: dco_decode_u_64(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      permissions: dco_decode_String(arr[3]),
+      offset: dco_decode_u_64(arr[4]),
+      device: dco_decode_String(arr[5]),
+      inode: dco_decode_u_64(arr[6]),
+      path: dco_decode_String(arr[7]),
+      deleted: dco_decode_bool(arr[8]),
+      rss: dco_decode_u_64(arr[9]),
+      pss: dco_decode_u_64(arr[10]),
+      private: dco_decode_u_64(arr[11]),
+      swap: dco_decode_u_64(arr[12]),
+    );
+  }
+
+  @protected
+  MountFilter dco_decode_mount_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MountFilter(
+      includePseudo: dco_decode_bool(arr[0]),
+      collapseBindMounts: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  NetworkAttribution dco_decode_network_attribution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NetworkAttribution.values[raw as int];
+  }
+
+  @protected
+  NetworkInfo dco_decode_network_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NetworkInfo(
+      bytesSent: dco_decode_u_64(arr[0]),
+      bytesReceived: dco_decode_u_64(arr[1]),
+      packetsSent: dco_decode_u_64(arr[2]),
+      packetsReceived: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  NetworkInterfaceInfo dco_decode_network_interface_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return NetworkInterfaceInfo(
+      name: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      isLoopback: dco_decode_bool(arr[2]),
+      isVirtual: dco_decode_bool(arr[3]),
+      operstate: dco_decode_String(arr[4]),
+      linkSpeedMbps: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      mtu: dco_decode_u_32(arr[6]),
+      macAddress: dco_decode_String(arr[7]),
+      ipv4Addresses: dco_decode_list_String(arr[8]),
+      ipv6Addresses: dco_decode_list_String(arr[9]),
+      bytesReceived: dco_decode_u_64(arr[10]),
+      bytesSent: dco_decode_u_64(arr[11]),
+      packetsReceived: dco_decode_u_64(arr[12]),
+      packetsSent: dco_decode_u_64(arr[13]),
+      receiveErrors: dco_decode_u_64(arr[14]),
+      sendErrors: dco_decode_u_64(arr[15]),
+      receiveDropped: dco_decode_u_64(arr[16]),
+      sendDropped: dco_decode_u_64(arr[17]),
+      receiveBytesPerSec: dco_decode_f_64(arr[18]),
+      sendBytesPerSec: dco_decode_f_64(arr[19]),
+    );
+  }
+
+  @protected
+  OpenFileInfo dco_decode_open_file_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return OpenFileInfo(
+      fd: dco_decode_u_32(arr[0]),
+      kind: dco_decode_file_descriptor_kind(arr[1]),
+      path: dco_decode_String(arr[2]),
+      flags: dco_decode_u_32(arr[3]),
+      readable: dco_decode_bool(arr[4]),
+      writable: dco_decode_bool(arr[5]),
+      position: dco_decode_u_64(arr[6]),
+      deleted: dco_decode_bool(arr[7]),
+      inode: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CgroupCpuStat? dco_decode_opt_box_autoadd_cgroup_cpu_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cgroup_cpu_stat(raw);
+  }
+
+  @protected
+  CgroupMemoryEvents? dco_decode_opt_box_autoadd_cgroup_memory_events(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_cgroup_memory_events(raw);
+  }
+
+  @protected
+  Pressure? dco_decode_opt_box_autoadd_pressure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pressure(raw);
+  }
+
+  @protected
+  PressureLine? dco_decode_opt_box_autoadd_pressure_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pressure_line(raw);
+  }
+
+  @protected
+  ProcessControlError? dco_decode_opt_box_autoadd_process_control_error(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_process_control_error(raw);
+  }
+
+  @protected
+  ProcessCredentials? dco_decode_opt_box_autoadd_process_credentials(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_process_credentials(raw);
+  }
+
+  @protected
+  ProcessDiskIo? dco_decode_opt_box_autoadd_process_disk_io(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_process_disk_io(raw);
+  }
+
+  @protected
+  SeccompMode? dco_decode_opt_box_autoadd_seccomp_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_seccomp_mode(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<EnvironmentVariable>? dco_decode_opt_list_environment_variable(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_environment_variable(raw);
+  }
+
+  @protected
+  PathUsage dco_decode_path_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PathUsage(
+      pid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      kind: dco_decode_path_usage_kind(arr[2]),
+      path: dco_decode_String(arr[3]),
+      fd: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  PathUsageKind dco_decode_path_usage_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PathUsageKind.values[raw as int];
+  }
+
+  @protected
+  Pressure dco_decode_pressure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Pressure(
+      some: dco_decode_pressure_line(arr[0]),
+      full: dco_decode_opt_box_autoadd_pressure_line(arr[1]),
+    );
+  }
+
+  @protected
+  PressureLine dco_decode_pressure_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PressureLine(
+      avg10: dco_decode_f_64(arr[0]),
+      avg60: dco_decode_f_64(arr[1]),
+      avg300: dco_decode_f_64(arr[2]),
+      totalUsec: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  PriorityLevel dco_decode_priority_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PriorityLevel.values[raw as int];
+  }
+
+  @protected
+  ProcessControlError dco_decode_process_control_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProcessControlError(
+      kind: dco_decode_process_control_error_kind(arr[0]),
+      code: dco_decode_i_32(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ProcessControlErrorKind dco_decode_process_control_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProcessControlErrorKind.values[raw as int];
+  }
+
+  @protected
+  ProcessCredentials dco_decode_process_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ProcessCredentials(
+      realUid: dco_decode_u_32(arr[0]),
+      effectiveUid: dco_decode_u_32(arr[1]),
+      savedUid: dco_decode_u_32(arr[2]),
+      realGid: dco_decode_u_32(arr[3]),
+      effectiveGid: dco_decode_u_32(arr[4]),
+      savedGid: dco_decode_u_32(arr[5]),
+      supplementaryGroups: dco_decode_list_prim_u_32_strict(arr[6]),
+      realUser: dco_decode_String(arr[7]),
+      effectiveUser: dco_decode_String(arr[8]),
+      group: dco_decode_String(arr[9]),
+      supplementaryGroupNames: dco_decode_list_String(arr[10]),
+    );
+  }
+
+  @protected
+  ProcessDetailError dco_decode_process_detail_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProcessDetailError(
+      field: dco_decode_process_detail_field(arr[0]),
+      error: dco_decode_process_control_error(arr[1]),
+    );
+  }
+
+  @protected
+  ProcessDetailField dco_decode_process_detail_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProcessDetailField.values[raw as int];
+  }
+
+  @protected
+  ProcessDetails dco_decode_process_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ProcessDetails(
+      pid: dco_decode_u_32(arr[0]),
+      exe: dco_decode_opt_String(arr[1]),
+      exeDeleted: dco_decode_bool(arr[2]),
+      cwd: dco_decode_opt_String(arr[3]),
+      root: dco_decode_opt_String(arr[4]),
+      argv: dco_decode_opt_list_String(arr[5]),
+      environment: dco_decode_opt_list_environment_variable(arr[6]),
+      errors: dco_decode_list_process_detail_error(arr[7]),
+    );
+  }
+
+  @protected
+  ProcessDiskIo dco_decode_process_disk_io(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ProcessDiskIo(
+      readBytes: dco_decode_u_64(arr[0]),
+      writeBytes: dco_decode_u_64(arr[1]),
+      cancelledWriteBytes: dco_decode_u_64(arr[2]),
+      readChars: dco_decode_u_64(arr[3]),
+      writeChars: dco_decode_u_64(arr[4]),
+      readSyscalls: dco_decode_u_64(arr[5]),
+      writeSyscalls: dco_decode_u_64(arr[6]),
+      readBytesPerSec: dco_decode_f_64(arr[7]),
+      writeBytesPerSec: dco_decode_f_64(arr[8]),
+    );
+  }
+
+  @protected
+  ProcessInfo dco_decode_process_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 21)
+      throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+    return ProcessInfo(
+      pid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      cpuUsage: dco_decode_f_64(arr[2]),
+      memoryUsage: dco_decode_u_64(arr[3]),
+      memoryMetric: dco_decode_memory_metric(arr[4]),
+      parentPid: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      status: dco_decode_String(arr[6]),
+      state: dco_decode_process_state(arr[7]),
+      command: dco_decode_String(arr[8]),
+      startTime: dco_decode_u_64(arr[9]),
+      age: dco_decode_u_64(arr[10]),
+      priority: dco_decode_priority_level(arr[11]),
+      nice: dco_decode_i_32(arr[12]),
+      ioPriority: dco_decode_io_priority(arr[13]),
+      lastCpu: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      threadCount: dco_decode_u_32(arr[15]),
+      diskIo: dco_decode_opt_box_autoadd_process_disk_io(arr[16]),
+      user: dco_decode_opt_String(arr[17]),
+      credentials: dco_decode_opt_box_autoadd_process_credentials(arr[18]),
+      privileged: dco_decode_bool(arr[19]),
+      cgroup: dco_decode_opt_String(arr[20]),
+    );
+  }
+
+  @protected
+  ProcessKillResult dco_decode_process_kill_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProcessKillResult(
+      pid: dco_decode_u_32(arr[0]),
+      parentPid: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      name: dco_decode_String(arr[2]),
+      outcome: dco_decode_kill_outcome(arr[3]),
+      error: dco_decode_opt_box_autoadd_process_control_error(arr[4]),
+    );
+  }
+
+  @protected
+  ProcessMemory dco_decode_process_memory(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ProcessMemory(
+      rss: dco_decode_u_64(arr[0]),
+      pss: dco_decode_u_64(arr[1]),
+      uss: dco_decode_u_64(arr[2]),
+      sharedClean: dco_decode_u_64(arr[3]),
+      sharedDirty: dco_decode_u_64(arr[4]),
+      privateClean: dco_decode_u_64(arr[5]),
+      privateDirty: dco_decode_u_64(arr[6]),
+      swap: dco_decode_u_64(arr[7]),
+      swapPss: dco_decode_u_64(arr[8]),
+      locked: dco_decode_u_64(arr[9]),
+      hugePages: dco_decode_u_64(arr[10]),
+    );
+  }
+
+  @protected
+  ProcessNetworkUsage dco_decode_process_network_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ProcessNetworkUsage(
+      pid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      receivedBytes: dco_decode_u_64(arr[2]),
+      sentBytes: dco_decode_u_64(arr[3]),
+      receiveBytesPerSec: dco_decode_f_64(arr[4]),
+      sendBytesPerSec: dco_decode_f_64(arr[5]),
+      connections: dco_decode_u_32(arr[6]),
+      source: dco_decode_network_attribution(arr[7]),
+      netNamespace: dco_decode_opt_box_autoadd_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  ProcessPriority dco_decode_process_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProcessPriority(
+      level: dco_decode_priority_level(arr[0]),
+      nice: dco_decode_i_32(arr[1]),
+      ioPriority: dco_decode_io_priority(arr[2]),
+    );
+  }
+
+  @protected
+  ProcessSignal dco_decode_process_signal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProcessSignal.values[raw as int];
+  }
+
+  @protected
+  ProcessState dco_decode_process_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProcessState.values[raw as int];
+  }
+
+  @protected
+  SchedulerInfo dco_decode_scheduler_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SchedulerInfo(
+      policy: dco_decode_scheduler_policy(arr[0]),
+      priority: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SchedulerPolicy dco_decode_scheduler_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SchedulerPolicy.values[raw as int];
+  }
+
+  @protected
+  SeccompMode dco_decode_seccomp_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SeccompMode.values[raw as int];
+  }
+
+  @protected
+  SecurityContext dco_decode_security_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return SecurityContext(
+      pid: dco_decode_u_32(arr[0]),
+      effective: dco_decode_capability_set(arr[1]),
+      permitted: dco_decode_capability_set(arr[2]),
+      inheritable: dco_decode_capability_set(arr[3]),
+      bounding: dco_decode_capability_set(arr[4]),
+      ambient: dco_decode_capability_set(arr[5]),
+      seccomp: dco_decode_opt_box_autoadd_seccomp_mode(arr[6]),
+      seccompFilters: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      noNewPrivs: dco_decode_opt_box_autoadd_bool(arr[8]),
+      lsm: dco_decode_opt_String(arr[9]),
+      lsmLabel: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  SystemInfo dco_decode_system_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SystemInfo(
+      osName: dco_decode_String(arr[0]),
+      osVersion: dco_decode_String(arr[1]),
+      kernelVersion: dco_decode_String(arr[2]),
+      hostname: dco_decode_String(arr[3]),
+      cpuBrand: dco_decode_String(arr[4]),
+      cpuCores: dco_decode_u_32(arr[5]),
+      totalMemory: dco_decode_u_64(arr[6]),
+      bootTime: dco_decode_u_64(arr[7]),
+      uptime: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  SystemResourceInfo dco_decode_system_resource_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return SystemResourceInfo(
+      cpuUsage: dco_decode_f_64(arr[0]),
+      cpuPerCore: dco_decode_list_prim_f_64_strict(arr[1]),
+      cpuTimes: dco_decode_cpu_times_breakdown(arr[2]),
+      cpuTimesPerCore: dco_decode_list_cpu_times_breakdown(arr[3]),
+      memoryTotal: dco_decode_u_64(arr[4]),
+      memoryUsed: dco_decode_u_64(arr[5]),
+      memoryAvailable: dco_decode_u_64(arr[6]),
+      swapTotal: dco_decode_u_64(arr[7]),
+      swapUsed: dco_decode_u_64(arr[8]),
+      swapFree: dco_decode_u_64(arr[9]),
+      diskUsage: dco_decode_list_disk_info(arr[10]),
+      networkUsage: dco_decode_network_info(arr[11]),
+    );
+  }
+
+  @protected
+  TerminationOutcome dco_decode_termination_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TerminationOutcome.values[raw as int];
+  }
+
+  @protected
+  ThreadInfo dco_decode_thread_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ThreadInfo(
+      tid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      state: dco_decode_process_state(arr[2]),
+      status: dco_decode_String(arr[3]),
+      cpuUsage: dco_decode_f_64(arr[4]),
+      userTimeMs: dco_decode_u_64(arr[5]),
+      systemTimeMs: dco_decode_u_64(arr[6]),
+      lastCpu: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      priority: dco_decode_i_32(arr[8]),
+      nice: dco_decode_i_32(arr[9]),
+      voluntaryContextSwitches: dco_decode_u_64(arr[10]),
+      involuntaryContextSwitches: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  ThreadScheduling dco_decode_thread_scheduling(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ThreadScheduling(
+      tid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      affinity: dco_decode_list_prim_u_32_strict(arr[2]),
+      scheduler: dco_decode_scheduler_info(arr[3]),
+      lastCpu: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TransportProtocol dco_decode_transport_protocol(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransportProtocol.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_disk_info).toList();
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CgroupCpuStat sse_decode_box_autoadd_cgroup_cpu_stat(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cgroup_cpu_stat(deserializer));
+  }
+
+  @protected
+  CgroupMemoryEvents sse_decode_box_autoadd_cgroup_memory_events(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cgroup_memory_events(deserializer));
+  }
+
+  @protected
+  IoPriority sse_decode_box_autoadd_io_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_io_priority(deserializer));
+  }
+
+  @protected
+  MountFilter sse_decode_box_autoadd_mount_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mount_filter(deserializer));
+  }
+
+  @protected
+  Pressure sse_decode_box_autoadd_pressure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pressure(deserializer));
+  }
+
+  @protected
+  PressureLine sse_decode_box_autoadd_pressure_line(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pressure_line(deserializer));
+  }
+
+  @protected
+  ProcessControlError sse_decode_box_autoadd_process_control_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_control_error(deserializer));
+  }
+
+  @protected
+  ProcessCredentials sse_decode_box_autoadd_process_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_credentials(deserializer));
+  }
+
+  @protected
+  ProcessDiskIo sse_decode_box_autoadd_process_disk_io(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_disk_io(deserializer));
+  }
+
+  @protected
+  SchedulerInfo sse_decode_box_autoadd_scheduler_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scheduler_info(deserializer));
+  }
+
+  @protected
+  SeccompMode sse_decode_box_autoadd_seccomp_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_seccomp_mode(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CapabilitySet sse_decode_capability_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mask = sse_decode_u_64(deserializer);
+    var var_names = sse_decode_list_String(deserializer);
+    return CapabilitySet(mask: var_mask, names: var_names);
+  }
+
+  @protected
+  CgroupCpuStat sse_decode_cgroup_cpu_stat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_usageUsec = sse_decode_u_64(deserializer);
+    var var_userUsec = sse_decode_u_64(deserializer);
+    var var_systemUsec = sse_decode_u_64(deserializer);
+    var var_nrPeriods = sse_decode_u_64(deserializer);
+    var var_nrThrottled = sse_decode_u_64(deserializer);
+    var var_throttledUsec = sse_decode_u_64(deserializer);
+    return CgroupCpuStat(
+      usageUsec: var_usageUsec,
+      userUsec: var_userUsec,
+      systemUsec: var_systemUsec,
+      nrPeriods: var_nrPeriods,
+      nrThrottled: var_nrThrottled,
+      throttledUsec: var_throttledUsec,
+    );
+  }
+
+  @protected
+  CgroupInfo sse_decode_cgroup_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_parent = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_cgroup_kind(deserializer);
+    var var_processCount = sse_decode_u_32(deserializer);
+    var var_cpu = sse_decode_opt_box_autoadd_cgroup_cpu_stat(deserializer);
+    var var_cpuUsage = sse_decode_f_64(deserializer);
+    var var_memoryCurrent = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_memoryMax = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_memoryEvents = sse_decode_opt_box_autoadd_cgroup_memory_events(
+      deserializer,
+    );
+    var var_io = sse_decode_list_cgroup_io_stat(deserializer);
+    var var_ioReadBytes = sse_decode_u_64(deserializer);
+    var var_ioWriteBytes = sse_decode_u_64(deserializer);
+    var var_pidsCurrent = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_cpuPressure = sse_decode_opt_box_autoadd_pressure(deserializer);
+    var var_memoryPressure = sse_decode_opt_box_autoadd_pressure(deserializer);
+    var var_ioPressure = sse_decode_opt_box_autoadd_pressure(deserializer);
+    return CgroupInfo(
+      path: var_path,
+      name: var_name,
+      parent: var_parent,
+      kind: var_kind,
+      processCount: var_processCount,
+      cpu: var_cpu,
+      cpuUsage: var_cpuUsage,
+      memoryCurrent: var_memoryCurrent,
+      memoryMax: var_memoryMax,
+      memoryEvents: var_memoryEvents,
+      io: var_io,
+      ioReadBytes: var_ioReadBytes,
+      ioWriteBytes: var_ioWriteBytes,
+      pidsCurrent: var_pidsCurrent,
+      cpuPressure: var_cpuPressure,
+      memoryPressure: var_memoryPressure,
+      ioPressure: var_ioPressure,
+    );
+  }
+
+  @protected
+  CgroupIoStat sse_decode_cgroup_io_stat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_device = sse_decode_String(deserializer);
+    var var_readBytes = sse_decode_u_64(deserializer);
+    var var_writeBytes = sse_decode_u_64(deserializer);
+    var var_readIos = sse_decode_u_64(deserializer);
+    var var_writeIos = sse_decode_u_64(deserializer);
+    var var_discardBytes = sse_decode_u_64(deserializer);
+    var var_discardIos = sse_decode_u_64(deserializer);
+    return CgroupIoStat(
+      device: var_device,
+      readBytes: var_readBytes,
+      writeBytes: var_writeBytes,
+      readIos: var_readIos,
+      writeIos: var_writeIos,
+      discardBytes: var_discardBytes,
+      discardIos: var_discardIos,
+    );
+  }
+
+  @protected
+  CgroupKind sse_decode_cgroup_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CgroupKind.values[inner];
+  }
+
+  @protected
+  CgroupMemoryEvents sse_decode_cgroup_memory_events(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_low = sse_decode_u_64(deserializer);
+    var var_high = sse_decode_u_64(deserializer);
+    var var_max = sse_decode_u_64(deserializer);
+    var var_oom = sse_decode_u_64(deserializer);
+    var var_oomKill = sse_decode_u_64(deserializer);
+    return CgroupMemoryEvents(
+      low: var_low,
+      high: var_high,
+      max: var_max,
+      oom: var_oom,
+      oomKill: var_oomKill,
+    );
+  }
+
+  @protected
+  ConnectionInfo sse_decode_connection_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_protocol = sse_decode_transport_protocol(deserializer);
+    var var_ipv6 = sse_decode_bool(deserializer);
+    var var_localAddress = sse_decode_String(deserializer);
+    var var_localPort = sse_decode_u_16(deserializer);
+    var var_remoteAddress = sse_decode_String(deserializer);
+    var var_remotePort = sse_decode_u_16(deserializer);
+    var var_state = sse_decode_connection_state(deserializer);
+    var var_sendQueue = sse_decode_u_64(deserializer);
+    var var_receiveQueue = sse_decode_u_64(deserializer);
+    var var_pid = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_uid = sse_decode_u_32(deserializer);
+    var var_inode = sse_decode_u_64(deserializer);
+    return ConnectionInfo(
+      protocol: var_protocol,
+      ipv6: var_ipv6,
+      localAddress: var_localAddress,
+      localPort: var_localPort,
+      remoteAddress: var_remoteAddress,
+      remotePort: var_remotePort,
+      state: var_state,
+      sendQueue: var_sendQueue,
+      receiveQueue: var_receiveQueue,
+      pid: var_pid,
+      uid: var_uid,
+      inode: var_inode,
+    );
+  }
+
+  @protected
+  ConnectionState sse_decode_connection_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ConnectionState.values[inner];
+  }
+
+  @protected
+  CpuTimesBreakdown sse_decode_cpu_times_breakdown(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_user = sse_decode_f_64(deserializer);
+    var var_nice = sse_decode_f_64(deserializer);
+    var var_system = sse_decode_f_64(deserializer);
+    var var_idle = sse_decode_f_64(deserializer);
+    var var_iowait = sse_decode_f_64(deserializer);
+    var var_irq = sse_decode_f_64(deserializer);
+    var var_softirq = sse_decode_f_64(deserializer);
+    var var_steal = sse_decode_f_64(deserializer);
+    var var_guest = sse_decode_f_64(deserializer);
+    var var_guestNice = sse_decode_f_64(deserializer);
+    return CpuTimesBreakdown(
+      user: var_user,
+      nice: var_nice,
+      system: var_system,
+      idle: var_idle,
+      iowait: var_iowait,
+      irq: var_irq,
+      softirq: var_softirq,
+      steal: var_steal,
+      guest: var_guest,
+      guestNice: var_guestNice,
+    );
+  }
+
+  @protected
+  CpuUsageMode sse_decode_cpu_usage_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CpuUsageMode.values[inner];
+  }
+
+  @protected
+  DiskActivity sse_decode_disk_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_parent = sse_decode_opt_String(deserializer);
+    var var_readBytes = sse_decode_u_64(deserializer);
+    var var_writeBytes = sse_decode_u_64(deserializer);
+    var var_readBytesPerSec = sse_decode_f_64(deserializer);
+    var var_writeBytesPerSec = sse_decode_f_64(deserializer);
+    var var_readIops = sse_decode_f_64(deserializer);
+    var var_writeIops = sse_decode_f_64(deserializer);
+    var var_avgWaitMs = sse_decode_f_64(deserializer);
+    var var_utilization = sse_decode_f_64(deserializer);
+    return DiskActivity(
+      name: var_name,
+      parent: var_parent,
+      readBytes: var_readBytes,
+      writeBytes: var_writeBytes,
+      readBytesPerSec: var_readBytesPerSec,
+      writeBytesPerSec: var_writeBytesPerSec,
+      readIops: var_readIops,
+      writeIops: var_writeIops,
+      avgWaitMs: var_avgWaitMs,
+      utilization: var_utilization,
+    );
+  }
+
+  @protected
+  DiskInfo sse_decode_disk_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_mountPoint = sse_decode_String(deserializer);
+    var var_totalSpace = sse_decode_u_64(deserializer);
+    var var_usedSpace = sse_decode_u_64(deserializer);
+    var var_availableSpace = sse_decode_u_64(deserializer);
+    var var_fsType = sse_decode_String(deserializer);
+    var var_device = sse_decode_String(deserializer);
+    var var_mountOptions = sse_decode_list_String(deserializer);
+    var var_readOnly = sse_decode_bool(deserializer);
+    var var_inodesTotal = sse_decode_u_64(deserializer);
+    var var_inodesUsed = sse_decode_u_64(deserializer);
+    var var_inodesFree = sse_decode_u_64(deserializer);
+    return DiskInfo(
+      name: var_name,
+      mountPoint: var_mountPoint,
+      totalSpace: var_totalSpace,
+      usedSpace: var_usedSpace,
+      availableSpace: var_availableSpace,
+      fsType: var_fsType,
+      device: var_device,
+      mountOptions: var_mountOptions,
+      readOnly: var_readOnly,
+      inodesTotal: var_inodesTotal,
+      inodesUsed: var_inodesUsed,
+      inodesFree: var_inodesFree,
+    );
+  }
+
+  @protected
+  EnvironmentVariable sse_decode_environment_variable(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return EnvironmentVariable(name: var_name, value: var_value);
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FileDescriptorKind sse_decode_file_descriptor_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileDescriptorKind.values[inner];
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  IoPriority sse_decode_io_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_class_ = sse_decode_io_priority_class(deserializer);
+    var var_level = sse_decode_u_8(deserializer);
+    return IoPriority(class_: var_class_, level: var_level);
+  }
+
+  @protected
+  IoPriorityClass sse_decode_io_priority_class(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IoPriorityClass.values[inner];
+  }
+
+  @protected
+  KillOutcome sse_decode_kill_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KillOutcome.values[inner];
+  }
+
+  @protected
+  KillTreeStrategy sse_decode_kill_tree_strategy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KillTreeStrategy.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CgroupInfo> sse_decode_list_cgroup_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CgroupInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cgroup_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CgroupIoStat> sse_decode_list_cgroup_io_stat(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CgroupIoStat>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cgroup_io_stat(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConnectionInfo> sse_decode_list_connection_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConnectionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_connection_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CpuTimesBreakdown> sse_decode_list_cpu_times_breakdown(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CpuTimesBreakdown>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cpu_times_breakdown(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiskActivity> sse_decode_list_disk_activity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiskActivity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_disk_activity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiskInfo> sse_decode_list_disk_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiskInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_disk_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EnvironmentVariable> sse_decode_list_environment_variable(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EnvironmentVariable>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_environment_variable(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MappedFileSummary> sse_decode_list_mapped_file_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MappedFileSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mapped_file_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemoryRegion> sse_decode_list_memory_region(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MemoryRegion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_memory_region(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NetworkInterfaceInfo> sse_decode_list_network_interface_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NetworkInterfaceInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_network_interface_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OpenFileInfo> sse_decode_list_open_file_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OpenFileInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_open_file_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PathUsage> sse_decode_list_path_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PathUsage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_path_usage(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProcessDetailError> sse_decode_list_process_detail_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProcessDetailError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_process_detail_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProcessInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_process_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ProcessKillResult> sse_decode_list_process_kill_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProcessKillResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_process_kill_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ProcessNetworkUsage> sse_decode_list_process_network_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProcessNetworkUsage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_process_network_usage(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ThreadInfo> sse_decode_list_thread_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ThreadInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_thread_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ThreadScheduling> sse_decode_list_thread_scheduling(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ThreadScheduling>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_thread_scheduling(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MappedFileSummary sse_decode_mapped_file_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_regions = sse_decode_u_32(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_rss = sse_decode_u_64(deserializer);
+    var var_pss = sse_decode_u_64(deserializer);
+    var var_private = sse_decode_u_64(deserializer);
+    var var_swap = sse_decode_u_64(deserializer);
+    return MappedFileSummary(
+      path: var_path,
+      regions: var_regions,
+      size: var_size,
+      rss: var_rss,
+      pss: var_pss,
+      private: var_private,
+      swap: var_swap,
+    );
+  }
+
+  @protected
+  MemoryMaps sse_decode_memory_maps(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_regions = sse_decode_list_memory_region(deserializer);
+    var var_files = sse_decode_list_mapped_file_summary(deserializer);
+    return MemoryMaps(regions: var_regions, files: var_files);
+  }
+
+  @protected
+  MemoryMetric sse_decode_memory_metric(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MemoryMetric.values[inner];
+  }
+
+  @protected
+  MemoryRegion sse_decode_memory_region(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_64(deserializer);
+    var var_end = sse_decode_u_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_permissions = sse_decode_String(deserializer);
+    var var_offset = sse_decode_u_64(deserializer);
+    var var_device = sse_decode_String(deserializer);
+    var var_inode = sse_decode_u_64(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_deleted = sse_decode_bool(deserializer);
+    var var_rss = sse_decode_u_64(deserializer);
+    var var_pss = sse_decode_u_64(deserializer);
+    var var_private = sse_decode_u_64(deserializer);
+    var var_swap = sse_decode_u_64(deserializer);
+    return MemoryRegion(
+      start: var_start,
+      end: var_end,
+      size: var_size,
+      permissions: var_permissions,
+      offset: var_offset,
+      device: var_device,
+      inode: var_inode,
+      path: var_path,
+      deleted: var_deleted,
+      rss: var_rss,
+      pss: var_pss,
+      private: var_private,
+      swap: var_swap,
+    );
+  }
+
+  @protected
+  MountFilter sse_decode_mount_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_includePseudo = sse_decode_bool(deserializer);
+    var var_collapseBindMounts = sse_decode_bool(deserializer);
+    return MountFilter(
+      includePseudo: var_includePseudo,
+      collapseBindMounts: var_collapseBindMounts,
+    );
+  }
+
+  @protected
+  NetworkAttribution sse_decode_network_attribution(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NetworkAttribution.values[inner];
+  }
+
+  @protected
+  NetworkInfo sse_decode_network_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_packetsSent = sse_decode_u_64(deserializer);
+    var var_packetsReceived = sse_decode_u_64(deserializer);
+    return NetworkInfo(
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      packetsSent: var_packetsSent,
+      packetsReceived: var_packetsReceived,
+    );
+  }
+
+  @protected
+  NetworkInterfaceInfo sse_decode_network_interface_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_isLoopback = sse_decode_bool(deserializer);
+    var var_isVirtual = sse_decode_bool(deserializer);
+    var var_operstate = sse_decode_String(deserializer);
+    var var_linkSpeedMbps = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_mtu = sse_decode_u_32(deserializer);
+    var var_macAddress = sse_decode_String(deserializer);
+    var var_ipv4Addresses = sse_decode_list_String(deserializer);
+    var var_ipv6Addresses = sse_decode_list_String(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_packetsReceived = sse_decode_u_64(deserializer);
+    var var_packetsSent = sse_decode_u_64(deserializer);
+    var var_receiveErrors = sse_decode_u_64(deserializer);
+    var var_sendErrors = sse_decode_u_64(deserializer);
+    var var_receiveDropped = sse_decode_u_64(deserializer);
+    var var_sendDropped = sse_decode_u_64(deserializer);
+    var var_receiveBytesPerSec = sse_decode_f_64(deserializer);
+    var var_sendBytesPerSec = sse_decode_f_64(deserializer);
+    return NetworkInterfaceInfo(
+      name: var_name,
+      kind: var_kind,
+      isLoopback: var_isLoopback,
+      isVirtual: var_isVirtual,
+      operstate: var_operstate,
+      linkSpeedMbps: var_linkSpeedMbps,
+      mtu: var_mtu,
+      macAddress: var_macAddress,
+      ipv4Addresses: var_ipv4Addresses,
+      ipv6Addresses: var_ipv6Addresses,
+      bytesReceived: var_bytesReceived,
+      bytesSent: var_bytesSent,
+      packetsReceived: var_packetsReceived,
+      packetsSent: var_packetsSent,
+      receiveErrors: var_receiveErrors,
+      sendErrors: var_sendErrors,
+      receiveDropped: var_receiveDropped,
+      sendDropped: var_sendDropped,
+      receiveBytesPerSec: var_receiveBytesPerSec,
+      sendBytesPerSec: var_sendBytesPerSec,
+    );
+  }
+
+  @protected
+  OpenFileInfo sse_decode_open_file_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fd = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_file_descriptor_kind(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_flags = sse_decode_u_32(deserializer);
+    var var_readable = sse_decode_bool(deserializer);
+    var var_writable = sse_decode_bool(deserializer);
+    var var_position = sse_decode_u_64(deserializer);
+    var var_deleted = sse_decode_bool(deserializer);
+    var var_inode = sse_decode_u_64(deserializer);
+    return OpenFileInfo(
+      fd: var_fd,
+      kind: var_kind,
+      path: var_path,
+      flags: var_flags,
+      readable: var_readable,
+      writable: var_writable,
+      position: var_position,
+      deleted: var_deleted,
+      inode: var_inode,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CgroupCpuStat? sse_decode_opt_box_autoadd_cgroup_cpu_stat(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cgroup_cpu_stat(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CgroupMemoryEvents? sse_decode_opt_box_autoadd_cgroup_memory_events(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cgroup_memory_events(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Pressure? sse_decode_opt_box_autoadd_pressure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pressure(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PressureLine? sse_decode_opt_box_autoadd_pressure_line(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pressure_line(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessControlError? sse_decode_opt_box_autoadd_process_control_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_process_control_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessCredentials? sse_decode_opt_box_autoadd_process_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_process_credentials(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessDiskIo? sse_decode_opt_box_autoadd_process_disk_io(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_process_disk_io(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SeccompMode? sse_decode_opt_box_autoadd_seccomp_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_seccomp_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<EnvironmentVariable>? sse_decode_opt_list_environment_variable(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_environment_variable(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PathUsage sse_decode_path_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_path_usage_kind(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_fd = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return PathUsage(
+      pid: var_pid,
+      name: var_name,
+      kind: var_kind,
+      path: var_path,
+      fd: var_fd,
+    );
+  }
+
+  @protected
+  PathUsageKind sse_decode_path_usage_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PathUsageKind.values[inner];
+  }
+
+  @protected
+  Pressure sse_decode_pressure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_some = sse_decode_pressure_line(deserializer);
+    var var_full = sse_decode_opt_box_autoadd_pressure_line(deserializer);
+    return Pressure(some: var_some, full: var_full);
+  }
+
+  @protected
+  PressureLine sse_decode_pressure_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_avg10 = sse_decode_f_64(deserializer);
+    var var_avg60 = sse_decode_f_64(deserializer);
+    var var_avg300 = sse_decode_f_64(deserializer);
+    var var_totalUsec = sse_decode_u_64(deserializer);
+    return PressureLine(
+      avg10: var_avg10,
+      avg60: var_avg60,
+      avg300: var_avg300,
+      totalUsec: var_totalUsec,
+    );
+  }
+
+  @protected
+  PriorityLevel sse_decode_priority_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PriorityLevel.values[inner];
+  }
+
+  @protected
+  ProcessControlError sse_decode_process_control_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_process_control_error_kind(deserializer);
+    var var_code = sse_decode_i_32(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ProcessControlError(
+      kind: var_kind,
+      code: var_code,
+      message: var_message,
+    );
+  }
+
+  @protected
+  ProcessControlErrorKind sse_decode_process_control_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProcessControlErrorKind.values[inner];
+  }
+
+  @protected
+  ProcessCredentials sse_decode_process_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_realUid = sse_decode_u_32(deserializer);
+    var var_effectiveUid = sse_decode_u_32(deserializer);
+    var var_savedUid = sse_decode_u_32(deserializer);
+    var var_realGid = sse_decode_u_32(deserializer);
+    var var_effectiveGid = sse_decode_u_32(deserializer);
+    var var_savedGid = sse_decode_u_32(deserializer);
+    var var_supplementaryGroups = sse_decode_list_prim_u_32_strict(
+      deserializer,
+    );
+    var var_realUser = sse_decode_String(deserializer);
+    var var_effectiveUser = sse_decode_String(deserializer);
+    var var_group = sse_decode_String(deserializer);
+    var var_supplementaryGroupNames = sse_decode_list_String(deserializer);
+    return ProcessCredentials(
+      realUid: var_realUid,
+      effectiveUid: var_effectiveUid,
+      savedUid: var_savedUid,
+      realGid: var_realGid,
+      effectiveGid: var_effectiveGid,
+      savedGid: var_savedGid,
+      supplementaryGroups: var_supplementaryGroups,
+      realUser: var_realUser,
+      effectiveUser: var_effectiveUser,
+      group: var_group,
+      supplementaryGroupNames: var_supplementaryGroupNames,
+    );
+  }
+
+  @protected
+  ProcessDetailError sse_decode_process_detail_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_process_detail_field(deserializer);
+    var var_error = sse_decode_process_control_error(deserializer);
+    return ProcessDetailError(field: var_field, error: var_error);
+  }
+
+  @protected
+  ProcessDetailField sse_decode_process_detail_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProcessDetailField.values[inner];
+  }
+
+  @protected
+  ProcessDetails sse_decode_process_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_exe = sse_decode_opt_String(deserializer);
+    var var_exeDeleted = sse_decode_bool(deserializer);
+    var var_cwd = sse_decode_opt_String(deserializer);
+    var var_root = sse_decode_opt_String(deserializer);
+    var var_argv = sse_decode_opt_list_String(deserializer);
+    var var_environment = sse_decode_opt_list_environment_variable(
+      deserializer,
+    );
+    var var_errors = sse_decode_list_process_detail_error(deserializer);
+    return ProcessDetails(
+      pid: var_pid,
+      exe: var_exe,
+      exeDeleted: var_exeDeleted,
+      cwd: var_cwd,
+      root: var_root,
+      argv: var_argv,
+      environment: var_environment,
+      errors: var_errors,
+    );
+  }
+
+  @protected
+  ProcessDiskIo sse_decode_process_disk_io(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_readBytes = sse_decode_u_64(deserializer);
+    var var_writeBytes = sse_decode_u_64(deserializer);
+    var var_cancelledWriteBytes = sse_decode_u_64(deserializer);
+    var var_readChars = sse_decode_u_64(deserializer);
+    var var_writeChars = sse_decode_u_64(deserializer);
+    var var_readSyscalls = sse_decode_u_64(deserializer);
+    var var_writeSyscalls = sse_decode_u_64(deserializer);
+    var var_readBytesPerSec = sse_decode_f_64(deserializer);
+    var var_writeBytesPerSec = sse_decode_f_64(deserializer);
+    return ProcessDiskIo(
+      readBytes: var_readBytes,
+      writeBytes: var_writeBytes,
+      cancelledWriteBytes: var_cancelledWriteBytes,
+      readChars: var_readChars,
+      writeChars: var_writeChars,
+      readSyscalls: var_readSyscalls,
+      writeSyscalls: var_writeSyscalls,
+      readBytesPerSec: var_readBytesPerSec,
+      writeBytesPerSec: var_writeBytesPerSec,
+    );
+  }
+
+  @protected
+  ProcessInfo sse_decode_process_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_cpuUsage = sse_decode_f_64(deserializer);
+    var var_memoryUsage = sse_decode_u_64(deserializer);
+    var var_memoryMetric = sse_decode_memory_metric(deserializer);
+    var var_parentPid = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_state = sse_decode_process_state(deserializer);
+    var var_command = sse_decode_String(deserializer);
+    var var_startTime = sse_decode_u_64(deserializer);
+    var var_age = sse_decode_u_64(deserializer);
+    var var_priority = sse_decode_priority_level(deserializer);
+    var var_nice = sse_decode_i_32(deserializer);
+    var var_ioPriority = sse_decode_io_priority(deserializer);
+    var var_lastCpu = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_threadCount = sse_decode_u_32(deserializer);
+    var var_diskIo = sse_decode_opt_box_autoadd_process_disk_io(deserializer);
+    var var_user = sse_decode_opt_String(deserializer);
+    var var_credentials = sse_decode_opt_box_autoadd_process_credentials(
+      deserializer,
+    );
+    var var_privileged = sse_decode_bool(deserializer);
+    var var_cgroup = sse_decode_opt_String(deserializer);
+    return ProcessInfo(
+      pid: var_pid,
+      name: var_name,
+      cpuUsage: var_cpuUsage,
+      memoryUsage: var_memoryUsage,
+      memoryMetric: var_memoryMetric,
+      parentPid: var_parentPid,
+      status: var_status,
+      state: var_state,
+      command: var_command,
+      startTime: var_startTime,
+      age: var_age,
+      priority: var_priority,
+      nice: var_nice,
+      ioPriority: var_ioPriority,
+      lastCpu: var_lastCpu,
+      threadCount: var_threadCount,
+      diskIo: var_diskIo,
+      user: var_user,
+      credentials: var_credentials,
+      privileged: var_privileged,
+      cgroup: var_cgroup,
+    );
+  }
+
+  @protected
+  ProcessKillResult sse_decode_process_kill_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_parentPid = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_outcome = sse_decode_kill_outcome(deserializer);
+    var var_error = sse_decode_opt_box_autoadd_process_control_error(
+      deserializer,
+    );
+    return ProcessKillResult(
+      pid: var_pid,
+      parentPid: var_parentPid,
+      name: var_name,
+      outcome: var_outcome,
+      error: var_error,
+    );
+  }
+
+  @protected
+  ProcessMemory sse_decode_process_memory(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rss = sse_decode_u_64(deserializer);
+    var var_pss = sse_decode_u_64(deserializer);
+    var var_uss = sse_decode_u_64(deserializer);
+    var var_sharedClean = sse_decode_u_64(deserializer);
+    var var_sharedDirty = sse_decode_u_64(deserializer);
+    var var_privateClean = sse_decode_u_64(deserializer);
+    var var_privateDirty = sse_decode_u_64(deserializer);
+    var var_swap = sse_decode_u_64(deserializer);
+    var var_swapPss = sse_decode_u_64(deserializer);
+    var var_locked = sse_decode_u_64(deserializer);
+    var var_hugePages = sse_decode_u_64(deserializer);
+    return ProcessMemory(
+      rss: var_rss,
+      pss: var_pss,
+      uss: var_uss,
+      sharedClean: var_sharedClean,
+      sharedDirty: var_sharedDirty,
+      privateClean: var_privateClean,
+      privateDirty: var_privateDirty,
+      swap: var_swap,
+      swapPss: var_swapPss,
+      locked: var_locked,
+      hugePages: var_hugePages,
+    );
+  }
+
+  @protected
+  ProcessNetworkUsage sse_decode_process_network_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_receivedBytes = sse_decode_u_64(deserializer);
+    var var_sentBytes = sse_decode_u_64(deserializer);
+    var var_receiveBytesPerSec = sse_decode_f_64(deserializer);
+    var var_sendBytesPerSec = sse_decode_f_64(deserializer);
+    var var_connections = sse_decode_u_32(deserializer);
+    var var_source = sse_decode_network_attribution(deserializer);
+    var var_netNamespace = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ProcessNetworkUsage(
+      pid: var_pid,
+      name: var_name,
+      receivedBytes: var_receivedBytes,
+      sentBytes: var_sentBytes,
+      receiveBytesPerSec: var_receiveBytesPerSec,
+      sendBytesPerSec: var_sendBytesPerSec,
+      connections: var_connections,
+      source: var_source,
+      netNamespace: var_netNamespace,
+    );
+  }
+
+  @protected
+  ProcessPriority sse_decode_process_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_priority_level(deserializer);
+    var var_nice = sse_decode_i_32(deserializer);
+    var var_ioPriority = sse_decode_io_priority(deserializer);
+    return ProcessPriority(
+      level: var_level,
+      nice: var_nice,
+      ioPriority: var_ioPriority,
+    );
+  }
+
+  @protected
+  ProcessSignal sse_decode_process_signal(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProcessSignal.values[inner];
+  }
+
+  @protected
+  ProcessState sse_decode_process_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProcessState.values[inner];
+  }
+
+  @protected
+  SchedulerInfo sse_decode_scheduler_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_policy = sse_decode_scheduler_policy(deserializer);
+    var var_priority = sse_decode_u_32(deserializer);
+    return SchedulerInfo(policy: var_policy, priority: var_priority);
+  }
+
+  @protected
+  SchedulerPolicy sse_decode_scheduler_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SchedulerPolicy.values[inner];
+  }
+
+  @protected
+  SeccompMode sse_decode_seccomp_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SeccompMode.values[inner];
+  }
+
+  @protected
+  SecurityContext sse_decode_security_context(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_effective = sse_decode_capability_set(deserializer);
+    var var_permitted = sse_decode_capability_set(deserializer);
+    var var_inheritable = sse_decode_capability_set(deserializer);
+    var var_bounding = sse_decode_capability_set(deserializer);
+    var var_ambient = sse_decode_capability_set(deserializer);
+    var var_seccomp = sse_decode_opt_box_autoadd_seccomp_mode(deserializer);
+    var var_seccompFilters = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_noNewPrivs = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_lsm = sse_decode_opt_String(deserializer);
+    var var_lsmLabel = sse_decode_opt_String(deserializer);
+    return SecurityContext(
+      pid: var_pid,
+      effective: var_effective,
+      permitted: var_permitted,
+      inheritable: var_inheritable,
+      bounding: var_bounding,
+      ambient: var_ambient,
+      seccomp: var_seccomp,
+      seccompFilters: var_seccompFilters,
+      noNewPrivs: var_noNewPrivs,
+      lsm: var_lsm,
+      lsmLabel: var_lsmLabel,
+    );
+  }
+
+  @protected
+  SystemInfo sse_decode_system_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_osName = sse_decode_String(deserializer);
+    var var_osVersion = sse_decode_String(deserializer);
+    var var_kernelVersion = sse_decode_String(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_cpuBrand = sse_decode_String(deserializer);
+    var var_cpuCores = sse_decode_u_32(deserializer);
+    var var_totalMemory = sse_decode_u_64(deserializer);
+    var var_bootTime = sse_decode_u_64(deserializer);
+    var var_uptime = sse_decode_u_64(deserializer);
+    return SystemInfo(
+      osName: var_osName,
+      osVersion: var_osVersion,
+      kernelVersion: var_kernelVersion,
+      hostname: var_hostname,
+      cpuBrand: var_cpuBrand,
+      cpuCores: var_cpuCores,
+      totalMemory: var_totalMemory,
+      bootTime: var_bootTime,
+      uptime: var_uptime,
+    );
+  }
+
+  @protected
+  SystemResourceInfo sse_decode_system_resource_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cpuUsage = sse_decode_f_64(deserializer);
+    var var_cpuPerCore = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_cpuTimes = sse_decode_cpu_times_breakdown(deserializer);
+    var var_cpuTimesPerCore = sse_decode_list_cpu_times_breakdown(deserializer);
+    var var_memoryTotal = sse_decode_u_64(deserializer);
+    var var_memoryUsed = sse_decode_u_64(deserializer);
+    var var_memoryAvailable = sse_decode_u_64(deserializer);
+    var var_swapTotal = sse_decode_u_64(deserializer);
+    var var_swapUsed = sse_decode_u_64(deserializer);
+    var var_swapFree = sse_decode_u_64(deserializer);
+    var var_diskUsage = sse_decode_list_disk_info(deserializer);
+    var var_networkUsage = sse_decode_network_info(deserializer);
+    return SystemResourceInfo(
+      cpuUsage: var_cpuUsage,
+      cpuPerCore: var_cpuPerCore,
+      cpuTimes: var_cpuTimes,
+      cpuTimesPerCore: var_cpuTimesPerCore,
+      memoryTotal: var_memoryTotal,
+      memoryUsed: var_memoryUsed,
+      memoryAvailable: var_memoryAvailable,
+      swapTotal: var_swapTotal,
+      swapUsed: var_swapUsed,
+      swapFree: var_swapFree,
+      diskUsage: var_diskUsage,
+      networkUsage: var_networkUsage,
+    );
+  }
+
+  @protected
+  TerminationOutcome sse_decode_termination_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TerminationOutcome.values[inner];
+  }
+
+  @protected
+  ThreadInfo sse_decode_thread_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_state = sse_decode_process_state(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_cpuUsage = sse_decode_f_64(deserializer);
+    var var_userTimeMs = sse_decode_u_64(deserializer);
+    var var_systemTimeMs = sse_decode_u_64(deserializer);
+    var var_lastCpu = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    var var_nice = sse_decode_i_32(deserializer);
+    var var_voluntaryContextSwitches = sse_decode_u_64(deserializer);
+    var var_involuntaryContextSwitches = sse_decode_u_64(deserializer);
+    return ThreadInfo(
+      tid: var_tid,
+      name: var_name,
+      state: var_state,
+      status: var_status,
+      cpuUsage: var_cpuUsage,
+      userTimeMs: var_userTimeMs,
+      systemTimeMs: var_systemTimeMs,
+      lastCpu: var_lastCpu,
+      priority: var_priority,
+      nice: var_nice,
+      voluntaryContextSwitches: var_voluntaryContextSwitches,
+      involuntaryContextSwitches: var_involuntaryContextSwitches,
+    );
+  }
+
+  @protected
+  ThreadScheduling sse_decode_thread_scheduling(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_affinity = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_scheduler = sse_decode_scheduler_info(deserializer);
+    var var_lastCpu = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ThreadScheduling(
+      tid: var_tid,
+      name: var_name,
+      affinity: var_affinity,
+      scheduler: var_scheduler,
+      lastCpu: var_lastCpu,
+    );
+  }
+
+  @protected
+  TransportProtocol sse_decode_transport_protocol(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TransportProtocol.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cgroup_cpu_stat(
+    CgroupCpuStat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cgroup_cpu_stat(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cgroup_memory_events(
+    CgroupMemoryEvents self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cgroup_memory_events(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_io_priority(
+    IoPriority self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_io_priority(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_mount_filter(
+    MountFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mount_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pressure(
+    Pressure self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pressure(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pressure_line(
+    PressureLine self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pressure_line(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_process_control_error(
+    ProcessControlError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_control_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_process_credentials(
+    ProcessCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_credentials(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_process_disk_io(
+    ProcessDiskIo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_disk_io(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scheduler_info(
+    SchedulerInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scheduler_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_seccomp_mode(
+    SeccompMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_seccomp_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_capability_set(CapabilitySet self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.mask, serializer);
+    sse_encode_list_String(self.names, serializer);
+  }
+
+  @protected
+  void sse_encode_cgroup_cpu_stat(
+    CgroupCpuStat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.usageUsec, serializer);
+    sse_encode_u_64(self.userUsec, serializer);
+    sse_encode_u_64(self.systemUsec, serializer);
+    sse_encode_u_64(self.nrPeriods, serializer);
+    sse_encode_u_64(self.nrThrottled, serializer);
+    sse_encode_u_64(self.throttledUsec, serializer);
+  }
+
+  @protected
+  void sse_encode_cgroup_info(CgroupInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.parent, serializer);
+    sse_encode_cgroup_kind(self.kind, serializer);
+    sse_encode_u_32(self.processCount, serializer);
+    sse_encode_opt_box_autoadd_cgroup_cpu_stat(self.cpu, serializer);
+    sse_encode_f_64(self.cpuUsage, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.memoryCurrent, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.memoryMax, serializer);
+    sse_encode_opt_box_autoadd_cgroup_memory_events(
+      self.memoryEvents,
+      serializer,
+    );
+    sse_encode_list_cgroup_io_stat(self.io, serializer);
+    sse_encode_u_64(self.ioReadBytes, serializer);
+    sse_encode_u_64(self.ioWriteBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.pidsCurrent, serializer);
+    sse_encode_opt_box_autoadd_pressure(self.cpuPressure, serializer);
+    sse_encode_opt_box_autoadd_pressure(self.memoryPressure, serializer);
+    sse_encode_opt_box_autoadd_pressure(self.ioPressure, serializer);
+  }
+
+  @protected
+  void sse_encode_cgroup_io_stat(CgroupIoStat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.device, serializer);
+    sse_encode_u_64(self.readBytes, serializer);
+    sse_encode_u_64(self.writeBytes, serializer);
+    sse_encode_u_64(self.readIos, serializer);
+    sse_encode_u_64(self.writeIos, serializer);
+    sse_encode_u_64(self.discardBytes, serializer);
+    sse_encode_u_64(self.discardIos, serializer);
+  }
+
+  @protected
+  void sse_encode_cgroup_kind(CgroupKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_cgroup_memory_events(
+    CgroupMemoryEvents self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.low, serializer);
+    sse_encode_u_64(self.high, serializer);
+    sse_encode_u_64(self.max, serializer);
+    sse_encode_u_64(self.oom, serializer);
+    sse_encode_u_64(self.oomKill, serializer);
+  }
+
+  @protected
+  void sse_encode_connection_info(
+    ConnectionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transport_protocol(self.protocol, serializer);
+    sse_encode_bool(self.ipv6, serializer);
+    sse_encode_String(self.localAddress, serializer);
+    sse_encode_u_16(self.localPort, serializer);
+    sse_encode_String(self.remoteAddress, serializer);
+    sse_encode_u_16(self.remotePort, serializer);
+    sse_encode_connection_state(self.state, serializer);
+    sse_encode_u_64(self.sendQueue, serializer);
+    sse_encode_u_64(self.receiveQueue, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.pid, serializer);
+    sse_encode_u_32(self.uid, serializer);
+    sse_encode_u_64(self.inode, serializer);
+  }
+
+  @protected
+  void sse_encode_connection_state(
+    ConnectionState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_cpu_times_breakdown(
+    CpuTimesBreakdown self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.user, serializer);
+    sse_encode_f_64(self.nice, serializer);
+    sse_encode_f_64(self.system, serializer);
+    sse_encode_f_64(self.idle, serializer);
+    sse_encode_f_64(self.iowait, serializer);
+    sse_encode_f_64(self.irq, serializer);
+    sse_encode_f_64(self.softirq, serializer);
+    sse_encode_f_64(self.steal, serializer);
+    sse_encode_f_64(self.guest, serializer);
+    sse_encode_f_64(self.guestNice, serializer);
+  }
+
+  @protected
+  void sse_encode_cpu_usage_mode(CpuUsageMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_disk_activity(DiskActivity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.parent, serializer);
+    sse_encode_u_64(self.readBytes, serializer);
+    sse_encode_u_64(self.writeBytes, serializer);
+    sse_encode_f_64(self.readBytesPerSec, serializer);
+    sse_encode_f_64(self.writeBytesPerSec, serializer);
+    sse_encode_f_64(self.readIops, serializer);
+    sse_encode_f_64(self.writeIops, serializer);
+    sse_encode_f_64(self.avgWaitMs, serializer);
+    sse_encode_f_64(self.utilization, serializer);
+  }
+
+  @protected
+  void sse_encode_disk_info(DiskInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.mountPoint, serializer);
+    sse_encode_u_64(self.totalSpace, serializer);
+    sse_encode_u_64(self.usedSpace, serializer);
+    sse_encode_u_64(self.availableSpace, serializer);
+    sse_encode_String(self.fsType, serializer);
+    sse_encode_String(self.device, serializer);
+    sse_encode_list_String(self.mountOptions, serializer);
+    sse_encode_bool(self.readOnly, serializer);
+    sse_encode_u_64(self.inodesTotal, serializer);
+    sse_encode_u_64(self.inodesUsed, serializer);
+    sse_encode_u_64(self.inodesFree, serializer);
+  }
+
+  @protected
+  void sse_encode_environment_variable(
+    EnvironmentVariable self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_file_descriptor_kind(
+    FileDescriptorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_io_priority(IoPriority self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_io_priority_class(self.class_, serializer);
+    sse_encode_u_8(self.level, serializer);
+  }
+
+  @protected
+  void sse_encode_io_priority_class(
+    IoPriorityClass self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_kill_outcome(KillOutcome self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_kill_tree_strategy(
+    KillTreeStrategy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cgroup_info(
+    List<CgroupInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cgroup_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cgroup_io_stat(
+    List<CgroupIoStat> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cgroup_io_stat(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_connection_info(
+    List<ConnectionInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_connection_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cpu_times_breakdown(
+    List<CpuTimesBreakdown> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cpu_times_breakdown(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_disk_activity(
+    List<DiskActivity> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_disk_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_disk_info(
+    List<DiskInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_disk_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_environment_variable(
+    List<EnvironmentVariable> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_environment_variable(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_mapped_file_summary(
+    List<MappedFileSummary> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mapped_file_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_memory_region(
+    List<MemoryRegion> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_memory_region(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_network_interface_info(
+    List<NetworkInterfaceInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_network_interface_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_open_file_info(
+    List<OpenFileInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_open_file_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_path_usage(
+    List<PathUsage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_path_usage(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(
+      self is Uint32List ? self : Uint32List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float64List;
+  void sse_encode_list_process_detail_error(
+    List<ProcessDetailError> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_process_detail_error(item, serializer);
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_list_process_info(
+    List<ProcessInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_process_info(item, serializer);
+    }
   }
 
   @protected
-  List<ProcessInfo> dco_decode_list_process_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_process_info).toList();
+  void sse_encode_list_process_kill_result(
+    List<ProcessKillResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_process_kill_result(item, serializer);
+    }
   }
 
   @protected
-  NetworkInfo dco_decode_network_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NetworkInfo(
-      bytesSent: dco_decode_u_64(arr[0]),
-      bytesReceived: dco_decode_u_64(arr[1]),
-      packetsSent: dco_decode_u_64(arr[2]),
-      packetsReceived: dco_decode_u_64(arr[3]),
-    );
+  void sse_encode_list_process_network_usage(
+    List<ProcessNetworkUsage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_process_network_usage(item, serializer);
+    }
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void sse_encode_list_thread_info(
+    List<ThreadInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_thread_info(item, serializer);
+    }
   }
 
   @protected
-  ProcessInfo dco_decode_process_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return ProcessInfo(
-      pid: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      cpuUsage: dco_decode_f_64(arr[2]),
-      memoryUsage: dco_decode_u_64(arr[3]),
-      parentPid: dco_decode_opt_box_autoadd_u_32(arr[4]),
-      status: dco_decode_String(arr[5]),
-      command: dco_decode_String(arr[6]),
-      startTime: dco_decode_u_64(arr[7]),
-    );
+  void sse_encode_list_thread_scheduling(
+    List<ThreadScheduling> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_thread_scheduling(item, serializer);
+    }
   }
 
   @protected
-  SystemInfo dco_decode_system_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return SystemInfo(
-      osName: dco_decode_String(arr[0]),
-      osVersion: dco_decode_String(arr[1]),
-      kernelVersion: dco_decode_String(arr[2]),
-      hostname: dco_decode_String(arr[3]),
-      cpuBrand: dco_decode_String(arr[4]),
-      cpuCores: dco_decode_u_32(arr[5]),
-      totalMemory: dco_decode_u_64(arr[6]),
-      bootTime: dco_decode_u_64(arr[7]),
-      uptime: dco_decode_u_64(arr[8]),
-    );
+  void sse_encode_mapped_file_summary(
+    MappedFileSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.regions, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_u_64(self.rss, serializer);
+    sse_encode_u_64(self.pss, serializer);
+    sse_encode_u_64(self.private, serializer);
+    sse_encode_u_64(self.swap, serializer);
   }
 
   @protected
-  SystemResourceInfo dco_decode_system_resource_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return SystemResourceInfo(
-      cpuUsage: dco_decode_f_64(arr[0]),
-      cpuPerCore: dco_decode_list_prim_f_64_strict(arr[1]),
-      memoryTotal: dco_decode_u_64(arr[2]),
-      memoryUsed: dco_decode_u_64(arr[3]),
-      memoryAvailable: dco_decode_u_64(arr[4]),
-      swapTotal: dco_decode_u_64(arr[5]),
-      swapUsed: dco_decode_u_64(arr[6]),
-      swapFree: dco_decode_u_64(arr[7]),
-      diskUsage: dco_decode_list_disk_info(arr[8]),
-      networkUsage: dco_decode_network_info(arr[9]),
-    );
+  void sse_encode_memory_maps(MemoryMaps self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_memory_region(self.regions, serializer);
+    sse_encode_list_mapped_file_summary(self.files, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_memory_metric(MemoryMetric self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_memory_region(MemoryRegion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.start, serializer);
+    sse_encode_u_64(self.end, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.permissions, serializer);
+    sse_encode_u_64(self.offset, serializer);
+    sse_encode_String(self.device, serializer);
+    sse_encode_u_64(self.inode, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.deleted, serializer);
+    sse_encode_u_64(self.rss, serializer);
+    sse_encode_u_64(self.pss, serializer);
+    sse_encode_u_64(self.private, serializer);
+    sse_encode_u_64(self.swap, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_mount_filter(MountFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.includePseudo, serializer);
+    sse_encode_bool(self.collapseBindMounts, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_network_attribution(
+    NetworkAttribution self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_network_info(NetworkInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.packetsSent, serializer);
+    sse_encode_u_64(self.packetsReceived, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_network_interface_info(
+    NetworkInterfaceInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_bool(self.isLoopback, serializer);
+    sse_encode_bool(self.isVirtual, serializer);
+    sse_encode_String(self.operstate, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.linkSpeedMbps, serializer);
+    sse_encode_u_32(self.mtu, serializer);
+    sse_encode_String(self.macAddress, serializer);
+    sse_encode_list_String(self.ipv4Addresses, serializer);
+    sse_encode_list_String(self.ipv6Addresses, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.packetsReceived, serializer);
+    sse_encode_u_64(self.packetsSent, serializer);
+    sse_encode_u_64(self.receiveErrors, serializer);
+    sse_encode_u_64(self.sendErrors, serializer);
+    sse_encode_u_64(self.receiveDropped, serializer);
+    sse_encode_u_64(self.sendDropped, serializer);
+    sse_encode_f_64(self.receiveBytesPerSec, serializer);
+    sse_encode_f_64(self.sendBytesPerSec, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_open_file_info(OpenFileInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_u_32(self.fd, serializer);
+    sse_encode_file_descriptor_kind(self.kind, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.flags, serializer);
+    sse_encode_bool(self.readable, serializer);
+    sse_encode_bool(self.writable, serializer);
+    sse_encode_u_64(self.position, serializer);
+    sse_encode_bool(self.deleted, serializer);
+    sse_encode_u_64(self.inode, serializer);
   }
 
   @protected
-  DiskInfo sse_decode_disk_info(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_mountPoint = sse_decode_String(deserializer);
-    var var_totalSpace = sse_decode_u_64(deserializer);
-    var var_usedSpace = sse_decode_u_64(deserializer);
-    var var_availableSpace = sse_decode_u_64(deserializer);
-    return DiskInfo(
-      name: var_name,
-      mountPoint: var_mountPoint,
-      totalSpace: var_totalSpace,
-      usedSpace: var_usedSpace,
-      availableSpace: var_availableSpace,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  List<DiskInfo> sse_decode_list_disk_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_cgroup_cpu_stat(
+    CgroupCpuStat? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DiskInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_disk_info(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cgroup_cpu_stat(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cgroup_memory_events(
+    CgroupMemoryEvents? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cgroup_memory_events(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pressure(
+    Pressure? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pressure(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pressure_line(
+    PressureLine? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pressure_line(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_control_error(
+    ProcessControlError? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_process_control_error(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_credentials(
+    ProcessCredentials? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_process_credentials(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_disk_io(
+    ProcessDiskIo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_process_disk_io(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_seccomp_mode(
+    SeccompMode? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_seccomp_mode(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat64List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_list_String(
+    List<String>? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
   }
 
   @protected
-  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer) {
+  void sse_encode_opt_list_environment_variable(
+    List<EnvironmentVariable>? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ProcessInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_process_info(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_environment_variable(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  NetworkInfo sse_decode_network_info(SseDeserializer deserializer) {
+  void sse_encode_path_usage(PathUsage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bytesSent = sse_decode_u_64(deserializer);
-    var var_bytesReceived = sse_decode_u_64(deserializer);
-    var var_packetsSent = sse_decode_u_64(deserializer);
-    var var_packetsReceived = sse_decode_u_64(deserializer);
-    return NetworkInfo(
-      bytesSent: var_bytesSent,
-      bytesReceived: var_bytesReceived,
-      packetsSent: var_packetsSent,
-      packetsReceived: var_packetsReceived,
-    );
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_path_usage_kind(self.kind, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.fd, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_path_usage_kind(
+    PathUsageKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  ProcessInfo sse_decode_process_info(SseDeserializer deserializer) {
+  void sse_encode_pressure(Pressure self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pid = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_cpuUsage = sse_decode_f_64(deserializer);
-    var var_memoryUsage = sse_decode_u_64(deserializer);
-    var var_parentPid = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_status = sse_decode_String(deserializer);
-    var var_command = sse_decode_String(deserializer);
-    var var_startTime = sse_decode_u_64(deserializer);
-    return ProcessInfo(
-      pid: var_pid,
-      name: var_name,
-      cpuUsage: var_cpuUsage,
-      memoryUsage: var_memoryUsage,
-      parentPid: var_parentPid,
-      status: var_status,
-      command: var_command,
-      startTime: var_startTime,
-    );
+    sse_encode_pressure_line(self.some, serializer);
+    sse_encode_opt_box_autoadd_pressure_line(self.full, serializer);
   }
 
   @protected
-  SystemInfo sse_decode_system_info(SseDeserializer deserializer) {
+  void sse_encode_pressure_line(PressureLine self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_osName = sse_decode_String(deserializer);
-    var var_osVersion = sse_decode_String(deserializer);
-    var var_kernelVersion = sse_decode_String(deserializer);
-    var var_hostname = sse_decode_String(deserializer);
-    var var_cpuBrand = sse_decode_String(deserializer);
-    var var_cpuCores = sse_decode_u_32(deserializer);
-    var var_totalMemory = sse_decode_u_64(deserializer);
-    var var_bootTime = sse_decode_u_64(deserializer);
-    var var_uptime = sse_decode_u_64(deserializer);
-    return SystemInfo(
-      osName: var_osName,
-      osVersion: var_osVersion,
-      kernelVersion: var_kernelVersion,
-      hostname: var_hostname,
-      cpuBrand: var_cpuBrand,
-      cpuCores: var_cpuCores,
-      totalMemory: var_totalMemory,
-      bootTime: var_bootTime,
-      uptime: var_uptime,
-    );
+    sse_encode_f_64(self.avg10, serializer);
+    sse_encode_f_64(self.avg60, serializer);
+    sse_encode_f_64(self.avg300, serializer);
+    sse_encode_u_64(self.totalUsec, serializer);
   }
 
   @protected
-  SystemResourceInfo sse_decode_system_resource_info(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_priority_level(PriorityLevel self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_cpuUsage = sse_decode_f_64(deserializer);
-    var var_cpuPerCore = sse_decode_list_prim_f_64_strict(deserializer);
-    var var_memoryTotal = sse_decode_u_64(deserializer);
-    var var_memoryUsed = sse_decode_u_64(deserializer);
-    var var_memoryAvailable = sse_decode_u_64(deserializer);
-    var var_swapTotal = sse_decode_u_64(deserializer);
-    var var_swapUsed = sse_decode_u_64(deserializer);
-    var var_swapFree = sse_decode_u_64(deserializer);
-    var var_diskUsage = sse_decode_list_disk_info(deserializer);
-    var var_networkUsage = sse_decode_network_info(deserializer);
-    return SystemResourceInfo(
-      cpuUsage: var_cpuUsage,
-      cpuPerCore: var_cpuPerCore,
-      memoryTotal: var_memoryTotal,
-      memoryUsed: var_memoryUsed,
-      memoryAvailable: var_memoryAvailable,
-      swapTotal: var_swapTotal,
-      swapUsed: var_swapUsed,
-      swapFree: var_swapFree,
-      diskUsage: var_diskUsage,
-      networkUsage: var_networkUsage,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_process_control_error(
+    ProcessControlError self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_process_control_error_kind(self.kind, serializer);
+    sse_encode_i_32(self.code, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_process_control_error_kind(
+    ProcessControlErrorKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_process_credentials(
+    ProcessCredentials self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_u_32(self.realUid, serializer);
+    sse_encode_u_32(self.effectiveUid, serializer);
+    sse_encode_u_32(self.savedUid, serializer);
+    sse_encode_u_32(self.realGid, serializer);
+    sse_encode_u_32(self.effectiveGid, serializer);
+    sse_encode_u_32(self.savedGid, serializer);
+    sse_encode_list_prim_u_32_strict(self.supplementaryGroups, serializer);
+    sse_encode_String(self.realUser, serializer);
+    sse_encode_String(self.effectiveUser, serializer);
+    sse_encode_String(self.group, serializer);
+    sse_encode_list_String(self.supplementaryGroupNames, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_process_detail_error(
+    ProcessDetailError self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_detail_field(self.field, serializer);
+    sse_encode_process_control_error(self.error, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_process_detail_field(
+    ProcessDetailField self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_process_details(
+    ProcessDetails self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_opt_String(self.exe, serializer);
+    sse_encode_bool(self.exeDeleted, serializer);
+    sse_encode_opt_String(self.cwd, serializer);
+    sse_encode_opt_String(self.root, serializer);
+    sse_encode_opt_list_String(self.argv, serializer);
+    sse_encode_opt_list_environment_variable(self.environment, serializer);
+    sse_encode_list_process_detail_error(self.errors, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_process_disk_io(
+    ProcessDiskIo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_u_64(self.readBytes, serializer);
+    sse_encode_u_64(self.writeBytes, serializer);
+    sse_encode_u_64(self.cancelledWriteBytes, serializer);
+    sse_encode_u_64(self.readChars, serializer);
+    sse_encode_u_64(self.writeChars, serializer);
+    sse_encode_u_64(self.readSyscalls, serializer);
+    sse_encode_u_64(self.writeSyscalls, serializer);
+    sse_encode_f_64(self.readBytesPerSec, serializer);
+    sse_encode_f_64(self.writeBytesPerSec, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_f_64(self.cpuUsage, serializer);
+    sse_encode_u_64(self.memoryUsage, serializer);
+    sse_encode_memory_metric(self.memoryMetric, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.parentPid, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_process_state(self.state, serializer);
+    sse_encode_String(self.command, serializer);
+    sse_encode_u_64(self.startTime, serializer);
+    sse_encode_u_64(self.age, serializer);
+    sse_encode_priority_level(self.priority, serializer);
+    sse_encode_i_32(self.nice, serializer);
+    sse_encode_io_priority(self.ioPriority, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.lastCpu, serializer);
+    sse_encode_u_32(self.threadCount, serializer);
+    sse_encode_opt_box_autoadd_process_disk_io(self.diskIo, serializer);
+    sse_encode_opt_String(self.user, serializer);
+    sse_encode_opt_box_autoadd_process_credentials(
+      self.credentials,
+      serializer,
+    );
+    sse_encode_bool(self.privileged, serializer);
+    sse_encode_opt_String(self.cgroup, serializer);
   }
 
   @protected
-  void sse_encode_disk_info(DiskInfo self, SseSerializer serializer) {
+  void sse_encode_process_kill_result(
+    ProcessKillResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.parentPid, serializer);
     sse_encode_String(self.name, serializer);
-    sse_encode_String(self.mountPoint, serializer);
-    sse_encode_u_64(self.totalSpace, serializer);
-    sse_encode_u_64(self.usedSpace, serializer);
-    sse_encode_u_64(self.availableSpace, serializer);
+    sse_encode_kill_outcome(self.outcome, serializer);
+    sse_encode_opt_box_autoadd_process_control_error(self.error, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_process_memory(ProcessMemory self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_u_64(self.rss, serializer);
+    sse_encode_u_64(self.pss, serializer);
+    sse_encode_u_64(self.uss, serializer);
+    sse_encode_u_64(self.sharedClean, serializer);
+    sse_encode_u_64(self.sharedDirty, serializer);
+    sse_encode_u_64(self.privateClean, serializer);
+    sse_encode_u_64(self.privateDirty, serializer);
+    sse_encode_u_64(self.swap, serializer);
+    sse_encode_u_64(self.swapPss, serializer);
+    sse_encode_u_64(self.locked, serializer);
+    sse_encode_u_64(self.hugePages, serializer);
   }
 
   @protected
-  void sse_encode_list_disk_info(
-    List<DiskInfo> self,
+  void sse_encode_process_network_usage(
+    ProcessNetworkUsage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_disk_info(item, serializer);
-    }
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_64(self.receivedBytes, serializer);
+    sse_encode_u_64(self.sentBytes, serializer);
+    sse_encode_f_64(self.receiveBytesPerSec, serializer);
+    sse_encode_f_64(self.sendBytesPerSec, serializer);
+    sse_encode_u_32(self.connections, serializer);
+    sse_encode_network_attribution(self.source, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.netNamespace, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_f_64_strict(
-    Float64List self,
+  void sse_encode_process_priority(
+    ProcessPriority self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat64List(self);
+    sse_encode_priority_level(self.level, serializer);
+    sse_encode_i_32(self.nice, serializer);
+    sse_encode_io_priority(self.ioPriority, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_process_signal(ProcessSignal self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_process_info(
-    List<ProcessInfo> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_process_state(ProcessState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_process_info(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_network_info(NetworkInfo self, SseSerializer serializer) {
+  void sse_encode_scheduler_info(SchedulerInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.bytesSent, serializer);
-    sse_encode_u_64(self.bytesReceived, serializer);
-    sse_encode_u_64(self.packetsSent, serializer);
-    sse_encode_u_64(self.packetsReceived, serializer);
+    sse_encode_scheduler_policy(self.policy, serializer);
+    sse_encode_u_32(self.priority, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_scheduler_policy(
+    SchedulerPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+  @protected
+  void sse_encode_seccomp_mode(SeccompMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer) {
+  void sse_encode_security_context(
+    SecurityContext self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self.pid, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_f_64(self.cpuUsage, serializer);
-    sse_encode_u_64(self.memoryUsage, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.parentPid, serializer);
-    sse_encode_String(self.status, serializer);
-    sse_encode_String(self.command, serializer);
-    sse_encode_u_64(self.startTime, serializer);
+    sse_encode_capability_set(self.effective, serializer);
+    sse_encode_capability_set(self.permitted, serializer);
+    sse_encode_capability_set(self.inheritable, serializer);
+    sse_encode_capability_set(self.bounding, serializer);
+    sse_encode_capability_set(self.ambient, serializer);
+    sse_encode_opt_box_autoadd_seccomp_mode(self.seccomp, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.seccompFilters, serializer);
+    sse_encode_opt_box_autoadd_bool(self.noNewPrivs, serializer);
+    sse_encode_opt_String(self.lsm, serializer);
+    sse_encode_opt_String(self.lsmLabel, serializer);
   }
 
   @protected
@@ -769,6 +5467,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_64(self.cpuUsage, serializer);
     sse_encode_list_prim_f_64_strict(self.cpuPerCore, serializer);
+    sse_encode_cpu_times_breakdown(self.cpuTimes, serializer);
+    sse_encode_list_cpu_times_breakdown(self.cpuTimesPerCore, serializer);
     sse_encode_u_64(self.memoryTotal, serializer);
     sse_encode_u_64(self.memoryUsed, serializer);
     sse_encode_u_64(self.memoryAvailable, serializer);
@@ -779,6 +5479,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_network_info(self.networkUsage, serializer);
   }
 
+  @protected
+  void sse_encode_termination_outcome(
+    TerminationOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_thread_info(ThreadInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.tid, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_process_state(self.state, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_f_64(self.cpuUsage, serializer);
+    sse_encode_u_64(self.userTimeMs, serializer);
+    sse_encode_u_64(self.systemTimeMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.lastCpu, serializer);
+    sse_encode_i_32(self.priority, serializer);
+    sse_encode_i_32(self.nice, serializer);
+    sse_encode_u_64(self.voluntaryContextSwitches, serializer);
+    sse_encode_u_64(self.involuntaryContextSwitches, serializer);
+  }
+
+  @protected
+  void sse_encode_thread_scheduling(
+    ThreadScheduling self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.tid, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_prim_u_32_strict(self.affinity, serializer);
+    sse_encode_scheduler_info(self.scheduler, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.lastCpu, serializer);
+  }
+
+  @protected
+  void sse_encode_transport_protocol(
+    TransportProtocol self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -801,10 +5555,4 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
 }
@@ -26,158 +26,1295 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CgroupCpuStat dco_decode_box_autoadd_cgroup_cpu_stat(dynamic raw);
+
+  @protected
+  CgroupMemoryEvents dco_decode_box_autoadd_cgroup_memory_events(dynamic raw);
+
+  @protected
+  IoPriority dco_decode_box_autoadd_io_priority(dynamic raw);
+
+  @protected
+  MountFilter dco_decode_box_autoadd_mount_filter(dynamic raw);
+
+  @protected
+  Pressure dco_decode_box_autoadd_pressure(dynamic raw);
+
+  @protected
+  PressureLine dco_decode_box_autoadd_pressure_line(dynamic raw);
+
+  @protected
+  ProcessControlError dco_decode_box_autoadd_process_control_error(dynamic raw);
+
+  @protected
+  ProcessCredentials dco_decode_box_autoadd_process_credentials(dynamic raw);
+
+  @protected
+  ProcessDiskIo dco_decode_box_autoadd_process_disk_io(dynamic raw);
+
+  @protected
+  SchedulerInfo dco_decode_box_autoadd_scheduler_info(dynamic raw);
+
+  @protected
+  SeccompMode dco_decode_box_autoadd_seccomp_mode(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  DiskInfo dco_decode_disk_info(dynamic raw);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CapabilitySet dco_decode_capability_set(dynamic raw);
+
+  @protected
+  CgroupCpuStat dco_decode_cgroup_cpu_stat(dynamic raw);
+
+  @protected
+  CgroupInfo dco_decode_cgroup_info(dynamic raw);
+
+  @protected
+  CgroupIoStat dco_decode_cgroup_io_stat(dynamic raw);
+
+  @protected
+  CgroupKind dco_decode_cgroup_kind(dynamic raw);
+
+  @protected
+  CgroupMemoryEvents dco_decode_cgroup_memory_events(dynamic raw);
+
+  @protected
+  ConnectionInfo dco_decode_connection_info(dynamic raw);
+
+  @protected
+  ConnectionState dco_decode_connection_state(dynamic raw);
+
+  @protected
+  CpuTimesBreakdown dco_decode_cpu_times_breakdown(dynamic raw);
+
+  @protected
+  CpuUsageMode dco_decode_cpu_usage_mode(dynamic raw);
+
+  @protected
+  DiskActivity dco_decode_disk_activity(dynamic raw);
+
+  @protected
+  DiskInfo dco_decode_disk_info(dynamic raw);
+
+  @protected
+  EnvironmentVariable dco_decode_environment_variable(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FileDescriptorKind dco_decode_file_descriptor_kind(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  IoPriority dco_decode_io_priority(dynamic raw);
+
+  @protected
+  IoPriorityClass dco_decode_io_priority_class(dynamic raw);
+
+  @protected
+  KillOutcome dco_decode_kill_outcome(dynamic raw);
+
+  @protected
+  KillTreeStrategy dco_decode_kill_tree_strategy(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<CgroupInfo> dco_decode_list_cgroup_info(dynamic raw);
+
+  @protected
+  List<CgroupIoStat> dco_decode_list_cgroup_io_stat(dynamic raw);
+
+  @protected
+  List<ConnectionInfo> dco_decode_list_connection_info(dynamic raw);
+
+  @protected
+  List<CpuTimesBreakdown> dco_decode_list_cpu_times_breakdown(dynamic raw);
+
+  @protected
+  List<DiskActivity> dco_decode_list_disk_activity(dynamic raw);
+
+  @protected
+  List<DiskInfo> dco_decode_list_disk_info(dynamic raw);
+
+  @protected
+  List<EnvironmentVariable> dco_decode_list_environment_variable(dynamic raw);
+
+  @protected
+  List<MappedFileSummary> dco_decode_list_mapped_file_summary(dynamic raw);
+
+  @protected
+  List<MemoryRegion> dco_decode_list_memory_region(dynamic raw);
+
+  @protected
+  List<NetworkInterfaceInfo> dco_decode_list_network_interface_info(
+    dynamic raw,
+  );
+
+  @protected
+  List<OpenFileInfo> dco_decode_list_open_file_info(dynamic raw);
+
+  @protected
+  List<PathUsage> dco_decode_list_path_usage(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ProcessDetailError> dco_decode_list_process_detail_error(dynamic raw);
+
+  @protected
+  List<ProcessInfo> dco_decode_list_process_info(dynamic raw);
+
+  @protected
+  List<ProcessKillResult> dco_decode_list_process_kill_result(dynamic raw);
+
+  @protected
+  List<ProcessNetworkUsage> dco_decode_list_process_network_usage(dynamic raw);
+
+  @protected
+  List<ThreadInfo> dco_decode_list_thread_info(dynamic raw);
+
+  @protected
+  List<ThreadScheduling> dco_decode_list_thread_scheduling(dynamic raw);
+
+  @protected
+  MappedFileSummary dco_decode_mapped_file_summary(dynamic raw);
+
+  @protected
+  MemoryMaps dco_decode_memory_maps(dynamic raw);
+
+  @protected
+  MemoryMetric dco_decode_memory_metric(dynamic raw);
+
+  @protected
+  MemoryRegion dco_decode_memory_region(dynamic raw);
+
+  @protected
+  MountFilter dco_decode_mount_filter(dynamic raw);
+
+  @protected
+  NetworkAttribution dco_decode_network_attribution(dynamic raw);
+
+  @protected
+  NetworkInfo dco_decode_network_info(dynamic raw);
+
+  @protected
+  NetworkInterfaceInfo dco_decode_network_interface_info(dynamic raw);
+
+  @protected
+  OpenFileInfo dco_decode_open_file_info(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CgroupCpuStat? dco_decode_opt_box_autoadd_cgroup_cpu_stat(dynamic raw);
+
+  @protected
+  CgroupMemoryEvents? dco_decode_opt_box_autoadd_cgroup_memory_events(
+    dynamic raw,
+  );
+
+  @protected
+  Pressure? dco_decode_opt_box_autoadd_pressure(dynamic raw);
+
+  @protected
+  PressureLine? dco_decode_opt_box_autoadd_pressure_line(dynamic raw);
+
+  @protected
+  ProcessControlError? dco_decode_opt_box_autoadd_process_control_error(
+    dynamic raw,
+  );
+
+  @protected
+  ProcessCredentials? dco_decode_opt_box_autoadd_process_credentials(
+    dynamic raw,
+  );
+
+  @protected
+  ProcessDiskIo? dco_decode_opt_box_autoadd_process_disk_io(dynamic raw);
+
+  @protected
+  SeccompMode? dco_decode_opt_box_autoadd_seccomp_mode(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<EnvironmentVariable>? dco_decode_opt_list_environment_variable(
+    dynamic raw,
+  );
+
+  @protected
+  PathUsage dco_decode_path_usage(dynamic raw);
+
+  @protected
+  PathUsageKind dco_decode_path_usage_kind(dynamic raw);
+
+  @protected
+  Pressure dco_decode_pressure(dynamic raw);
+
+  @protected
+  PressureLine dco_decode_pressure_line(dynamic raw);
+
+  @protected
+  PriorityLevel dco_decode_priority_level(dynamic raw);
+
+  @protected
+  ProcessControlError dco_decode_process_control_error(dynamic raw);
+
+  @protected
+  ProcessControlErrorKind dco_decode_process_control_error_kind(dynamic raw);
+
+  @protected
+  ProcessCredentials dco_decode_process_credentials(dynamic raw);
+
+  @protected
+  ProcessDetailError dco_decode_process_detail_error(dynamic raw);
+
+  @protected
+  ProcessDetailField dco_decode_process_detail_field(dynamic raw);
+
+  @protected
+  ProcessDetails dco_decode_process_details(dynamic raw);
+
+  @protected
+  ProcessDiskIo dco_decode_process_disk_io(dynamic raw);
+
+  @protected
+  ProcessInfo dco_decode_process_info(dynamic raw);
+
+  @protected
+  ProcessKillResult dco_decode_process_kill_result(dynamic raw);
+
+  @protected
+  ProcessMemory dco_decode_process_memory(dynamic raw);
+
+  @protected
+  ProcessNetworkUsage dco_decode_process_network_usage(dynamic raw);
+
+  @protected
+  ProcessPriority dco_decode_process_priority(dynamic raw);
+
+  @protected
+  ProcessSignal dco_decode_process_signal(dynamic raw);
+
+  @protected
+  ProcessState dco_decode_process_state(dynamic raw);
+
+  @protected
+  SchedulerInfo dco_decode_scheduler_info(dynamic raw);
+
+  @protected
+  SchedulerPolicy dco_decode_scheduler_policy(dynamic raw);
+
+  @protected
+  SeccompMode dco_decode_seccomp_mode(dynamic raw);
+
+  @protected
+  SecurityContext dco_decode_security_context(dynamic raw);
+
+  @protected
+  SystemInfo dco_decode_system_info(dynamic raw);
+
+  @protected
+  SystemResourceInfo dco_decode_system_resource_info(dynamic raw);
+
+  @protected
+  TerminationOutcome dco_decode_termination_outcome(dynamic raw);
+
+  @protected
+  ThreadInfo dco_decode_thread_info(dynamic raw);
+
+  @protected
+  ThreadScheduling dco_decode_thread_scheduling(dynamic raw);
+
+  @protected
+  TransportProtocol dco_decode_transport_protocol(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CgroupCpuStat sse_decode_box_autoadd_cgroup_cpu_stat(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CgroupMemoryEvents sse_decode_box_autoadd_cgroup_memory_events(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IoPriority sse_decode_box_autoadd_io_priority(SseDeserializer deserializer);
+
+  @protected
+  MountFilter sse_decode_box_autoadd_mount_filter(SseDeserializer deserializer);
+
+  @protected
+  Pressure sse_decode_box_autoadd_pressure(SseDeserializer deserializer);
+
+  @protected
+  PressureLine sse_decode_box_autoadd_pressure_line(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessControlError sse_decode_box_autoadd_process_control_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessCredentials sse_decode_box_autoadd_process_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessDiskIo sse_decode_box_autoadd_process_disk_io(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SchedulerInfo sse_decode_box_autoadd_scheduler_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeccompMode sse_decode_box_autoadd_seccomp_mode(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CapabilitySet sse_decode_capability_set(SseDeserializer deserializer);
+
+  @protected
+  CgroupCpuStat sse_decode_cgroup_cpu_stat(SseDeserializer deserializer);
+
+  @protected
+  CgroupInfo sse_decode_cgroup_info(SseDeserializer deserializer);
+
+  @protected
+  CgroupIoStat sse_decode_cgroup_io_stat(SseDeserializer deserializer);
+
+  @protected
+  CgroupKind sse_decode_cgroup_kind(SseDeserializer deserializer);
+
+  @protected
+  CgroupMemoryEvents sse_decode_cgroup_memory_events(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConnectionInfo sse_decode_connection_info(SseDeserializer deserializer);
+
+  @protected
+  ConnectionState sse_decode_connection_state(SseDeserializer deserializer);
+
+  @protected
+  CpuTimesBreakdown sse_decode_cpu_times_breakdown(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CpuUsageMode sse_decode_cpu_usage_mode(SseDeserializer deserializer);
+
+  @protected
+  DiskActivity sse_decode_disk_activity(SseDeserializer deserializer);
+
+  @protected
+  DiskInfo sse_decode_disk_info(SseDeserializer deserializer);
+
+  @protected
+  EnvironmentVariable sse_decode_environment_variable(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FileDescriptorKind sse_decode_file_descriptor_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  IoPriority sse_decode_io_priority(SseDeserializer deserializer);
+
+  @protected
+  IoPriorityClass sse_decode_io_priority_class(SseDeserializer deserializer);
+
+  @protected
+  KillOutcome sse_decode_kill_outcome(SseDeserializer deserializer);
+
+  @protected
+  KillTreeStrategy sse_decode_kill_tree_strategy(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<CgroupInfo> sse_decode_list_cgroup_info(SseDeserializer deserializer);
+
+  @protected
+  List<CgroupIoStat> sse_decode_list_cgroup_io_stat(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConnectionInfo> sse_decode_list_connection_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CpuTimesBreakdown> sse_decode_list_cpu_times_breakdown(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DiskActivity> sse_decode_list_disk_activity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DiskInfo> sse_decode_list_disk_info(SseDeserializer deserializer);
+
+  @protected
+  List<EnvironmentVariable> sse_decode_list_environment_variable(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MappedFileSummary> sse_decode_list_mapped_file_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MemoryRegion> sse_decode_list_memory_region(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NetworkInterfaceInfo> sse_decode_list_network_interface_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OpenFileInfo> sse_decode_list_open_file_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PathUsage> sse_decode_list_path_usage(SseDeserializer deserializer);
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ProcessDetailError> sse_decode_list_process_detail_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer);
+
+  @protected
+  List<ProcessKillResult> sse_decode_list_process_kill_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ProcessNetworkUsage> sse_decode_list_process_network_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ThreadInfo> sse_decode_list_thread_info(SseDeserializer deserializer);
+
+  @protected
+  List<ThreadScheduling> sse_decode_list_thread_scheduling(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MappedFileSummary sse_decode_mapped_file_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryMaps sse_decode_memory_maps(SseDeserializer deserializer);
+
+  @protected
+  MemoryMetric sse_decode_memory_metric(SseDeserializer deserializer);
+
+  @protected
+  MemoryRegion sse_decode_memory_region(SseDeserializer deserializer);
+
+  @protected
+  MountFilter sse_decode_mount_filter(SseDeserializer deserializer);
+
+  @protected
+  NetworkAttribution sse_decode_network_attribution(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NetworkInfo sse_decode_network_info(SseDeserializer deserializer);
+
+  @protected
+  NetworkInterfaceInfo sse_decode_network_interface_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OpenFileInfo sse_decode_open_file_info(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CgroupCpuStat? sse_decode_opt_box_autoadd_cgroup_cpu_stat(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CgroupMemoryEvents? sse_decode_opt_box_autoadd_cgroup_memory_events(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Pressure? sse_decode_opt_box_autoadd_pressure(SseDeserializer deserializer);
+
+  @protected
+  PressureLine? sse_decode_opt_box_autoadd_pressure_line(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessControlError? sse_decode_opt_box_autoadd_process_control_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessCredentials? sse_decode_opt_box_autoadd_process_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessDiskIo? sse_decode_opt_box_autoadd_process_disk_io(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeccompMode? sse_decode_opt_box_autoadd_seccomp_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<EnvironmentVariable>? sse_decode_opt_list_environment_variable(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PathUsage sse_decode_path_usage(SseDeserializer deserializer);
+
+  @protected
+  PathUsageKind sse_decode_path_usage_kind(SseDeserializer deserializer);
+
+  @protected
+  Pressure sse_decode_pressure(SseDeserializer deserializer);
+
+  @protected
+  PressureLine sse_decode_pressure_line(SseDeserializer deserializer);
+
+  @protected
+  PriorityLevel sse_decode_priority_level(SseDeserializer deserializer);
+
+  @protected
+  ProcessControlError sse_decode_process_control_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessControlErrorKind sse_decode_process_control_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessCredentials sse_decode_process_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessDetailError sse_decode_process_detail_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessDetailField sse_decode_process_detail_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessDetails sse_decode_process_details(SseDeserializer deserializer);
+
+  @protected
+  ProcessDiskIo sse_decode_process_disk_io(SseDeserializer deserializer);
+
+  @protected
+  ProcessInfo sse_decode_process_info(SseDeserializer deserializer);
+
+  @protected
+  ProcessKillResult sse_decode_process_kill_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessMemory sse_decode_process_memory(SseDeserializer deserializer);
+
+  @protected
+  ProcessNetworkUsage sse_decode_process_network_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessPriority sse_decode_process_priority(SseDeserializer deserializer);
+
+  @protected
+  ProcessSignal sse_decode_process_signal(SseDeserializer deserializer);
+
+  @protected
+  ProcessState sse_decode_process_state(SseDeserializer deserializer);
+
+  @protected
+  SchedulerInfo sse_decode_scheduler_info(SseDeserializer deserializer);
+
+  @protected
+  SchedulerPolicy sse_decode_scheduler_policy(SseDeserializer deserializer);
+
+  @protected
+  SeccompMode sse_decode_seccomp_mode(SseDeserializer deserializer);
+
+  @protected
+  SecurityContext sse_decode_security_context(SseDeserializer deserializer);
+
+  @protected
+  SystemInfo sse_decode_system_info(SseDeserializer deserializer);
+
+  @protected
+  SystemResourceInfo sse_decode_system_resource_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TerminationOutcome sse_decode_termination_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThreadInfo sse_decode_thread_info(SseDeserializer deserializer);
+
+  @protected
+  ThreadScheduling sse_decode_thread_scheduling(SseDeserializer deserializer);
+
+  @protected
+  TransportProtocol sse_decode_transport_protocol(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_cgroup_cpu_stat(
+    CgroupCpuStat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cgroup_memory_events(
+    CgroupMemoryEvents self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_io_priority(
+    IoPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_mount_filter(
+    MountFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_pressure(Pressure self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_pressure_line(
+    PressureLine self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_process_control_error(
+    ProcessControlError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_process_credentials(
+    ProcessCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_process_disk_io(
+    ProcessDiskIo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_scheduler_info(
+    SchedulerInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_seccomp_mode(
+    SeccompMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_capability_set(CapabilitySet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cgroup_cpu_stat(CgroupCpuStat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cgroup_info(CgroupInfo self, SseSerializer serializer);
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_cgroup_io_stat(CgroupIoStat self, SseSerializer serializer);
 
   @protected
-  List<DiskInfo> dco_decode_list_disk_info(dynamic raw);
+  void sse_encode_cgroup_kind(CgroupKind self, SseSerializer serializer);
 
   @protected
-  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+  void sse_encode_cgroup_memory_events(
+    CgroupMemoryEvents self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_connection_info(
+    ConnectionInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ProcessInfo> dco_decode_list_process_info(dynamic raw);
+  void sse_encode_connection_state(
+    ConnectionState self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NetworkInfo dco_decode_network_info(dynamic raw);
+  void sse_encode_cpu_times_breakdown(
+    CpuTimesBreakdown self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  void sse_encode_cpu_usage_mode(CpuUsageMode self, SseSerializer serializer);
 
   @protected
-  ProcessInfo dco_decode_process_info(dynamic raw);
+  void sse_encode_disk_activity(DiskActivity self, SseSerializer serializer);
 
   @protected
-  SystemInfo dco_decode_system_info(dynamic raw);
+  void sse_encode_disk_info(DiskInfo self, SseSerializer serializer);
 
   @protected
-  SystemResourceInfo dco_decode_system_resource_info(dynamic raw);
+  void sse_encode_environment_variable(
+    EnvironmentVariable self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_file_descriptor_kind(
+    FileDescriptorKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_io_priority(IoPriority self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_io_priority_class(
+    IoPriorityClass self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_kill_outcome(KillOutcome self, SseSerializer serializer);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_kill_tree_strategy(
+    KillTreeStrategy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  DiskInfo sse_decode_disk_info(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_list_cgroup_info(
+    List<CgroupInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<DiskInfo> sse_decode_list_disk_info(SseDeserializer deserializer);
+  void sse_encode_list_cgroup_io_stat(
+    List<CgroupIoStat> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+  void sse_encode_list_connection_info(
+    List<ConnectionInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_cpu_times_breakdown(
+    List<CpuTimesBreakdown> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer);
+  void sse_encode_list_disk_activity(
+    List<DiskActivity> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NetworkInfo sse_decode_network_info(SseDeserializer deserializer);
+  void sse_encode_list_disk_info(List<DiskInfo> self, SseSerializer serializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_list_environment_variable(
+    List<EnvironmentVariable> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ProcessInfo sse_decode_process_info(SseDeserializer deserializer);
+  void sse_encode_list_mapped_file_summary(
+    List<MappedFileSummary> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SystemInfo sse_decode_system_info(SseDeserializer deserializer);
+  void sse_encode_list_memory_region(
+    List<MemoryRegion> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SystemResourceInfo sse_decode_system_resource_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_network_interface_info(
+    List<NetworkInterfaceInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_list_open_file_info(
+    List<OpenFileInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_list_path_usage(
+    List<PathUsage> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_list_process_detail_error(
+    List<ProcessDetailError> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_list_process_info(
+    List<ProcessInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_disk_info(DiskInfo self, SseSerializer serializer);
+  void sse_encode_list_process_kill_result(
+    List<ProcessKillResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_list_process_network_usage(
+    List<ProcessNetworkUsage> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_disk_info(List<DiskInfo> self, SseSerializer serializer);
+  void sse_encode_list_thread_info(
+    List<ThreadInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_prim_f_64_strict(
-    Float64List self,
+  void sse_encode_list_thread_scheduling(
+    List<ThreadScheduling> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_mapped_file_summary(
+    MappedFileSummary self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_process_info(
-    List<ProcessInfo> self,
+  void sse_encode_memory_maps(MemoryMaps self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_memory_metric(MemoryMetric self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_memory_region(MemoryRegion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mount_filter(MountFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_attribution(
+    NetworkAttribution self,
     SseSerializer serializer,
   );
 
   @protected
   void sse_encode_network_info(NetworkInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_network_interface_info(
+    NetworkInterfaceInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_open_file_info(OpenFileInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_cgroup_cpu_stat(
+    CgroupCpuStat? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_cgroup_memory_events(
+    CgroupMemoryEvents? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pressure(
+    Pressure? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pressure_line(
+    PressureLine? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_control_error(
+    ProcessControlError? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_credentials(
+    ProcessCredentials? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_disk_io(
+    ProcessDiskIo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_seccomp_mode(
+    SeccompMode? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_environment_variable(
+    List<EnvironmentVariable>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_path_usage(PathUsage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_path_usage_kind(PathUsageKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pressure(Pressure self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pressure_line(PressureLine self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_priority_level(PriorityLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_process_control_error(
+    ProcessControlError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_control_error_kind(
+    ProcessControlErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_credentials(
+    ProcessCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_detail_error(
+    ProcessDetailError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_detail_field(
+    ProcessDetailField self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_details(
+    ProcessDetails self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_disk_io(ProcessDiskIo self, SseSerializer serializer);
+
   @protected
   void sse_encode_process_info(ProcessInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_process_kill_result(
+    ProcessKillResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_memory(ProcessMemory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_process_network_usage(
+    ProcessNetworkUsage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_priority(
+    ProcessPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_signal(ProcessSignal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_process_state(ProcessState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scheduler_info(SchedulerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scheduler_policy(
+    SchedulerPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_seccomp_mode(SeccompMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_security_context(
+    SecurityContext self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_system_info(SystemInfo self, SseSerializer serializer);
 
@@ -187,6 +1324,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_termination_outcome(
+    TerminationOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thread_info(ThreadInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_thread_scheduling(
+    ThreadScheduling self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transport_protocol(
+    TransportProtocol self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -198,9 +1359,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -1,17 +1,38 @@
 // 导入平台特定实现
 #[cfg(target_os = "windows")]
 use crate::platform::windows::{
-    get_processes_impl, get_system_resources_impl, get_system_info_impl, kill_process_impl
+    find_processes_using_impl, get_cgroup_tree_impl, get_connections_impl, get_cpu_affinity_impl,
+    get_current_user_impl, get_disk_activity_impl, get_disks_impl, get_memory_maps_impl,
+    get_network_interfaces_impl, get_open_files_impl, get_priority_impl, get_process_details_impl,
+    get_process_memory_impl, get_process_network_usage_impl, get_processes_impl, get_scheduler_impl,
+    get_security_context_impl, get_system_info_impl, get_system_resources_impl, get_thread_scheduling_impl,
+    get_threads_impl, kill_process_impl, kill_process_tree_impl, resume_process_impl, send_signal_impl,
+    set_cpu_affinity_impl, set_cpu_usage_mode_impl, set_io_priority_impl, set_memory_metric_impl,
+    set_priority_impl, set_scheduler_impl, suspend_process_impl, terminate_gracefully_impl
 };
 
 #[cfg(target_os = "linux")]
 use crate::platform::linux::{
-    get_processes_impl, get_system_resources_impl, get_system_info_impl, kill_process_impl
+    find_processes_using_impl, get_cgroup_tree_impl, get_connections_impl, get_cpu_affinity_impl,
+    get_current_user_impl, get_disk_activity_impl, get_disks_impl, get_memory_maps_impl,
+    get_network_interfaces_impl, get_open_files_impl, get_priority_impl, get_process_details_impl,
+    get_process_memory_impl, get_process_network_usage_impl, get_processes_impl, get_scheduler_impl,
+    get_security_context_impl, get_system_info_impl, get_system_resources_impl, get_thread_scheduling_impl,
+    get_threads_impl, kill_process_impl, kill_process_tree_impl, resume_process_impl, send_signal_impl,
+    set_cpu_affinity_impl, set_cpu_usage_mode_impl, set_io_priority_impl, set_memory_metric_impl,
+    set_priority_impl, set_scheduler_impl, suspend_process_impl, terminate_gracefully_impl
 };
 
 #[cfg(target_os = "macos")]
 use crate::platform::macos::{
-    get_processes_impl, get_system_resources_impl, get_system_info_impl, kill_process_impl
+    find_processes_using_impl, get_cgroup_tree_impl, get_connections_impl, get_cpu_affinity_impl,
+    get_current_user_impl, get_disk_activity_impl, get_disks_impl, get_memory_maps_impl,
+    get_network_interfaces_impl, get_open_files_impl, get_priority_impl, get_process_details_impl,
+    get_process_memory_impl, get_process_network_usage_impl, get_processes_impl, get_scheduler_impl,
+    get_security_context_impl, get_system_info_impl, get_system_resources_impl, get_thread_scheduling_impl,
+    get_threads_impl, kill_process_impl, kill_process_tree_impl, resume_process_impl, send_signal_impl,
+    set_cpu_affinity_impl, set_cpu_usage_mode_impl, set_io_priority_impl, set_memory_metric_impl,
+    set_priority_impl, set_scheduler_impl, suspend_process_impl, terminate_gracefully_impl
 };
 
 #[flutter_rust_bridge::frb(sync)] // Synchronous mode for simplicity of the demo
@@ -38,10 +59,566 @@ pub struct ProcessInfo {
     pub name: String,
     pub cpu_usage: f64,
     pub memory_usage: u64, // in bytes
+    /// What `memory_usage` measures: the metric chosen with `set_memory_metric`, or Rss
+    /// where that is unavailable (other users' processes, non-Linux platforms)
+    pub memory_metric: MemoryMetric,
     pub parent_pid: Option<u32>,
     pub status: String,
+    pub state: ProcessState,
     pub command: String,
     pub start_time: u64, // timestamp
+    pub age: u64, // seconds since start_time
+    pub priority: PriorityLevel,
+    /// Nice value, -20 (highest) to 19; derived from the priority class on Windows
+    pub nice: i32,
+    pub io_priority: IoPriority,
+    /// CPU the process last ran on (Linux only)
+    pub last_cpu: Option<u32>,
+    pub thread_count: u32,
+    /// None when the I/O counters of the process cannot be read (other users' processes
+    /// without root)
+    pub disk_io: Option<ProcessDiskIo>,
+    /// Effective user name ("DOMAIN\\name" on Windows), or the numeric uid when it has no
+    /// name. None when the owner cannot be read, such as other users' processes on Windows
+    /// without administrator rights. Compare with `get_current_user` to pick out the caller's
+    /// own processes; None never matches.
+    pub user: Option<String>,
+    /// None where unavailable (Windows)
+    pub credentials: Option<ProcessCredentials>,
+    /// Holds any effective capability on Linux, runs as root on macOS, has an elevated token
+    /// on Windows; see `get_security_context`
+    pub privileged: bool,
+    /// cgroup of the process as in /proc/<pid>/cgroup, e.g. "/system.slice/sshd.service" (Linux only)
+    pub cgroup: Option<String>,
+}
+
+/// User and group ids of a process, with their names. Names fall back to the
+/// number when the id has no entry.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessCredentials {
+    pub real_uid: u32,
+    /// The uid used for permission checks; differs from real_uid for setuid programs
+    pub effective_uid: u32,
+    pub saved_uid: u32,
+    pub real_gid: u32,
+    pub effective_gid: u32,
+    pub saved_gid: u32,
+    /// Empty on macOS
+    pub supplementary_groups: Vec<u32>,
+    pub real_user: String,
+    pub effective_user: String,
+    /// Name of effective_gid
+    pub group: String,
+    /// In the order of supplementary_groups
+    pub supplementary_group_names: Vec<String>,
+}
+
+/// I/O counters of a process since it started. Rates cover the interval since the
+/// previous `get_processes` call and are 0 the first time a process is seen.
+/// On Windows the byte counts include all I/O (files, devices, network), not only disk.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDiskIo {
+    /// Bytes fetched from storage (page cache hits are not counted)
+    pub read_bytes: u64,
+    /// Bytes sent to storage, including later-cancelled writeback
+    pub write_bytes: u64,
+    /// Dirty page cache bytes that were truncated away before reaching storage
+    pub cancelled_write_bytes: u64,
+    /// Bytes passed to read()-like syscalls, including cache hits, pipes and sockets
+    pub read_chars: u64,
+    pub write_chars: u64,
+    pub read_syscalls: u64,
+    pub write_syscalls: u64,
+    pub read_bytes_per_sec: f64,
+    /// Net of cancelled writes
+    pub write_bytes_per_sec: f64,
+}
+
+/// Fields of `ProcessDetails` that are read separately and may fail on their own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessDetailField {
+    Executable,
+    WorkingDirectory,
+    RootDirectory,
+    CommandLine,
+    Environment,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessDetailError {
+    pub field: ProcessDetailField,
+    pub error: ProcessControlError,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvironmentVariable {
+    pub name: String,
+    pub value: String,
+}
+
+/// Result of `get_process_details`. A field is None when it could not be read (see
+/// `errors`) or does not exist, e.g. the executable of a kernel thread.
+#[derive(Debug, Clone)]
+pub struct ProcessDetails {
+    pub pid: u32,
+    /// Resolved path of the running executable
+    pub exe: Option<String>,
+    /// The executable was deleted or replaced on disk since the process started
+    pub exe_deleted: bool,
+    pub cwd: Option<String>,
+    /// Root directory; differs from "/" inside a chroot or container
+    pub root: Option<String>,
+    /// Arguments as the process sees them; empty for kernel threads
+    pub argv: Option<Vec<String>>,
+    /// Environment the process was started with. Changes it made later with setenv()
+    /// are not visible.
+    pub environment: Option<Vec<EnvironmentVariable>>,
+    pub errors: Vec<ProcessDetailError>,
+}
+
+/// Which figure backs `ProcessInfo.memory_usage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryMetric {
+    /// Resident set size; shared libraries count in full for every process mapping them
+    Rss,
+    /// Proportional set size: each shared page is split between the processes sharing it,
+    /// so the values add up to the memory actually in use (Linux only)
+    Pss,
+    /// Unique set size: pages private to the process, i.e. what killing it would free (Linux only)
+    Uss,
+}
+
+/// Memory breakdown of a process from /proc/<pid>/smaps_rollup, in bytes
+#[derive(Debug, Clone, Default)]
+pub struct ProcessMemory {
+    pub rss: u64,
+    pub pss: u64,
+    /// private_clean + private_dirty
+    pub uss: u64,
+    pub shared_clean: u64,
+    pub shared_dirty: u64,
+    pub private_clean: u64,
+    pub private_dirty: u64,
+    pub swap: u64,
+    /// Swap split between the processes sharing it, like pss
+    pub swap_pss: u64,
+    /// mlock()ed memory
+    pub locked: u64,
+    /// Transparent and hugetlbfs huge pages
+    pub huge_pages: u64,
+}
+
+/// One mapping of a process's address space, from `get_memory_maps`. Sizes in bytes.
+#[derive(Debug, Clone)]
+pub struct MemoryRegion {
+    pub start: u64,
+    pub end: u64,
+    pub size: u64,
+    /// "r-xp" style: read, write, execute, then p(rivate) or s(hared)
+    pub permissions: String,
+    /// Offset into the mapped file
+    pub offset: u64,
+    /// "major:minor" of the device holding the file, "00:00" for anonymous memory
+    pub device: String,
+    pub inode: u64,
+    /// File path, a pseudo-name such as [heap], [stack] or [vdso], or [anon] for
+    /// anonymous memory
+    pub path: String,
+    /// The mapped file was unlinked (e.g. a library replaced by an upgrade)
+    pub deleted: bool,
+    pub rss: u64,
+    pub pss: u64,
+    /// Private clean + dirty pages
+    pub private: u64,
+    pub swap: u64,
+}
+
+/// Totals of all regions mapping the same path
+#[derive(Debug, Clone, Default)]
+pub struct MappedFileSummary {
+    pub path: String,
+    pub regions: u32,
+    pub size: u64,
+    pub rss: u64,
+    pub pss: u64,
+    pub private: u64,
+    pub swap: u64,
+}
+
+/// Result of `get_memory_maps`
+#[derive(Debug, Clone)]
+pub struct MemoryMaps {
+    /// In address order
+    pub regions: Vec<MemoryRegion>,
+    /// Per path, largest PSS first
+    pub files: Vec<MappedFileSummary>,
+}
+
+/// Scheduler state of a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessState {
+    Running,
+    Sleeping,
+    /// Uninterruptible sleep, usually waiting on I/O
+    DiskSleep,
+    Stopped,
+    /// Suspended with `suspend_process` (stopped or in a frozen cgroup)
+    Suspended,
+    /// Stopped by a debugger
+    Traced,
+    Zombie,
+    /// Exiting, after the zombie has been reaped
+    Dead,
+    /// Idle kernel thread
+    Idle,
+    /// Kernel thread parked while its CPU is offline
+    Parked,
+    Unknown,
+}
+
+impl ProcessState {
+    /// Human readable name, used for `ProcessInfo.status`
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            ProcessState::Running => "Running",
+            ProcessState::Sleeping => "Sleeping",
+            ProcessState::DiskSleep => "Disk Sleep",
+            ProcessState::Stopped => "Stopped",
+            ProcessState::Suspended => "Suspended",
+            ProcessState::Traced => "Traced",
+            ProcessState::Zombie => "Zombie",
+            ProcessState::Dead => "Dead",
+            ProcessState::Idle => "Idle",
+            ProcessState::Parked => "Parked",
+            ProcessState::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Named scheduling priority, mapped to nice values on Linux/macOS and to priority
+/// classes on Windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityLevel {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+}
+
+impl PriorityLevel {
+    pub(crate) fn nice(&self) -> i32 {
+        match self {
+            PriorityLevel::Idle => 19,
+            PriorityLevel::BelowNormal => 10,
+            PriorityLevel::Normal => 0,
+            PriorityLevel::AboveNormal => -5,
+            PriorityLevel::High => -10,
+        }
+    }
+
+    /// The level a nice value falls into
+    #[cfg(not(target_os = "windows"))]
+    pub(crate) fn from_nice(nice: i32) -> Self {
+        match nice {
+            15.. => PriorityLevel::Idle,
+            5..=14 => PriorityLevel::BelowNormal,
+            -4..=4 => PriorityLevel::Normal,
+            -9..=-5 => PriorityLevel::AboveNormal,
+            _ => PriorityLevel::High,
+        }
+    }
+}
+
+/// I/O scheduling class (see ioprio_set(2))
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoPriorityClass {
+    /// Not set; the kernel derives best-effort priority from the nice value
+    None,
+    Realtime,
+    BestEffort,
+    /// Only gets disk time when no one else needs it
+    Idle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoPriority {
+    pub class: IoPriorityClass,
+    /// 0 (highest) to 7 within the Realtime and BestEffort classes
+    pub level: u8,
+}
+
+impl Default for IoPriority {
+    fn default() -> Self {
+        IoPriority { class: IoPriorityClass::None, level: 4 }
+    }
+}
+
+/// Result of `get_priority`
+#[derive(Debug, Clone)]
+pub struct ProcessPriority {
+    pub level: PriorityLevel,
+    pub nice: i32,
+    pub io_priority: IoPriority,
+}
+
+/// What an open file descriptor refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDescriptorKind {
+    File,
+    Directory,
+    /// Character or block device
+    Device,
+    Socket,
+    Pipe,
+    /// anon_inode: eventfd, epoll, inotify, timerfd, ...
+    AnonInode,
+    Other,
+}
+
+/// One open file descriptor, from `get_open_files`
+#[derive(Debug, Clone)]
+pub struct OpenFileInfo {
+    pub fd: u32,
+    pub kind: FileDescriptorKind,
+    /// Target path, or the link text for sockets, pipes and anon inodes ("socket:[1234]",
+    /// "anon_inode:[eventfd]")
+    pub path: String,
+    /// open(2) flags as listed in fdinfo
+    pub flags: u32,
+    pub readable: bool,
+    pub writable: bool,
+    /// Current file offset
+    pub position: u64,
+    /// The file was unlinked while still open
+    pub deleted: bool,
+    /// Inode of sockets and pipes, to match them with `ConnectionInfo.inode`; 0 otherwise
+    pub inode: u64,
+}
+
+/// How a process uses the path given to `find_processes_using`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathUsageKind {
+    /// Held through an open file descriptor
+    OpenFile,
+    WorkingDirectory,
+    /// Root directory of the process (chroot or container)
+    RootDirectory,
+    /// The process is running this executable
+    Executable,
+    /// Mapped into memory, e.g. a shared library
+    MemoryMapped,
+}
+
+/// One process holding a path, from `find_processes_using`
+#[derive(Debug, Clone)]
+pub struct PathUsage {
+    pub pid: u32,
+    pub name: String,
+    pub kind: PathUsageKind,
+    /// What the process holds; may be below the queried directory or mount point
+    pub path: String,
+    /// Descriptor number for OpenFile
+    pub fd: Option<u32>,
+}
+
+/// A capability mask with the names of its bits, e.g. "CAP_NET_ADMIN"
+#[derive(Debug, Clone, Default)]
+pub struct CapabilitySet {
+    pub mask: u64,
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeccompMode {
+    Disabled,
+    /// Only read, write, _exit and sigreturn are allowed
+    Strict,
+    /// Syscalls are checked by BPF filters
+    Filter,
+}
+
+/// Privileges and confinement of a process, from `get_security_context`
+#[derive(Debug, Clone, Default)]
+pub struct SecurityContext {
+    pub pid: u32,
+    pub effective: CapabilitySet,
+    pub permitted: CapabilitySet,
+    pub inheritable: CapabilitySet,
+    /// Upper limit of capabilities the process and its children can ever gain
+    pub bounding: CapabilitySet,
+    /// Kept across execve of unprivileged programs (Linux 4.3+)
+    pub ambient: CapabilitySet,
+    /// None when the kernel is built without seccomp
+    pub seccomp: Option<SeccompMode>,
+    /// Number of attached filters (Linux 5.9+)
+    pub seccomp_filters: Option<u32>,
+    /// Set when execve can no longer grant privileges (setuid bits, file capabilities)
+    pub no_new_privs: Option<bool>,
+    /// selinux, apparmor, smack or tomoyo
+    pub lsm: Option<String>,
+    /// Label from /proc/<pid>/attr/current, e.g. "unconfined" or "system_u:system_r:sshd_t:s0"
+    pub lsm_label: Option<String>,
+}
+
+/// One thread of a process, from `get_threads`
+#[derive(Debug, Clone)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    pub state: ProcessState,
+    pub status: String,
+    /// Same scale as `ProcessInfo.cpu_usage`; 0 on the first call for a process
+    pub cpu_usage: f64,
+    pub user_time_ms: u64,
+    pub system_time_ms: u64,
+    pub last_cpu: Option<u32>,
+    /// Kernel priority (20 + nice, negative for real-time) on Linux, base priority on Windows
+    pub priority: i32,
+    pub nice: i32,
+    pub voluntary_context_switches: u64,
+    pub involuntary_context_switches: u64,
+}
+
+/// Linux scheduling policy (see sched(7))
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerPolicy {
+    /// SCHED_OTHER, the default time-sharing policy
+    Other,
+    /// SCHED_BATCH, for CPU-bound non-interactive work
+    Batch,
+    /// SCHED_IDLE, runs only when the CPU has nothing else to do
+    Idle,
+    /// SCHED_FIFO real-time
+    Fifo,
+    /// SCHED_RR real-time
+    RoundRobin,
+    /// SCHED_DEADLINE; cannot be set through this API
+    Deadline,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchedulerInfo {
+    pub policy: SchedulerPolicy,
+    /// Real-time priority, 1-99 for Fifo and RoundRobin, 0 otherwise
+    pub priority: u32,
+}
+
+/// Affinity and scheduling of one thread, from `get_thread_scheduling`
+#[derive(Debug, Clone)]
+pub struct ThreadScheduling {
+    pub tid: u32,
+    pub name: String,
+    /// CPU ids the thread may run on
+    pub affinity: Vec<u32>,
+    pub scheduler: SchedulerInfo,
+    pub last_cpu: Option<u32>,
+}
+
+/// Signals that can be sent with `send_signal`.
+/// On Windows, Terminate/Hangup/Quit close the process's windows (WM_CLOSE) or send
+/// CTRL_BREAK to its console, Interrupt sends CTRL_C and Kill calls TerminateProcess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    Hangup,
+    Interrupt,
+    Quit,
+    Abort,
+    Kill,
+    User1,
+    User2,
+    Terminate,
+    Continue,
+    Stop,
+    TerminalStop,
+}
+
+/// Which step of `terminate_gracefully` ended the process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationOutcome {
+    /// Exited within the timeout after the polite request (SIGTERM / WM_CLOSE)
+    Terminated,
+    /// Had to be killed (SIGKILL / TerminateProcess)
+    Killed,
+}
+
+/// How `kill_process_tree` delivers the kill
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillTreeStrategy {
+    /// Kill each process individually, deepest descendants first
+    LeavesFirst,
+    /// Kill whole process groups led by a member of the tree in one call, then any
+    /// remaining members individually. Same as LeavesFirst on Windows.
+    ProcessGroup,
+}
+
+/// What happened to one process of a `kill_process_tree` call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillOutcome {
+    Killed,
+    /// Exited (or its pid was reused) before it was signalled
+    AlreadyExited,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessKillResult {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub outcome: KillOutcome,
+    /// Set when outcome is Failed
+    pub error: Option<ProcessControlError>,
+}
+
+/// Why a process control call failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessControlErrorKind {
+    /// EPERM / ERROR_ACCESS_DENIED
+    PermissionDenied,
+    /// ESRCH: the process does not exist or has already exited
+    NoSuchProcess,
+    /// Kernel threads and critical system processes that must not be touched
+    ProtectedProcess,
+    InvalidArgument,
+    /// The platform has no equivalent of the requested operation
+    Unsupported,
+    /// The process was signalled but did not go away in time
+    TimedOut,
+    Other,
+}
+
+/// Error of the process control functions, thrown as an exception on the Dart side
+#[derive(Debug, Clone)]
+pub struct ProcessControlError {
+    pub kind: ProcessControlErrorKind,
+    /// errno on Linux/macOS, GetLastError() on Windows; 0 when the error is not from the OS
+    pub code: i32,
+    pub message: String,
+}
+
+impl ProcessControlError {
+    pub(crate) fn new(kind: ProcessControlErrorKind, code: i32, message: impl Into<String>) -> Self {
+        ProcessControlError { kind, code, message: message.into() }
+    }
+}
+
+impl std::fmt::Display for ProcessControlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.code != 0 { write!(f, "{} (os error {})", self.message, self.code) } else { f.write_str(&self.message) }
+    }
+}
+
+impl std::error::Error for ProcessControlError {}
+
+/// How per-process CPU usage is normalised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuUsageMode {
+    /// Share of the whole machine, 0-100 regardless of core count
+    Machine,
+    /// Share of a single core, up to 100 * cores (like top)
+    SingleCore,
 }
 
 // System resource information
@@ -49,6 +626,9 @@ pub struct ProcessInfo {
 pub struct SystemResourceInfo {
     pub cpu_usage: f64,
     pub cpu_per_core: Vec<f64>,
+    // Where the CPU time went over the sample interval
+    pub cpu_times: CpuTimesBreakdown,
+    pub cpu_times_per_core: Vec<CpuTimesBreakdown>,
     pub memory_total: u64,
     pub memory_used: u64,
     pub memory_available: u64,
@@ -60,6 +640,27 @@ pub struct SystemResourceInfo {
     pub network_usage: NetworkInfo,
 }
 
+/// Share of CPU time per category over the sample interval, in percent.
+/// The categories add up to 100; guest time is reported separately from user/nice.
+/// Categories a platform does not track are left at 0.
+#[derive(Debug, Clone, Default)]
+pub struct CpuTimesBreakdown {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    /// Hardware interrupts (interrupt time on Windows)
+    pub irq: f64,
+    /// Soft interrupts (DPC time on Windows)
+    pub softirq: f64,
+    /// Time stolen by the hypervisor for other guests
+    pub steal: f64,
+    /// Time spent running a virtual CPU for a guest OS
+    pub guest: f64,
+    pub guest_nice: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct DiskInfo {
     pub name: String,
@@ -67,6 +668,48 @@ pub struct DiskInfo {
     pub total_space: u64,
     pub used_space: u64,
     pub available_space: u64,
+    pub fs_type: String,
+    /// Mount source, e.g. /dev/sda1 or server:/export
+    pub device: String,
+    pub mount_options: Vec<String>,
+    pub read_only: bool,
+    pub inodes_total: u64,
+    pub inodes_used: u64,
+    pub inodes_free: u64,
+}
+
+/// I/O activity of one block device. Rates cover the interval since the
+/// previous `get_disk_activity` call and are 0 on the first call.
+#[derive(Debug, Clone)]
+pub struct DiskActivity {
+    pub name: String,
+    /// Disk this partition belongs to; None for whole disks
+    pub parent: Option<String>,
+    pub read_bytes: u64,  // total since boot
+    pub write_bytes: u64, // total since boot
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    /// Average time an I/O request took, queueing included
+    pub avg_wait_ms: f64,
+    /// Percentage of the interval the device was busy ("active time")
+    pub utilization: f64,
+}
+
+/// Which mounts `get_disks` reports
+#[derive(Debug, Clone)]
+pub struct MountFilter {
+    /// Include proc, sysfs, cgroup, overlay layers and other virtual filesystems
+    pub include_pseudo: bool,
+    /// Report a device mounted in several places (bind mounts) only once
+    pub collapse_bind_mounts: bool,
+}
+
+impl Default for MountFilter {
+    fn default() -> Self {
+        MountFilter { include_pseudo: false, collapse_bind_mounts: true }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -77,6 +720,198 @@ pub struct NetworkInfo {
     pub packets_received: u64,
 }
 
+/// One network interface. Counters are totals since the interface came up; the
+/// rates cover the interval since the previous `get_network_interfaces` call.
+#[derive(Debug, Clone)]
+pub struct NetworkInterfaceInfo {
+    pub name: String,
+    /// ethernet, wireless, loopback, bridge, veth, tun, bond, vlan, virtual, ...
+    pub kind: String,
+    pub is_loopback: bool,
+    /// Not backed by hardware (veth, docker0, bridges, tunnels)
+    pub is_virtual: bool,
+    /// up, down, dormant, unknown, ...
+    pub operstate: String,
+    pub link_speed_mbps: Option<u64>,
+    pub mtu: u32,
+    pub mac_address: String,
+    pub ipv4_addresses: Vec<String>, // "address/prefix"
+    pub ipv6_addresses: Vec<String>, // "address/prefix"
+    pub bytes_received: u64,
+    pub bytes_sent: u64,
+    pub packets_received: u64,
+    pub packets_sent: u64,
+    pub receive_errors: u64,
+    pub send_errors: u64,
+    pub receive_dropped: u64,
+    pub send_dropped: u64,
+    pub receive_bytes_per_sec: f64,
+    pub send_bytes_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportProtocol {
+    Tcp,
+    Udp,
+}
+
+/// TCP connection state. UDP sockets are either Established (connected) or Unconnected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Established,
+    SynSent,
+    SynReceived,
+    FinWait1,
+    FinWait2,
+    TimeWait,
+    Closed,
+    CloseWait,
+    LastAck,
+    Listen,
+    Closing,
+    Unconnected,
+    Unknown,
+}
+
+/// One TCP/UDP socket, like a row of `ss -tuanp`
+#[derive(Debug, Clone)]
+pub struct ConnectionInfo {
+    pub protocol: TransportProtocol,
+    pub ipv6: bool,
+    pub local_address: String,
+    pub local_port: u16,
+    pub remote_address: String,
+    pub remote_port: u16,
+    pub state: ConnectionState,
+    pub send_queue: u64,    // bytes
+    pub receive_queue: u64, // bytes
+    /// Owning process; None if it could not be determined (e.g. no permission)
+    pub pid: Option<u32>,
+    pub uid: u32,
+    pub inode: u64,
+}
+
+/// Where the traffic of a `ProcessNetworkUsage` row was measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkAttribution {
+    /// Summed over the TCP sockets the process holds
+    Socket,
+    /// Interface totals of the process's network namespace, e.g. a container.
+    /// Only its lowest pid gets a row.
+    Namespace,
+}
+
+/// Network traffic of one process, from `get_process_network_usage`. Byte counts
+/// are totals of the sockets currently open (or of the namespace's interfaces); the
+/// rates cover the interval since the previous call.
+#[derive(Debug, Clone)]
+pub struct ProcessNetworkUsage {
+    pub pid: u32,
+    pub name: String,
+    pub received_bytes: u64,
+    /// Bytes acknowledged by the peer, so retransmissions count once
+    pub sent_bytes: u64,
+    pub receive_bytes_per_sec: f64,
+    pub send_bytes_per_sec: f64,
+    /// TCP sockets counted; 0 for Namespace rows
+    pub connections: u32,
+    pub source: NetworkAttribution,
+    /// Inode of the network namespace, if known
+    pub net_namespace: Option<u64>,
+}
+
+/// systemd unit type of a cgroup, from its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CgroupKind {
+    Root,
+    Slice,
+    Service,
+    /// Also containers, e.g. docker-<id>.scope
+    Scope,
+    Other,
+}
+
+/// cpu.stat of a cgroup, in microseconds
+#[derive(Debug, Clone, Default)]
+pub struct CgroupCpuStat {
+    pub usage_usec: u64,
+    pub user_usec: u64,
+    pub system_usec: u64,
+    /// Enforcement periods of cpu.max, and how many of them hit the limit
+    pub nr_periods: u64,
+    pub nr_throttled: u64,
+    pub throttled_usec: u64,
+}
+
+/// memory.events: how often each limit was hit
+#[derive(Debug, Clone, Default)]
+pub struct CgroupMemoryEvents {
+    pub low: u64,
+    pub high: u64,
+    pub max: u64,
+    pub oom: u64,
+    pub oom_kill: u64,
+}
+
+/// One device line of io.stat
+#[derive(Debug, Clone, Default)]
+pub struct CgroupIoStat {
+    /// "major:minor"
+    pub device: String,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub read_ios: u64,
+    pub write_ios: u64,
+    pub discard_bytes: u64,
+    pub discard_ios: u64,
+}
+
+/// One line of a pressure stall (PSI) file: percentage of time stalled over the last
+/// 10, 60 and 300 seconds, and total stall time
+#[derive(Debug, Clone, Default)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total_usec: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Pressure {
+    /// At least one task was stalled
+    pub some: PressureLine,
+    /// All non-idle tasks were stalled at once; missing for CPU before Linux 5.13
+    pub full: Option<PressureLine>,
+}
+
+/// One cgroup of the v2 hierarchy, from `get_cgroup_tree`. Counters cover the cgroup
+/// and everything below it; fields are None when the controller is not enabled there.
+#[derive(Debug, Clone)]
+pub struct CgroupInfo {
+    /// Relative to the hierarchy root, which is "/"
+    pub path: String,
+    pub name: String,
+    pub parent: Option<String>,
+    pub kind: CgroupKind,
+    /// Processes directly in this cgroup, not in its children
+    pub process_count: u32,
+    pub cpu: Option<CgroupCpuStat>,
+    /// Over the interval since the previous call, normalised like `ProcessInfo.cpu_usage`
+    pub cpu_usage: f64,
+    pub memory_current: Option<u64>,
+    /// None when unlimited
+    pub memory_max: Option<u64>,
+    pub memory_events: Option<CgroupMemoryEvents>,
+    pub io: Vec<CgroupIoStat>,
+    /// Sums of `io` over all devices
+    pub io_read_bytes: u64,
+    pub io_write_bytes: u64,
+    pub pids_current: Option<u64>,
+    pub cpu_pressure: Option<Pressure>,
+    pub memory_pressure: Option<Pressure>,
+    pub io_pressure: Option<Pressure>,
+}
+
 // System information structure
 #[derive(Debug, Clone)]
 pub struct SystemInfo {
@@ -99,20 +934,208 @@ pub fn get_processes() -> Vec<ProcessInfo> {
     get_processes_impl()
 }
 
+/// Get capabilities, seccomp mode and LSM label of a process
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_security_context(pid: u32) -> Result<SecurityContext, ProcessControlError> {
+    get_security_context_impl(pid)
+}
+
+/// Choose how `ProcessInfo.cpu_usage` is normalised on subsequent calls
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_cpu_usage_mode(mode: CpuUsageMode) {
+    set_cpu_usage_mode_impl(mode)
+}
+
+/// Choose what `ProcessInfo.memory_usage` reports on subsequent calls. Pss and Uss
+/// read smaps_rollup for every process, which costs noticeably more than Rss; each
+/// process is re-read at most every 5 seconds.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_memory_metric(metric: MemoryMetric) {
+    set_memory_metric_impl(metric)
+}
+
+/// Full memory breakdown of one process, read fresh on every call (Linux only).
+/// Reading another user's process needs root.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_process_memory(pid: u32) -> Result<ProcessMemory, ProcessControlError> {
+    get_process_memory_impl(pid)
+}
+
+/// Memory mappings of a process with per-region and per-file usage, like `pmap -X`
+/// (Linux only). Reads /proc/<pid>/smaps, so call it for one process at a time.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_memory_maps(pid: u32) -> Result<MemoryMaps, ProcessControlError> {
+    get_memory_maps_impl(pid)
+}
+
+/// Executable, working and root directory, argv and environment of a process. Fails
+/// only if the process does not exist; fields that cannot be read (usually the
+/// environment of another user's process) are reported in `errors`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_process_details(pid: u32) -> Result<ProcessDetails, ProcessControlError> {
+    get_process_details_impl(pid)
+}
+
 /// Get system resource usage
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_system_resources() -> SystemResourceInfo {
     get_system_resources_impl()
 }
 
+/// Get mounted filesystems, filtered by `filter`.
+/// `get_system_resources` uses the default filter.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_disks(filter: MountFilter) -> Vec<DiskInfo> {
+    get_disks_impl(filter)
+}
+
+/// Get per-device disk throughput, IOPS, latency and utilization
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_disk_activity() -> Vec<DiskActivity> {
+    get_disk_activity_impl()
+}
+
+/// Get per-interface network statistics
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_network_interfaces() -> Vec<NetworkInterfaceInfo> {
+    get_network_interfaces_impl()
+}
+
+/// Get every cgroup with its CPU, memory, I/O and pressure accounting (Linux only)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_cgroup_tree() -> Vec<CgroupInfo> {
+    get_cgroup_tree_impl()
+}
+
 /// Get system information
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_system_info() -> SystemInfo {
     get_system_info_impl()
 }
 
+/// Get all TCP/UDP sockets with their owning processes
+pub fn get_connections() -> Vec<ConnectionInfo> {
+    get_connections_impl()
+}
+
+/// Name of the user this app runs as, in the form used by `ProcessInfo.user`
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_current_user() -> String {
+    get_current_user_impl()
+}
+
+/// Get per-process TCP traffic, for finding what is saturating the link
+pub fn get_process_network_usage() -> Vec<ProcessNetworkUsage> {
+    get_process_network_usage_impl()
+}
+
 /// Kill a process by PID
 #[flutter_rust_bridge::frb(sync)]
-pub fn kill_process(pid: u32) -> bool {
+pub fn kill_process(pid: u32) -> Result<(), ProcessControlError> {
     kill_process_impl(pid)
 }
+
+/// Send a signal to a process
+#[flutter_rust_bridge::frb(sync)]
+pub fn send_signal(pid: u32, signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    send_signal_impl(pid, signal)
+}
+
+/// Kill a process and all of its descendants. The tree is stopped first so nothing
+/// can fork while it is collected, and re-scanned until no new children show up.
+/// Fails only if the root itself cannot be targeted; per-process results are reported.
+pub fn kill_process_tree(root_pid: u32, strategy: KillTreeStrategy) -> Result<Vec<ProcessKillResult>, ProcessControlError> {
+    kill_process_tree_impl(root_pid, strategy)
+}
+
+/// Scheduling and I/O priority of a process
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_priority(pid: u32) -> Result<ProcessPriority, ProcessControlError> {
+    get_priority_impl(pid)
+}
+
+/// Change the scheduling priority of a process (all of its threads on Linux).
+/// Raising it above Normal usually needs root / administrator rights.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_priority(pid: u32, level: PriorityLevel) -> Result<(), ProcessControlError> {
+    set_priority_impl(pid, level)
+}
+
+/// Change the I/O priority of a process. Linux only; the Realtime class needs root.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_io_priority(pid: u32, priority: IoPriority) -> Result<(), ProcessControlError> {
+    set_io_priority_impl(pid, priority)
+}
+
+/// Threads of a process. CPU usage is the delta since the previous call for the same
+/// process, so poll this like `get_processes`. Not available on macOS.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_threads(pid: u32) -> Result<Vec<ThreadInfo>, ProcessControlError> {
+    get_threads_impl(pid)
+}
+
+/// Open file descriptors of a process, like `lsof -p` (Linux only). Reading another
+/// user's descriptors needs root.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_open_files(pid: u32) -> Result<Vec<OpenFileInfo>, ProcessControlError> {
+    get_open_files_impl(pid)
+}
+
+/// Processes using a file, directory or mount point through open descriptors, their
+/// working or root directory, executable or memory mappings, like `fuser -v` (Linux only).
+/// A directory matches everything below it; a mount point everything on that filesystem.
+pub fn find_processes_using(path: String) -> Result<Vec<PathUsage>, ProcessControlError> {
+    find_processes_using_impl(path)
+}
+
+/// CPU ids a process may run on. On Linux `pid` may also be a thread id.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_cpu_affinity(pid: u32) -> Result<Vec<u32>, ProcessControlError> {
+    get_cpu_affinity_impl(pid)
+}
+
+/// Restrict a process to the given CPU ids. With `all_threads` every existing
+/// thread is pinned too; otherwise only the thread `pid` (and threads it creates later).
+/// Windows always applies the mask to the whole process and supports CPUs 0-63.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_cpu_affinity(pid: u32, cpus: Vec<u32>, all_threads: bool) -> Result<(), ProcessControlError> {
+    set_cpu_affinity_impl(pid, cpus, all_threads)
+}
+
+/// Scheduling policy of a process or thread (Linux only)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_scheduler(pid: u32) -> Result<SchedulerInfo, ProcessControlError> {
+    get_scheduler_impl(pid)
+}
+
+/// Change the scheduling policy (Linux only). Real-time policies need root or CAP_SYS_NICE.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_scheduler(pid: u32, scheduler: SchedulerInfo, all_threads: bool) -> Result<(), ProcessControlError> {
+    set_scheduler_impl(pid, scheduler, all_threads)
+}
+
+/// Affinity and scheduling policy of every thread of a process (Linux only)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_thread_scheduling(pid: u32) -> Result<Vec<ThreadScheduling>, ProcessControlError> {
+    get_thread_scheduling_impl(pid)
+}
+
+/// Pause a process, and with `include_children` all of its descendants. A process
+/// (tree) that has a cgroup v2 of its own is frozen through cgroup.freeze, so children
+/// forked meanwhile cannot escape; otherwise SIGSTOP is used.
+#[flutter_rust_bridge::frb(sync)]
+pub fn suspend_process(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    suspend_process_impl(pid, include_children)
+}
+
+/// Undo `suspend_process`
+#[flutter_rust_bridge::frb(sync)]
+pub fn resume_process(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    resume_process_impl(pid, include_children)
+}
+
+/// Ask a process to exit (SIGTERM / WM_CLOSE), wait up to `timeout_ms` for it to
+/// do so, then kill it. Blocks while waiting, so it is not a sync call.
+pub fn terminate_gracefully(pid: u32, timeout_ms: u64) -> Result<TerminationOutcome, ProcessControlError> {
+    terminate_gracefully_impl(pid, timeout_ms)
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1670184670;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1424805137;
 
 // Section: executor
 
@@ -45,6 +45,205 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__simple__capability_set_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "capability_set_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CapabilitySet::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__cgroup_cpu_stat_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cgroup_cpu_stat_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CgroupCpuStat::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__cgroup_io_stat_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cgroup_io_stat_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CgroupIoStat::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__cgroup_memory_events_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cgroup_memory_events_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CgroupMemoryEvents::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__cpu_times_breakdown_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cpu_times_breakdown_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CpuTimesBreakdown::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__find_processes_using_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_processes_using",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                    let output_ok = crate::api::simple::find_processes_using(api_path)?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__get_backend_version_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -74,14 +273,14 @@ fn wire__crate__api__simple__get_backend_version_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_processes_impl(
+fn wire__crate__api__simple__get_cgroup_tree_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_processes",
+            debug_name: "get_cgroup_tree",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -97,20 +296,52 @@ fn wire__crate__api__simple__get_processes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_processes())?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_cgroup_tree())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__get_system_info_impl(
+fn wire__crate__api__simple__get_connections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_connections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_connections())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_cpu_affinity_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_system_info",
+            debug_name: "get_cpu_affinity",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -124,22 +355,23 @@ fn wire__crate__api__simple__get_system_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_system_info())?;
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_cpu_affinity(api_pid)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__get_system_resources_impl(
+fn wire__crate__api__simple__get_current_user_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_system_resources",
+            debug_name: "get_current_user",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -155,20 +387,20 @@ fn wire__crate__api__simple__get_system_resources_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_system_resources())?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_current_user())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__simple__get_disk_activity_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "get_disk_activity",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -182,26 +414,24 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_disk_activity())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__get_disks_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_disks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -213,26 +443,23 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter = <crate::api::simple::MountFilter>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_disks(api_filter))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__simple__kill_process_impl(
+fn wire__crate__api__simple__get_memory_maps_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "kill_process",
+            debug_name: "get_memory_maps",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -248,508 +475,5568 @@ fn wire__crate__api__simple__kill_process_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pid = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::kill_process(api_pid))?;
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_memory_maps(api_pid)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+fn wire__crate__api__simple__get_network_interfaces_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_network_interfaces",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_network_interfaces())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_open_files_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_open_files",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_open_files(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_priority_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_priority",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_priority(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_process_details_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_details",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_process_details(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_process_memory_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_memory",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_process_memory(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_process_network_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_network_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::get_process_network_usage())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_processes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_processes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_processes())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_scheduler(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_security_context_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_security_context",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_security_context(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_system_info_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_system_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_system_info())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_system_resources_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_system_resources",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_system_resources())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_thread_scheduling_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_thread_scheduling",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_thread_scheduling(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_threads_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_threads",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::get_threads(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__io_priority_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "io_priority_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::IoPriority::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__kill_process_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kill_process",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::kill_process(api_pid)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__kill_process_tree_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kill_process_tree",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_pid = <u32>::sse_decode(&mut deserializer);
+            let api_strategy =
+                <crate::api::simple::KillTreeStrategy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                    let output_ok =
+                        crate::api::simple::kill_process_tree(api_root_pid, api_strategy)?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__mapped_file_summary_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mapped_file_summary_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::MappedFileSummary::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__mount_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mount_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::MountFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__pressure_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pressure_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::Pressure::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__pressure_line_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pressure_line_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::PressureLine::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__process_credentials_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_credentials_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::ProcessCredentials::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__process_disk_io_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_disk_io_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::ProcessDiskIo::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__process_memory_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_memory_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::ProcessMemory::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__resume_process_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_process",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_include_children = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::resume_process(api_pid, api_include_children)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__security_context_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "security_context_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::SecurityContext::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_signal_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_signal",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_signal = <crate::api::simple::ProcessSignal>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::send_signal(api_pid, api_signal)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_cpu_affinity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cpu_affinity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_cpus = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_all_threads = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok =
+                    crate::api::simple::set_cpu_affinity(api_pid, api_cpus, api_all_threads)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_cpu_usage_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cpu_usage_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode = <crate::api::simple::CpuUsageMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::simple::set_cpu_usage_mode(api_mode);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_io_priority_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_io_priority",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_priority = <crate::api::simple::IoPriority>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::set_io_priority(api_pid, api_priority)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_memory_metric_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_metric",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_metric = <crate::api::simple::MemoryMetric>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::simple::set_memory_metric(api_metric);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_priority_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_priority",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_level = <crate::api::simple::PriorityLevel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::set_priority(api_pid, api_level)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_scheduler = <crate::api::simple::SchedulerInfo>::sse_decode(&mut deserializer);
+            let api_all_threads = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok =
+                    crate::api::simple::set_scheduler(api_pid, api_scheduler, api_all_threads)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__suspend_process_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suspend_process",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_include_children = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                let output_ok = crate::api::simple::suspend_process(api_pid, api_include_children)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__terminate_gracefully_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "terminate_gracefully",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::simple::ProcessControlError>((move || {
+                    let output_ok =
+                        crate::api::simple::terminate_gracefully(api_pid, api_timeout_ms)?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::simple::CapabilitySet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mask = <u64>::sse_decode(deserializer);
+        let mut var_names = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::CapabilitySet {
+            mask: var_mask,
+            names: var_names,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CgroupCpuStat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_usageUsec = <u64>::sse_decode(deserializer);
+        let mut var_userUsec = <u64>::sse_decode(deserializer);
+        let mut var_systemUsec = <u64>::sse_decode(deserializer);
+        let mut var_nrPeriods = <u64>::sse_decode(deserializer);
+        let mut var_nrThrottled = <u64>::sse_decode(deserializer);
+        let mut var_throttledUsec = <u64>::sse_decode(deserializer);
+        return crate::api::simple::CgroupCpuStat {
+            usage_usec: var_usageUsec,
+            user_usec: var_userUsec,
+            system_usec: var_systemUsec,
+            nr_periods: var_nrPeriods,
+            nr_throttled: var_nrThrottled,
+            throttled_usec: var_throttledUsec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CgroupInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parent = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::simple::CgroupKind>::sse_decode(deserializer);
+        let mut var_processCount = <u32>::sse_decode(deserializer);
+        let mut var_cpu = <Option<crate::api::simple::CgroupCpuStat>>::sse_decode(deserializer);
+        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
+        let mut var_memoryCurrent = <Option<u64>>::sse_decode(deserializer);
+        let mut var_memoryMax = <Option<u64>>::sse_decode(deserializer);
+        let mut var_memoryEvents =
+            <Option<crate::api::simple::CgroupMemoryEvents>>::sse_decode(deserializer);
+        let mut var_io = <Vec<crate::api::simple::CgroupIoStat>>::sse_decode(deserializer);
+        let mut var_ioReadBytes = <u64>::sse_decode(deserializer);
+        let mut var_ioWriteBytes = <u64>::sse_decode(deserializer);
+        let mut var_pidsCurrent = <Option<u64>>::sse_decode(deserializer);
+        let mut var_cpuPressure = <Option<crate::api::simple::Pressure>>::sse_decode(deserializer);
+        let mut var_memoryPressure =
+            <Option<crate::api::simple::Pressure>>::sse_decode(deserializer);
+        let mut var_ioPressure = <Option<crate::api::simple::Pressure>>::sse_decode(deserializer);
+        return crate::api::simple::CgroupInfo {
+            path: var_path,
+            name: var_name,
+            parent: var_parent,
+            kind: var_kind,
+            process_count: var_processCount,
+            cpu: var_cpu,
+            cpu_usage: var_cpuUsage,
+            memory_current: var_memoryCurrent,
+            memory_max: var_memoryMax,
+            memory_events: var_memoryEvents,
+            io: var_io,
+            io_read_bytes: var_ioReadBytes,
+            io_write_bytes: var_ioWriteBytes,
+            pids_current: var_pidsCurrent,
+            cpu_pressure: var_cpuPressure,
+            memory_pressure: var_memoryPressure,
+            io_pressure: var_ioPressure,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CgroupIoStat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_device = <String>::sse_decode(deserializer);
+        let mut var_readBytes = <u64>::sse_decode(deserializer);
+        let mut var_writeBytes = <u64>::sse_decode(deserializer);
+        let mut var_readIos = <u64>::sse_decode(deserializer);
+        let mut var_writeIos = <u64>::sse_decode(deserializer);
+        let mut var_discardBytes = <u64>::sse_decode(deserializer);
+        let mut var_discardIos = <u64>::sse_decode(deserializer);
+        return crate::api::simple::CgroupIoStat {
+            device: var_device,
+            read_bytes: var_readBytes,
+            write_bytes: var_writeBytes,
+            read_ios: var_readIos,
+            write_ios: var_writeIos,
+            discard_bytes: var_discardBytes,
+            discard_ios: var_discardIos,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CgroupKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::CgroupKind::Root,
+            1 => crate::api::simple::CgroupKind::Slice,
+            2 => crate::api::simple::CgroupKind::Service,
+            3 => crate::api::simple::CgroupKind::Scope,
+            4 => crate::api::simple::CgroupKind::Other,
+            _ => unreachable!("Invalid variant for CgroupKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CgroupMemoryEvents {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_low = <u64>::sse_decode(deserializer);
+        let mut var_high = <u64>::sse_decode(deserializer);
+        let mut var_max = <u64>::sse_decode(deserializer);
+        let mut var_oom = <u64>::sse_decode(deserializer);
+        let mut var_oomKill = <u64>::sse_decode(deserializer);
+        return crate::api::simple::CgroupMemoryEvents {
+            low: var_low,
+            high: var_high,
+            max: var_max,
+            oom: var_oom,
+            oom_kill: var_oomKill,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ConnectionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_protocol = <crate::api::simple::TransportProtocol>::sse_decode(deserializer);
+        let mut var_ipv6 = <bool>::sse_decode(deserializer);
+        let mut var_localAddress = <String>::sse_decode(deserializer);
+        let mut var_localPort = <u16>::sse_decode(deserializer);
+        let mut var_remoteAddress = <String>::sse_decode(deserializer);
+        let mut var_remotePort = <u16>::sse_decode(deserializer);
+        let mut var_state = <crate::api::simple::ConnectionState>::sse_decode(deserializer);
+        let mut var_sendQueue = <u64>::sse_decode(deserializer);
+        let mut var_receiveQueue = <u64>::sse_decode(deserializer);
+        let mut var_pid = <Option<u32>>::sse_decode(deserializer);
+        let mut var_uid = <u32>::sse_decode(deserializer);
+        let mut var_inode = <u64>::sse_decode(deserializer);
+        return crate::api::simple::ConnectionInfo {
+            protocol: var_protocol,
+            ipv6: var_ipv6,
+            local_address: var_localAddress,
+            local_port: var_localPort,
+            remote_address: var_remoteAddress,
+            remote_port: var_remotePort,
+            state: var_state,
+            send_queue: var_sendQueue,
+            receive_queue: var_receiveQueue,
+            pid: var_pid,
+            uid: var_uid,
+            inode: var_inode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ConnectionState::Established,
+            1 => crate::api::simple::ConnectionState::SynSent,
+            2 => crate::api::simple::ConnectionState::SynReceived,
+            3 => crate::api::simple::ConnectionState::FinWait1,
+            4 => crate::api::simple::ConnectionState::FinWait2,
+            5 => crate::api::simple::ConnectionState::TimeWait,
+            6 => crate::api::simple::ConnectionState::Closed,
+            7 => crate::api::simple::ConnectionState::CloseWait,
+            8 => crate::api::simple::ConnectionState::LastAck,
+            9 => crate::api::simple::ConnectionState::Listen,
+            10 => crate::api::simple::ConnectionState::Closing,
+            11 => crate::api::simple::ConnectionState::Unconnected,
+            12 => crate::api::simple::ConnectionState::Unknown,
+            _ => unreachable!("Invalid variant for ConnectionState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CpuTimesBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_user = <f64>::sse_decode(deserializer);
+        let mut var_nice = <f64>::sse_decode(deserializer);
+        let mut var_system = <f64>::sse_decode(deserializer);
+        let mut var_idle = <f64>::sse_decode(deserializer);
+        let mut var_iowait = <f64>::sse_decode(deserializer);
+        let mut var_irq = <f64>::sse_decode(deserializer);
+        let mut var_softirq = <f64>::sse_decode(deserializer);
+        let mut var_steal = <f64>::sse_decode(deserializer);
+        let mut var_guest = <f64>::sse_decode(deserializer);
+        let mut var_guestNice = <f64>::sse_decode(deserializer);
+        return crate::api::simple::CpuTimesBreakdown {
+            user: var_user,
+            nice: var_nice,
+            system: var_system,
+            idle: var_idle,
+            iowait: var_iowait,
+            irq: var_irq,
+            softirq: var_softirq,
+            steal: var_steal,
+            guest: var_guest,
+            guest_nice: var_guestNice,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CpuUsageMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::CpuUsageMode::Machine,
+            1 => crate::api::simple::CpuUsageMode::SingleCore,
+            _ => unreachable!("Invalid variant for CpuUsageMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::DiskActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parent = <Option<String>>::sse_decode(deserializer);
+        let mut var_readBytes = <u64>::sse_decode(deserializer);
+        let mut var_writeBytes = <u64>::sse_decode(deserializer);
+        let mut var_readBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_writeBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_readIops = <f64>::sse_decode(deserializer);
+        let mut var_writeIops = <f64>::sse_decode(deserializer);
+        let mut var_avgWaitMs = <f64>::sse_decode(deserializer);
+        let mut var_utilization = <f64>::sse_decode(deserializer);
+        return crate::api::simple::DiskActivity {
+            name: var_name,
+            parent: var_parent,
+            read_bytes: var_readBytes,
+            write_bytes: var_writeBytes,
+            read_bytes_per_sec: var_readBytesPerSec,
+            write_bytes_per_sec: var_writeBytesPerSec,
+            read_iops: var_readIops,
+            write_iops: var_writeIops,
+            avg_wait_ms: var_avgWaitMs,
+            utilization: var_utilization,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::DiskInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_mountPoint = <String>::sse_decode(deserializer);
+        let mut var_totalSpace = <u64>::sse_decode(deserializer);
+        let mut var_usedSpace = <u64>::sse_decode(deserializer);
+        let mut var_availableSpace = <u64>::sse_decode(deserializer);
+        let mut var_fsType = <String>::sse_decode(deserializer);
+        let mut var_device = <String>::sse_decode(deserializer);
+        let mut var_mountOptions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_readOnly = <bool>::sse_decode(deserializer);
+        let mut var_inodesTotal = <u64>::sse_decode(deserializer);
+        let mut var_inodesUsed = <u64>::sse_decode(deserializer);
+        let mut var_inodesFree = <u64>::sse_decode(deserializer);
+        return crate::api::simple::DiskInfo {
+            name: var_name,
+            mount_point: var_mountPoint,
+            total_space: var_totalSpace,
+            used_space: var_usedSpace,
+            available_space: var_availableSpace,
+            fs_type: var_fsType,
+            device: var_device,
+            mount_options: var_mountOptions,
+            read_only: var_readOnly,
+            inodes_total: var_inodesTotal,
+            inodes_used: var_inodesUsed,
+            inodes_free: var_inodesFree,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::EnvironmentVariable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::simple::EnvironmentVariable {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::FileDescriptorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::FileDescriptorKind::File,
+            1 => crate::api::simple::FileDescriptorKind::Directory,
+            2 => crate::api::simple::FileDescriptorKind::Device,
+            3 => crate::api::simple::FileDescriptorKind::Socket,
+            4 => crate::api::simple::FileDescriptorKind::Pipe,
+            5 => crate::api::simple::FileDescriptorKind::AnonInode,
+            6 => crate::api::simple::FileDescriptorKind::Other,
+            _ => unreachable!("Invalid variant for FileDescriptorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::IoPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_class_ = <crate::api::simple::IoPriorityClass>::sse_decode(deserializer);
+        let mut var_level = <u8>::sse_decode(deserializer);
+        return crate::api::simple::IoPriority {
+            class: var_class_,
+            level: var_level,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::IoPriorityClass {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::IoPriorityClass::None,
+            1 => crate::api::simple::IoPriorityClass::Realtime,
+            2 => crate::api::simple::IoPriorityClass::BestEffort,
+            3 => crate::api::simple::IoPriorityClass::Idle,
+            _ => unreachable!("Invalid variant for IoPriorityClass: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::KillOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::KillOutcome::Killed,
+            1 => crate::api::simple::KillOutcome::AlreadyExited,
+            2 => crate::api::simple::KillOutcome::Failed,
+            _ => unreachable!("Invalid variant for KillOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::KillTreeStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::KillTreeStrategy::LeavesFirst,
+            1 => crate::api::simple::KillTreeStrategy::ProcessGroup,
+            _ => unreachable!("Invalid variant for KillTreeStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::CgroupInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::CgroupInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::CgroupIoStat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::CgroupIoStat>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ConnectionInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::CpuTimesBreakdown> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::CpuTimesBreakdown>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::DiskActivity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::DiskActivity>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::DiskInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::DiskInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::EnvironmentVariable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::EnvironmentVariable>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::MappedFileSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::MappedFileSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::MemoryRegion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::MemoryRegion>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::NetworkInterfaceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::NetworkInterfaceInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::OpenFileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::OpenFileInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::PathUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::PathUsage>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ProcessDetailError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ProcessDetailError>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ProcessInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ProcessInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ProcessKillResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ProcessKillResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ProcessNetworkUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ProcessNetworkUsage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ThreadInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ThreadInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ThreadScheduling> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ThreadScheduling>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::simple::MappedFileSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_regions = <u32>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_rss = <u64>::sse_decode(deserializer);
+        let mut var_pss = <u64>::sse_decode(deserializer);
+        let mut var_private = <u64>::sse_decode(deserializer);
+        let mut var_swap = <u64>::sse_decode(deserializer);
+        return crate::api::simple::MappedFileSummary {
+            path: var_path,
+            regions: var_regions,
+            size: var_size,
+            rss: var_rss,
+            pss: var_pss,
+            private: var_private,
+            swap: var_swap,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MemoryMaps {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_regions = <Vec<crate::api::simple::MemoryRegion>>::sse_decode(deserializer);
+        let mut var_files = <Vec<crate::api::simple::MappedFileSummary>>::sse_decode(deserializer);
+        return crate::api::simple::MemoryMaps {
+            regions: var_regions,
+            files: var_files,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MemoryMetric {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::MemoryMetric::Rss,
+            1 => crate::api::simple::MemoryMetric::Pss,
+            2 => crate::api::simple::MemoryMetric::Uss,
+            _ => unreachable!("Invalid variant for MemoryMetric: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MemoryRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u64>::sse_decode(deserializer);
+        let mut var_end = <u64>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_permissions = <String>::sse_decode(deserializer);
+        let mut var_offset = <u64>::sse_decode(deserializer);
+        let mut var_device = <String>::sse_decode(deserializer);
+        let mut var_inode = <u64>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_deleted = <bool>::sse_decode(deserializer);
+        let mut var_rss = <u64>::sse_decode(deserializer);
+        let mut var_pss = <u64>::sse_decode(deserializer);
+        let mut var_private = <u64>::sse_decode(deserializer);
+        let mut var_swap = <u64>::sse_decode(deserializer);
+        return crate::api::simple::MemoryRegion {
+            start: var_start,
+            end: var_end,
+            size: var_size,
+            permissions: var_permissions,
+            offset: var_offset,
+            device: var_device,
+            inode: var_inode,
+            path: var_path,
+            deleted: var_deleted,
+            rss: var_rss,
+            pss: var_pss,
+            private: var_private,
+            swap: var_swap,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MountFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_includePseudo = <bool>::sse_decode(deserializer);
+        let mut var_collapseBindMounts = <bool>::sse_decode(deserializer);
+        return crate::api::simple::MountFilter {
+            include_pseudo: var_includePseudo,
+            collapse_bind_mounts: var_collapseBindMounts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NetworkAttribution {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::NetworkAttribution::Socket,
+            1 => crate::api::simple::NetworkAttribution::Namespace,
+            _ => unreachable!("Invalid variant for NetworkAttribution: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NetworkInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_packetsSent = <u64>::sse_decode(deserializer);
+        let mut var_packetsReceived = <u64>::sse_decode(deserializer);
+        return crate::api::simple::NetworkInfo {
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            packets_sent: var_packetsSent,
+            packets_received: var_packetsReceived,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NetworkInterfaceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_isLoopback = <bool>::sse_decode(deserializer);
+        let mut var_isVirtual = <bool>::sse_decode(deserializer);
+        let mut var_operstate = <String>::sse_decode(deserializer);
+        let mut var_linkSpeedMbps = <Option<u64>>::sse_decode(deserializer);
+        let mut var_mtu = <u32>::sse_decode(deserializer);
+        let mut var_macAddress = <String>::sse_decode(deserializer);
+        let mut var_ipv4Addresses = <Vec<String>>::sse_decode(deserializer);
+        let mut var_ipv6Addresses = <Vec<String>>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_packetsReceived = <u64>::sse_decode(deserializer);
+        let mut var_packetsSent = <u64>::sse_decode(deserializer);
+        let mut var_receiveErrors = <u64>::sse_decode(deserializer);
+        let mut var_sendErrors = <u64>::sse_decode(deserializer);
+        let mut var_receiveDropped = <u64>::sse_decode(deserializer);
+        let mut var_sendDropped = <u64>::sse_decode(deserializer);
+        let mut var_receiveBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_sendBytesPerSec = <f64>::sse_decode(deserializer);
+        return crate::api::simple::NetworkInterfaceInfo {
+            name: var_name,
+            kind: var_kind,
+            is_loopback: var_isLoopback,
+            is_virtual: var_isVirtual,
+            operstate: var_operstate,
+            link_speed_mbps: var_linkSpeedMbps,
+            mtu: var_mtu,
+            mac_address: var_macAddress,
+            ipv4_addresses: var_ipv4Addresses,
+            ipv6_addresses: var_ipv6Addresses,
+            bytes_received: var_bytesReceived,
+            bytes_sent: var_bytesSent,
+            packets_received: var_packetsReceived,
+            packets_sent: var_packetsSent,
+            receive_errors: var_receiveErrors,
+            send_errors: var_sendErrors,
+            receive_dropped: var_receiveDropped,
+            send_dropped: var_sendDropped,
+            receive_bytes_per_sec: var_receiveBytesPerSec,
+            send_bytes_per_sec: var_sendBytesPerSec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::OpenFileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fd = <u32>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::simple::FileDescriptorKind>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_flags = <u32>::sse_decode(deserializer);
+        let mut var_readable = <bool>::sse_decode(deserializer);
+        let mut var_writable = <bool>::sse_decode(deserializer);
+        let mut var_position = <u64>::sse_decode(deserializer);
+        let mut var_deleted = <bool>::sse_decode(deserializer);
+        let mut var_inode = <u64>::sse_decode(deserializer);
+        return crate::api::simple::OpenFileInfo {
+            fd: var_fd,
+            kind: var_kind,
+            path: var_path,
+            flags: var_flags,
+            readable: var_readable,
+            writable: var_writable,
+            position: var_position,
+            deleted: var_deleted,
+            inode: var_inode,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::CgroupCpuStat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::CgroupCpuStat>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::CgroupMemoryEvents> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::CgroupMemoryEvents>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::Pressure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::Pressure>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::PressureLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::PressureLine>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::ProcessControlError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::ProcessControlError>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::ProcessCredentials> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::ProcessCredentials>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::ProcessDiskIo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::ProcessDiskIo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::SeccompMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::SeccompMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::simple::EnvironmentVariable>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::simple::EnvironmentVariable>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::PathUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::simple::PathUsageKind>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_fd = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::simple::PathUsage {
+            pid: var_pid,
+            name: var_name,
+            kind: var_kind,
+            path: var_path,
+            fd: var_fd,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::PathUsageKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::PathUsageKind::OpenFile,
+            1 => crate::api::simple::PathUsageKind::WorkingDirectory,
+            2 => crate::api::simple::PathUsageKind::RootDirectory,
+            3 => crate::api::simple::PathUsageKind::Executable,
+            4 => crate::api::simple::PathUsageKind::MemoryMapped,
+            _ => unreachable!("Invalid variant for PathUsageKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::Pressure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_some = <crate::api::simple::PressureLine>::sse_decode(deserializer);
+        let mut var_full = <Option<crate::api::simple::PressureLine>>::sse_decode(deserializer);
+        return crate::api::simple::Pressure {
+            some: var_some,
+            full: var_full,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::PressureLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_avg10 = <f64>::sse_decode(deserializer);
+        let mut var_avg60 = <f64>::sse_decode(deserializer);
+        let mut var_avg300 = <f64>::sse_decode(deserializer);
+        let mut var_totalUsec = <u64>::sse_decode(deserializer);
+        return crate::api::simple::PressureLine {
+            avg10: var_avg10,
+            avg60: var_avg60,
+            avg300: var_avg300,
+            total_usec: var_totalUsec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::PriorityLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::PriorityLevel::Idle,
+            1 => crate::api::simple::PriorityLevel::BelowNormal,
+            2 => crate::api::simple::PriorityLevel::Normal,
+            3 => crate::api::simple::PriorityLevel::AboveNormal,
+            4 => crate::api::simple::PriorityLevel::High,
+            _ => unreachable!("Invalid variant for PriorityLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessControlError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::simple::ProcessControlErrorKind>::sse_decode(deserializer);
+        let mut var_code = <i32>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::simple::ProcessControlError {
+            kind: var_kind,
+            code: var_code,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessControlErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ProcessControlErrorKind::PermissionDenied,
+            1 => crate::api::simple::ProcessControlErrorKind::NoSuchProcess,
+            2 => crate::api::simple::ProcessControlErrorKind::ProtectedProcess,
+            3 => crate::api::simple::ProcessControlErrorKind::InvalidArgument,
+            4 => crate::api::simple::ProcessControlErrorKind::Unsupported,
+            5 => crate::api::simple::ProcessControlErrorKind::TimedOut,
+            6 => crate::api::simple::ProcessControlErrorKind::Other,
+            _ => unreachable!("Invalid variant for ProcessControlErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_realUid = <u32>::sse_decode(deserializer);
+        let mut var_effectiveUid = <u32>::sse_decode(deserializer);
+        let mut var_savedUid = <u32>::sse_decode(deserializer);
+        let mut var_realGid = <u32>::sse_decode(deserializer);
+        let mut var_effectiveGid = <u32>::sse_decode(deserializer);
+        let mut var_savedGid = <u32>::sse_decode(deserializer);
+        let mut var_supplementaryGroups = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_realUser = <String>::sse_decode(deserializer);
+        let mut var_effectiveUser = <String>::sse_decode(deserializer);
+        let mut var_group = <String>::sse_decode(deserializer);
+        let mut var_supplementaryGroupNames = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::ProcessCredentials {
+            real_uid: var_realUid,
+            effective_uid: var_effectiveUid,
+            saved_uid: var_savedUid,
+            real_gid: var_realGid,
+            effective_gid: var_effectiveGid,
+            saved_gid: var_savedGid,
+            supplementary_groups: var_supplementaryGroups,
+            real_user: var_realUser,
+            effective_user: var_effectiveUser,
+            group: var_group,
+            supplementary_group_names: var_supplementaryGroupNames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessDetailError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::api::simple::ProcessDetailField>::sse_decode(deserializer);
+        let mut var_error = <crate::api::simple::ProcessControlError>::sse_decode(deserializer);
+        return crate::api::simple::ProcessDetailError {
+            field: var_field,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessDetailField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ProcessDetailField::Executable,
+            1 => crate::api::simple::ProcessDetailField::WorkingDirectory,
+            2 => crate::api::simple::ProcessDetailField::RootDirectory,
+            3 => crate::api::simple::ProcessDetailField::CommandLine,
+            4 => crate::api::simple::ProcessDetailField::Environment,
+            _ => unreachable!("Invalid variant for ProcessDetailField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_exe = <Option<String>>::sse_decode(deserializer);
+        let mut var_exeDeleted = <bool>::sse_decode(deserializer);
+        let mut var_cwd = <Option<String>>::sse_decode(deserializer);
+        let mut var_root = <Option<String>>::sse_decode(deserializer);
+        let mut var_argv = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_environment =
+            <Option<Vec<crate::api::simple::EnvironmentVariable>>>::sse_decode(deserializer);
+        let mut var_errors =
+            <Vec<crate::api::simple::ProcessDetailError>>::sse_decode(deserializer);
+        return crate::api::simple::ProcessDetails {
+            pid: var_pid,
+            exe: var_exe,
+            exe_deleted: var_exeDeleted,
+            cwd: var_cwd,
+            root: var_root,
+            argv: var_argv,
+            environment: var_environment,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessDiskIo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_readBytes = <u64>::sse_decode(deserializer);
+        let mut var_writeBytes = <u64>::sse_decode(deserializer);
+        let mut var_cancelledWriteBytes = <u64>::sse_decode(deserializer);
+        let mut var_readChars = <u64>::sse_decode(deserializer);
+        let mut var_writeChars = <u64>::sse_decode(deserializer);
+        let mut var_readSyscalls = <u64>::sse_decode(deserializer);
+        let mut var_writeSyscalls = <u64>::sse_decode(deserializer);
+        let mut var_readBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_writeBytesPerSec = <f64>::sse_decode(deserializer);
+        return crate::api::simple::ProcessDiskIo {
+            read_bytes: var_readBytes,
+            write_bytes: var_writeBytes,
+            cancelled_write_bytes: var_cancelledWriteBytes,
+            read_chars: var_readChars,
+            write_chars: var_writeChars,
+            read_syscalls: var_readSyscalls,
+            write_syscalls: var_writeSyscalls,
+            read_bytes_per_sec: var_readBytesPerSec,
+            write_bytes_per_sec: var_writeBytesPerSec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
+        let mut var_memoryUsage = <u64>::sse_decode(deserializer);
+        let mut var_memoryMetric = <crate::api::simple::MemoryMetric>::sse_decode(deserializer);
+        let mut var_parentPid = <Option<u32>>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::api::simple::ProcessState>::sse_decode(deserializer);
+        let mut var_command = <String>::sse_decode(deserializer);
+        let mut var_startTime = <u64>::sse_decode(deserializer);
+        let mut var_age = <u64>::sse_decode(deserializer);
+        let mut var_priority = <crate::api::simple::PriorityLevel>::sse_decode(deserializer);
+        let mut var_nice = <i32>::sse_decode(deserializer);
+        let mut var_ioPriority = <crate::api::simple::IoPriority>::sse_decode(deserializer);
+        let mut var_lastCpu = <Option<u32>>::sse_decode(deserializer);
+        let mut var_threadCount = <u32>::sse_decode(deserializer);
+        let mut var_diskIo = <Option<crate::api::simple::ProcessDiskIo>>::sse_decode(deserializer);
+        let mut var_user = <Option<String>>::sse_decode(deserializer);
+        let mut var_credentials =
+            <Option<crate::api::simple::ProcessCredentials>>::sse_decode(deserializer);
+        let mut var_privileged = <bool>::sse_decode(deserializer);
+        let mut var_cgroup = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::ProcessInfo {
+            pid: var_pid,
+            name: var_name,
+            cpu_usage: var_cpuUsage,
+            memory_usage: var_memoryUsage,
+            memory_metric: var_memoryMetric,
+            parent_pid: var_parentPid,
+            status: var_status,
+            state: var_state,
+            command: var_command,
+            start_time: var_startTime,
+            age: var_age,
+            priority: var_priority,
+            nice: var_nice,
+            io_priority: var_ioPriority,
+            last_cpu: var_lastCpu,
+            thread_count: var_threadCount,
+            disk_io: var_diskIo,
+            user: var_user,
+            credentials: var_credentials,
+            privileged: var_privileged,
+            cgroup: var_cgroup,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessKillResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_parentPid = <Option<u32>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_outcome = <crate::api::simple::KillOutcome>::sse_decode(deserializer);
+        let mut var_error =
+            <Option<crate::api::simple::ProcessControlError>>::sse_decode(deserializer);
+        return crate::api::simple::ProcessKillResult {
+            pid: var_pid,
+            parent_pid: var_parentPid,
+            name: var_name,
+            outcome: var_outcome,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessMemory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rss = <u64>::sse_decode(deserializer);
+        let mut var_pss = <u64>::sse_decode(deserializer);
+        let mut var_uss = <u64>::sse_decode(deserializer);
+        let mut var_sharedClean = <u64>::sse_decode(deserializer);
+        let mut var_sharedDirty = <u64>::sse_decode(deserializer);
+        let mut var_privateClean = <u64>::sse_decode(deserializer);
+        let mut var_privateDirty = <u64>::sse_decode(deserializer);
+        let mut var_swap = <u64>::sse_decode(deserializer);
+        let mut var_swapPss = <u64>::sse_decode(deserializer);
+        let mut var_locked = <u64>::sse_decode(deserializer);
+        let mut var_hugePages = <u64>::sse_decode(deserializer);
+        return crate::api::simple::ProcessMemory {
+            rss: var_rss,
+            pss: var_pss,
+            uss: var_uss,
+            shared_clean: var_sharedClean,
+            shared_dirty: var_sharedDirty,
+            private_clean: var_privateClean,
+            private_dirty: var_privateDirty,
+            swap: var_swap,
+            swap_pss: var_swapPss,
+            locked: var_locked,
+            huge_pages: var_hugePages,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessNetworkUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_receivedBytes = <u64>::sse_decode(deserializer);
+        let mut var_sentBytes = <u64>::sse_decode(deserializer);
+        let mut var_receiveBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_sendBytesPerSec = <f64>::sse_decode(deserializer);
+        let mut var_connections = <u32>::sse_decode(deserializer);
+        let mut var_source = <crate::api::simple::NetworkAttribution>::sse_decode(deserializer);
+        let mut var_netNamespace = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::simple::ProcessNetworkUsage {
+            pid: var_pid,
+            name: var_name,
+            received_bytes: var_receivedBytes,
+            sent_bytes: var_sentBytes,
+            receive_bytes_per_sec: var_receiveBytesPerSec,
+            send_bytes_per_sec: var_sendBytesPerSec,
+            connections: var_connections,
+            source: var_source,
+            net_namespace: var_netNamespace,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <crate::api::simple::PriorityLevel>::sse_decode(deserializer);
+        let mut var_nice = <i32>::sse_decode(deserializer);
+        let mut var_ioPriority = <crate::api::simple::IoPriority>::sse_decode(deserializer);
+        return crate::api::simple::ProcessPriority {
+            level: var_level,
+            nice: var_nice,
+            io_priority: var_ioPriority,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessSignal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ProcessSignal::Hangup,
+            1 => crate::api::simple::ProcessSignal::Interrupt,
+            2 => crate::api::simple::ProcessSignal::Quit,
+            3 => crate::api::simple::ProcessSignal::Abort,
+            4 => crate::api::simple::ProcessSignal::Kill,
+            5 => crate::api::simple::ProcessSignal::User1,
+            6 => crate::api::simple::ProcessSignal::User2,
+            7 => crate::api::simple::ProcessSignal::Terminate,
+            8 => crate::api::simple::ProcessSignal::Continue,
+            9 => crate::api::simple::ProcessSignal::Stop,
+            10 => crate::api::simple::ProcessSignal::TerminalStop,
+            _ => unreachable!("Invalid variant for ProcessSignal: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ProcessState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ProcessState::Running,
+            1 => crate::api::simple::ProcessState::Sleeping,
+            2 => crate::api::simple::ProcessState::DiskSleep,
+            3 => crate::api::simple::ProcessState::Stopped,
+            4 => crate::api::simple::ProcessState::Suspended,
+            5 => crate::api::simple::ProcessState::Traced,
+            6 => crate::api::simple::ProcessState::Zombie,
+            7 => crate::api::simple::ProcessState::Dead,
+            8 => crate::api::simple::ProcessState::Idle,
+            9 => crate::api::simple::ProcessState::Parked,
+            10 => crate::api::simple::ProcessState::Unknown,
+            _ => unreachable!("Invalid variant for ProcessState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SchedulerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_policy = <crate::api::simple::SchedulerPolicy>::sse_decode(deserializer);
+        let mut var_priority = <u32>::sse_decode(deserializer);
+        return crate::api::simple::SchedulerInfo {
+            policy: var_policy,
+            priority: var_priority,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SchedulerPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::SchedulerPolicy::Other,
+            1 => crate::api::simple::SchedulerPolicy::Batch,
+            2 => crate::api::simple::SchedulerPolicy::Idle,
+            3 => crate::api::simple::SchedulerPolicy::Fifo,
+            4 => crate::api::simple::SchedulerPolicy::RoundRobin,
+            5 => crate::api::simple::SchedulerPolicy::Deadline,
+            6 => crate::api::simple::SchedulerPolicy::Unknown,
+            _ => unreachable!("Invalid variant for SchedulerPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SeccompMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::SeccompMode::Disabled,
+            1 => crate::api::simple::SeccompMode::Strict,
+            2 => crate::api::simple::SeccompMode::Filter,
+            _ => unreachable!("Invalid variant for SeccompMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SecurityContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_effective = <crate::api::simple::CapabilitySet>::sse_decode(deserializer);
+        let mut var_permitted = <crate::api::simple::CapabilitySet>::sse_decode(deserializer);
+        let mut var_inheritable = <crate::api::simple::CapabilitySet>::sse_decode(deserializer);
+        let mut var_bounding = <crate::api::simple::CapabilitySet>::sse_decode(deserializer);
+        let mut var_ambient = <crate::api::simple::CapabilitySet>::sse_decode(deserializer);
+        let mut var_seccomp = <Option<crate::api::simple::SeccompMode>>::sse_decode(deserializer);
+        let mut var_seccompFilters = <Option<u32>>::sse_decode(deserializer);
+        let mut var_noNewPrivs = <Option<bool>>::sse_decode(deserializer);
+        let mut var_lsm = <Option<String>>::sse_decode(deserializer);
+        let mut var_lsmLabel = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::SecurityContext {
+            pid: var_pid,
+            effective: var_effective,
+            permitted: var_permitted,
+            inheritable: var_inheritable,
+            bounding: var_bounding,
+            ambient: var_ambient,
+            seccomp: var_seccomp,
+            seccomp_filters: var_seccompFilters,
+            no_new_privs: var_noNewPrivs,
+            lsm: var_lsm,
+            lsm_label: var_lsmLabel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SystemInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_osName = <String>::sse_decode(deserializer);
+        let mut var_osVersion = <String>::sse_decode(deserializer);
+        let mut var_kernelVersion = <String>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_cpuBrand = <String>::sse_decode(deserializer);
+        let mut var_cpuCores = <u32>::sse_decode(deserializer);
+        let mut var_totalMemory = <u64>::sse_decode(deserializer);
+        let mut var_bootTime = <u64>::sse_decode(deserializer);
+        let mut var_uptime = <u64>::sse_decode(deserializer);
+        return crate::api::simple::SystemInfo {
+            os_name: var_osName,
+            os_version: var_osVersion,
+            kernel_version: var_kernelVersion,
+            hostname: var_hostname,
+            cpu_brand: var_cpuBrand,
+            cpu_cores: var_cpuCores,
+            total_memory: var_totalMemory,
+            boot_time: var_bootTime,
+            uptime: var_uptime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SystemResourceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
+        let mut var_cpuPerCore = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_cpuTimes = <crate::api::simple::CpuTimesBreakdown>::sse_decode(deserializer);
+        let mut var_cpuTimesPerCore =
+            <Vec<crate::api::simple::CpuTimesBreakdown>>::sse_decode(deserializer);
+        let mut var_memoryTotal = <u64>::sse_decode(deserializer);
+        let mut var_memoryUsed = <u64>::sse_decode(deserializer);
+        let mut var_memoryAvailable = <u64>::sse_decode(deserializer);
+        let mut var_swapTotal = <u64>::sse_decode(deserializer);
+        let mut var_swapUsed = <u64>::sse_decode(deserializer);
+        let mut var_swapFree = <u64>::sse_decode(deserializer);
+        let mut var_diskUsage = <Vec<crate::api::simple::DiskInfo>>::sse_decode(deserializer);
+        let mut var_networkUsage = <crate::api::simple::NetworkInfo>::sse_decode(deserializer);
+        return crate::api::simple::SystemResourceInfo {
+            cpu_usage: var_cpuUsage,
+            cpu_per_core: var_cpuPerCore,
+            cpu_times: var_cpuTimes,
+            cpu_times_per_core: var_cpuTimesPerCore,
+            memory_total: var_memoryTotal,
+            memory_used: var_memoryUsed,
+            memory_available: var_memoryAvailable,
+            swap_total: var_swapTotal,
+            swap_used: var_swapUsed,
+            swap_free: var_swapFree,
+            disk_usage: var_diskUsage,
+            network_usage: var_networkUsage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::TerminationOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::TerminationOutcome::Terminated,
+            1 => crate::api::simple::TerminationOutcome::Killed,
+            _ => unreachable!("Invalid variant for TerminationOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ThreadInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::api::simple::ProcessState>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
+        let mut var_userTimeMs = <u64>::sse_decode(deserializer);
+        let mut var_systemTimeMs = <u64>::sse_decode(deserializer);
+        let mut var_lastCpu = <Option<u32>>::sse_decode(deserializer);
+        let mut var_priority = <i32>::sse_decode(deserializer);
+        let mut var_nice = <i32>::sse_decode(deserializer);
+        let mut var_voluntaryContextSwitches = <u64>::sse_decode(deserializer);
+        let mut var_involuntaryContextSwitches = <u64>::sse_decode(deserializer);
+        return crate::api::simple::ThreadInfo {
+            tid: var_tid,
+            name: var_name,
+            state: var_state,
+            status: var_status,
+            cpu_usage: var_cpuUsage,
+            user_time_ms: var_userTimeMs,
+            system_time_ms: var_systemTimeMs,
+            last_cpu: var_lastCpu,
+            priority: var_priority,
+            nice: var_nice,
+            voluntary_context_switches: var_voluntaryContextSwitches,
+            involuntary_context_switches: var_involuntaryContextSwitches,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ThreadScheduling {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_affinity = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_scheduler = <crate::api::simple::SchedulerInfo>::sse_decode(deserializer);
+        let mut var_lastCpu = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::simple::ThreadScheduling {
+            tid: var_tid,
+            name: var_name,
+            affinity: var_affinity,
+            scheduler: var_scheduler,
+            last_cpu: var_lastCpu,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::TransportProtocol {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::TransportProtocol::Tcp,
+            1 => crate::api::simple::TransportProtocol::Udp,
+            _ => unreachable!("Invalid variant for TransportProtocol: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => {
+            wire__crate__api__simple__capability_set_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        2 => wire__crate__api__simple__cgroup_cpu_stat_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => {
+            wire__crate__api__simple__cgroup_io_stat_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        4 => wire__crate__api__simple__cgroup_memory_events_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__simple__cpu_times_breakdown_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__simple__find_processes_using_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__simple__get_connections_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__simple__get_process_network_usage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__simple__io_priority_default_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__kill_process_tree_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__simple__mapped_file_summary_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => {
+            wire__crate__api__simple__mount_filter_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__simple__pressure_default_impl(port, ptr, rust_vec_len, data_len),
+        36 => {
+            wire__crate__api__simple__pressure_line_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        37 => wire__crate__api__simple__process_credentials_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__simple__process_disk_io_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => {
+            wire__crate__api__simple__process_memory_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => wire__crate__api__simple__security_context_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => {
+            wire__crate__api__simple__terminate_gracefully_impl(port, ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        7 => wire__crate__api__simple__get_backend_version_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__simple__get_cgroup_tree_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__simple__get_cpu_affinity_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__simple__get_current_user_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__simple__get_disk_activity_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__simple__get_disks_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__simple__get_memory_maps_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__get_network_interfaces_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__simple__get_open_files_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__simple__get_priority_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__simple__get_process_details_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple__get_process_memory_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__simple__get_processes_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__simple__get_scheduler_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__simple__get_security_context_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__simple__get_system_info_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__simple__get_system_resources_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__simple__get_thread_scheduling_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__simple__get_threads_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__simple__kill_process_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__resume_process_impl(ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__send_signal_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__simple__set_cpu_affinity_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__simple__set_cpu_usage_mode_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__simple__set_io_priority_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__simple__set_memory_metric_impl(ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__simple__set_priority_impl(ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__simple__set_scheduler_impl(ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__simple__suspend_process_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CapabilitySet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mask.into_into_dart().into_dart(),
+            self.names.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CapabilitySet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CapabilitySet>
+    for crate::api::simple::CapabilitySet
+{
+    fn into_into_dart(self) -> crate::api::simple::CapabilitySet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CgroupCpuStat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.usage_usec.into_into_dart().into_dart(),
+            self.user_usec.into_into_dart().into_dart(),
+            self.system_usec.into_into_dart().into_dart(),
+            self.nr_periods.into_into_dart().into_dart(),
+            self.nr_throttled.into_into_dart().into_dart(),
+            self.throttled_usec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CgroupCpuStat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CgroupCpuStat>
+    for crate::api::simple::CgroupCpuStat
+{
+    fn into_into_dart(self) -> crate::api::simple::CgroupCpuStat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CgroupInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.parent.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.process_count.into_into_dart().into_dart(),
+            self.cpu.into_into_dart().into_dart(),
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.memory_current.into_into_dart().into_dart(),
+            self.memory_max.into_into_dart().into_dart(),
+            self.memory_events.into_into_dart().into_dart(),
+            self.io.into_into_dart().into_dart(),
+            self.io_read_bytes.into_into_dart().into_dart(),
+            self.io_write_bytes.into_into_dart().into_dart(),
+            self.pids_current.into_into_dart().into_dart(),
+            self.cpu_pressure.into_into_dart().into_dart(),
+            self.memory_pressure.into_into_dart().into_dart(),
+            self.io_pressure.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CgroupInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CgroupInfo>
+    for crate::api::simple::CgroupInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::CgroupInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CgroupIoStat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device.into_into_dart().into_dart(),
+            self.read_bytes.into_into_dart().into_dart(),
+            self.write_bytes.into_into_dart().into_dart(),
+            self.read_ios.into_into_dart().into_dart(),
+            self.write_ios.into_into_dart().into_dart(),
+            self.discard_bytes.into_into_dart().into_dart(),
+            self.discard_ios.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CgroupIoStat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CgroupIoStat>
+    for crate::api::simple::CgroupIoStat
+{
+    fn into_into_dart(self) -> crate::api::simple::CgroupIoStat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CgroupKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Root => 0.into_dart(),
+            Self::Slice => 1.into_dart(),
+            Self::Service => 2.into_dart(),
+            Self::Scope => 3.into_dart(),
+            Self::Other => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CgroupKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CgroupKind>
+    for crate::api::simple::CgroupKind
+{
+    fn into_into_dart(self) -> crate::api::simple::CgroupKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CgroupMemoryEvents {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.low.into_into_dart().into_dart(),
+            self.high.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.oom.into_into_dart().into_dart(),
+            self.oom_kill.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CgroupMemoryEvents
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CgroupMemoryEvents>
+    for crate::api::simple::CgroupMemoryEvents
+{
+    fn into_into_dart(self) -> crate::api::simple::CgroupMemoryEvents {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ConnectionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.protocol.into_into_dart().into_dart(),
+            self.ipv6.into_into_dart().into_dart(),
+            self.local_address.into_into_dart().into_dart(),
+            self.local_port.into_into_dart().into_dart(),
+            self.remote_address.into_into_dart().into_dart(),
+            self.remote_port.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.send_queue.into_into_dart().into_dart(),
+            self.receive_queue.into_into_dart().into_dart(),
+            self.pid.into_into_dart().into_dart(),
+            self.uid.into_into_dart().into_dart(),
+            self.inode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ConnectionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ConnectionInfo>
+    for crate::api::simple::ConnectionInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::ConnectionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ConnectionState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Established => 0.into_dart(),
+            Self::SynSent => 1.into_dart(),
+            Self::SynReceived => 2.into_dart(),
+            Self::FinWait1 => 3.into_dart(),
+            Self::FinWait2 => 4.into_dart(),
+            Self::TimeWait => 5.into_dart(),
+            Self::Closed => 6.into_dart(),
+            Self::CloseWait => 7.into_dart(),
+            Self::LastAck => 8.into_dart(),
+            Self::Listen => 9.into_dart(),
+            Self::Closing => 10.into_dart(),
+            Self::Unconnected => 11.into_dart(),
+            Self::Unknown => 12.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ConnectionState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ConnectionState>
+    for crate::api::simple::ConnectionState
+{
+    fn into_into_dart(self) -> crate::api::simple::ConnectionState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CpuTimesBreakdown {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user.into_into_dart().into_dart(),
+            self.nice.into_into_dart().into_dart(),
+            self.system.into_into_dart().into_dart(),
+            self.idle.into_into_dart().into_dart(),
+            self.iowait.into_into_dart().into_dart(),
+            self.irq.into_into_dart().into_dart(),
+            self.softirq.into_into_dart().into_dart(),
+            self.steal.into_into_dart().into_dart(),
+            self.guest.into_into_dart().into_dart(),
+            self.guest_nice.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CpuTimesBreakdown
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CpuTimesBreakdown>
+    for crate::api::simple::CpuTimesBreakdown
+{
+    fn into_into_dart(self) -> crate::api::simple::CpuTimesBreakdown {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CpuUsageMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Machine => 0.into_dart(),
+            Self::SingleCore => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CpuUsageMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CpuUsageMode>
+    for crate::api::simple::CpuUsageMode
+{
+    fn into_into_dart(self) -> crate::api::simple::CpuUsageMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::DiskActivity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.parent.into_into_dart().into_dart(),
+            self.read_bytes.into_into_dart().into_dart(),
+            self.write_bytes.into_into_dart().into_dart(),
+            self.read_bytes_per_sec.into_into_dart().into_dart(),
+            self.write_bytes_per_sec.into_into_dart().into_dart(),
+            self.read_iops.into_into_dart().into_dart(),
+            self.write_iops.into_into_dart().into_dart(),
+            self.avg_wait_ms.into_into_dart().into_dart(),
+            self.utilization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::DiskActivity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::DiskActivity>
+    for crate::api::simple::DiskActivity
+{
+    fn into_into_dart(self) -> crate::api::simple::DiskActivity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::DiskInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.mount_point.into_into_dart().into_dart(),
+            self.total_space.into_into_dart().into_dart(),
+            self.used_space.into_into_dart().into_dart(),
+            self.available_space.into_into_dart().into_dart(),
+            self.fs_type.into_into_dart().into_dart(),
+            self.device.into_into_dart().into_dart(),
+            self.mount_options.into_into_dart().into_dart(),
+            self.read_only.into_into_dart().into_dart(),
+            self.inodes_total.into_into_dart().into_dart(),
+            self.inodes_used.into_into_dart().into_dart(),
+            self.inodes_free.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::DiskInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::DiskInfo>
+    for crate::api::simple::DiskInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::DiskInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::EnvironmentVariable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::EnvironmentVariable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::EnvironmentVariable>
+    for crate::api::simple::EnvironmentVariable
+{
+    fn into_into_dart(self) -> crate::api::simple::EnvironmentVariable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FileDescriptorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::File => 0.into_dart(),
+            Self::Directory => 1.into_dart(),
+            Self::Device => 2.into_dart(),
+            Self::Socket => 3.into_dart(),
+            Self::Pipe => 4.into_dart(),
+            Self::AnonInode => 5.into_dart(),
+            Self::Other => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::FileDescriptorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FileDescriptorKind>
+    for crate::api::simple::FileDescriptorKind
+{
+    fn into_into_dart(self) -> crate::api::simple::FileDescriptorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::IoPriority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.class.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::IoPriority
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::IoPriority>
+    for crate::api::simple::IoPriority
+{
+    fn into_into_dart(self) -> crate::api::simple::IoPriority {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::IoPriorityClass {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Realtime => 1.into_dart(),
+            Self::BestEffort => 2.into_dart(),
+            Self::Idle => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::IoPriorityClass
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::IoPriorityClass>
+    for crate::api::simple::IoPriorityClass
+{
+    fn into_into_dart(self) -> crate::api::simple::IoPriorityClass {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KillOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Killed => 0.into_dart(),
+            Self::AlreadyExited => 1.into_dart(),
+            Self::Failed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KillOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KillOutcome>
+    for crate::api::simple::KillOutcome
+{
+    fn into_into_dart(self) -> crate::api::simple::KillOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KillTreeStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::LeavesFirst => 0.into_dart(),
+            Self::ProcessGroup => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KillTreeStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KillTreeStrategy>
+    for crate::api::simple::KillTreeStrategy
+{
+    fn into_into_dart(self) -> crate::api::simple::KillTreeStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MappedFileSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.regions.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.rss.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.private.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MappedFileSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MappedFileSummary>
+    for crate::api::simple::MappedFileSummary
+{
+    fn into_into_dart(self) -> crate::api::simple::MappedFileSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MemoryMaps {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.regions.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MemoryMaps
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MemoryMaps>
+    for crate::api::simple::MemoryMaps
+{
+    fn into_into_dart(self) -> crate::api::simple::MemoryMaps {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MemoryMetric {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rss => 0.into_dart(),
+            Self::Pss => 1.into_dart(),
+            Self::Uss => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MemoryMetric
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MemoryMetric>
+    for crate::api::simple::MemoryMetric
+{
+    fn into_into_dart(self) -> crate::api::simple::MemoryMetric {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MemoryRegion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.permissions.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.device.into_into_dart().into_dart(),
+            self.inode.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.deleted.into_into_dart().into_dart(),
+            self.rss.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.private.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MemoryRegion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MemoryRegion>
+    for crate::api::simple::MemoryRegion
+{
+    fn into_into_dart(self) -> crate::api::simple::MemoryRegion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MountFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.include_pseudo.into_into_dart().into_dart(),
+            self.collapse_bind_mounts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MountFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MountFilter>
+    for crate::api::simple::MountFilter
+{
+    fn into_into_dart(self) -> crate::api::simple::MountFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NetworkAttribution {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Socket => 0.into_dart(),
+            Self::Namespace => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NetworkAttribution
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NetworkAttribution>
+    for crate::api::simple::NetworkAttribution
+{
+    fn into_into_dart(self) -> crate::api::simple::NetworkAttribution {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NetworkInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.packets_sent.into_into_dart().into_dart(),
+            self.packets_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NetworkInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NetworkInfo>
+    for crate::api::simple::NetworkInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::NetworkInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NetworkInterfaceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.is_loopback.into_into_dart().into_dart(),
+            self.is_virtual.into_into_dart().into_dart(),
+            self.operstate.into_into_dart().into_dart(),
+            self.link_speed_mbps.into_into_dart().into_dart(),
+            self.mtu.into_into_dart().into_dart(),
+            self.mac_address.into_into_dart().into_dart(),
+            self.ipv4_addresses.into_into_dart().into_dart(),
+            self.ipv6_addresses.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.packets_received.into_into_dart().into_dart(),
+            self.packets_sent.into_into_dart().into_dart(),
+            self.receive_errors.into_into_dart().into_dart(),
+            self.send_errors.into_into_dart().into_dart(),
+            self.receive_dropped.into_into_dart().into_dart(),
+            self.send_dropped.into_into_dart().into_dart(),
+            self.receive_bytes_per_sec.into_into_dart().into_dart(),
+            self.send_bytes_per_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NetworkInterfaceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NetworkInterfaceInfo>
+    for crate::api::simple::NetworkInterfaceInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::NetworkInterfaceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::OpenFileInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fd.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+            self.readable.into_into_dart().into_dart(),
+            self.writable.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.deleted.into_into_dart().into_dart(),
+            self.inode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::OpenFileInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::OpenFileInfo>
+    for crate::api::simple::OpenFileInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::OpenFileInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::PathUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.fd.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::PathUsage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::PathUsage>
+    for crate::api::simple::PathUsage
+{
+    fn into_into_dart(self) -> crate::api::simple::PathUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::PathUsageKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::OpenFile => 0.into_dart(),
+            Self::WorkingDirectory => 1.into_dart(),
+            Self::RootDirectory => 2.into_dart(),
+            Self::Executable => 3.into_dart(),
+            Self::MemoryMapped => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::PathUsageKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::PathUsageKind>
+    for crate::api::simple::PathUsageKind
+{
+    fn into_into_dart(self) -> crate::api::simple::PathUsageKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::Pressure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.some.into_into_dart().into_dart(),
+            self.full.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::Pressure {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::Pressure>
+    for crate::api::simple::Pressure
+{
+    fn into_into_dart(self) -> crate::api::simple::Pressure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::PressureLine {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.avg10.into_into_dart().into_dart(),
+            self.avg60.into_into_dart().into_dart(),
+            self.avg300.into_into_dart().into_dart(),
+            self.total_usec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::PressureLine
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::PressureLine>
+    for crate::api::simple::PressureLine
+{
+    fn into_into_dart(self) -> crate::api::simple::PressureLine {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::PriorityLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Idle => 0.into_dart(),
+            Self::BelowNormal => 1.into_dart(),
+            Self::Normal => 2.into_dart(),
+            Self::AboveNormal => 3.into_dart(),
+            Self::High => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::PriorityLevel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::PriorityLevel>
+    for crate::api::simple::PriorityLevel
+{
+    fn into_into_dart(self) -> crate::api::simple::PriorityLevel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessControlError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessControlError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessControlError>
+    for crate::api::simple::ProcessControlError
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessControlError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessControlErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::PermissionDenied => 0.into_dart(),
+            Self::NoSuchProcess => 1.into_dart(),
+            Self::ProtectedProcess => 2.into_dart(),
+            Self::InvalidArgument => 3.into_dart(),
+            Self::Unsupported => 4.into_dart(),
+            Self::TimedOut => 5.into_dart(),
+            Self::Other => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessControlErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessControlErrorKind>
+    for crate::api::simple::ProcessControlErrorKind
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessControlErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessCredentials {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.real_uid.into_into_dart().into_dart(),
+            self.effective_uid.into_into_dart().into_dart(),
+            self.saved_uid.into_into_dart().into_dart(),
+            self.real_gid.into_into_dart().into_dart(),
+            self.effective_gid.into_into_dart().into_dart(),
+            self.saved_gid.into_into_dart().into_dart(),
+            self.supplementary_groups.into_into_dart().into_dart(),
+            self.real_user.into_into_dart().into_dart(),
+            self.effective_user.into_into_dart().into_dart(),
+            self.group.into_into_dart().into_dart(),
+            self.supplementary_group_names.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessCredentials
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessCredentials>
+    for crate::api::simple::ProcessCredentials
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessCredentials {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessDetailError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessDetailError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessDetailError>
+    for crate::api::simple::ProcessDetailError
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessDetailError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessDetailField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Executable => 0.into_dart(),
+            Self::WorkingDirectory => 1.into_dart(),
+            Self::RootDirectory => 2.into_dart(),
+            Self::CommandLine => 3.into_dart(),
+            Self::Environment => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessDetailField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessDetailField>
+    for crate::api::simple::ProcessDetailField
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessDetailField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.exe.into_into_dart().into_dart(),
+            self.exe_deleted.into_into_dart().into_dart(),
+            self.cwd.into_into_dart().into_dart(),
+            self.root.into_into_dart().into_dart(),
+            self.argv.into_into_dart().into_dart(),
+            self.environment.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessDetails>
+    for crate::api::simple::ProcessDetails
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessDiskIo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.read_bytes.into_into_dart().into_dart(),
+            self.write_bytes.into_into_dart().into_dart(),
+            self.cancelled_write_bytes.into_into_dart().into_dart(),
+            self.read_chars.into_into_dart().into_dart(),
+            self.write_chars.into_into_dart().into_dart(),
+            self.read_syscalls.into_into_dart().into_dart(),
+            self.write_syscalls.into_into_dart().into_dart(),
+            self.read_bytes_per_sec.into_into_dart().into_dart(),
+            self.write_bytes_per_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessDiskIo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessDiskIo>
+    for crate::api::simple::ProcessDiskIo
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessDiskIo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.memory_usage.into_into_dart().into_dart(),
+            self.memory_metric.into_into_dart().into_dart(),
+            self.parent_pid.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.command.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.age.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.nice.into_into_dart().into_dart(),
+            self.io_priority.into_into_dart().into_dart(),
+            self.last_cpu.into_into_dart().into_dart(),
+            self.thread_count.into_into_dart().into_dart(),
+            self.disk_io.into_into_dart().into_dart(),
+            self.user.into_into_dart().into_dart(),
+            self.credentials.into_into_dart().into_dart(),
+            self.privileged.into_into_dart().into_dart(),
+            self.cgroup.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessInfo>
+    for crate::api::simple::ProcessInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessKillResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.parent_pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.outcome.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessKillResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessKillResult>
+    for crate::api::simple::ProcessKillResult
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessKillResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessMemory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rss.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.uss.into_into_dart().into_dart(),
+            self.shared_clean.into_into_dart().into_dart(),
+            self.shared_dirty.into_into_dart().into_dart(),
+            self.private_clean.into_into_dart().into_dart(),
+            self.private_dirty.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+            self.swap_pss.into_into_dart().into_dart(),
+            self.locked.into_into_dart().into_dart(),
+            self.huge_pages.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessMemory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessMemory>
+    for crate::api::simple::ProcessMemory
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessMemory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessNetworkUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.received_bytes.into_into_dart().into_dart(),
+            self.sent_bytes.into_into_dart().into_dart(),
+            self.receive_bytes_per_sec.into_into_dart().into_dart(),
+            self.send_bytes_per_sec.into_into_dart().into_dart(),
+            self.connections.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.net_namespace.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessNetworkUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessNetworkUsage>
+    for crate::api::simple::ProcessNetworkUsage
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessNetworkUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessPriority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.nice.into_into_dart().into_dart(),
+            self.io_priority.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessPriority
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessPriority>
+    for crate::api::simple::ProcessPriority
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessPriority {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessSignal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Hangup => 0.into_dart(),
+            Self::Interrupt => 1.into_dart(),
+            Self::Quit => 2.into_dart(),
+            Self::Abort => 3.into_dart(),
+            Self::Kill => 4.into_dart(),
+            Self::User1 => 5.into_dart(),
+            Self::User2 => 6.into_dart(),
+            Self::Terminate => 7.into_dart(),
+            Self::Continue => 8.into_dart(),
+            Self::Stop => 9.into_dart(),
+            Self::TerminalStop => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessSignal
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessSignal>
+    for crate::api::simple::ProcessSignal
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessSignal {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Running => 0.into_dart(),
+            Self::Sleeping => 1.into_dart(),
+            Self::DiskSleep => 2.into_dart(),
+            Self::Stopped => 3.into_dart(),
+            Self::Suspended => 4.into_dart(),
+            Self::Traced => 5.into_dart(),
+            Self::Zombie => 6.into_dart(),
+            Self::Dead => 7.into_dart(),
+            Self::Idle => 8.into_dart(),
+            Self::Parked => 9.into_dart(),
+            Self::Unknown => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ProcessState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessState>
+    for crate::api::simple::ProcessState
+{
+    fn into_into_dart(self) -> crate::api::simple::ProcessState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SchedulerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.policy.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SchedulerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SchedulerInfo>
+    for crate::api::simple::SchedulerInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::SchedulerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SchedulerPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Other => 0.into_dart(),
+            Self::Batch => 1.into_dart(),
+            Self::Idle => 2.into_dart(),
+            Self::Fifo => 3.into_dart(),
+            Self::RoundRobin => 4.into_dart(),
+            Self::Deadline => 5.into_dart(),
+            Self::Unknown => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SchedulerPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SchedulerPolicy>
+    for crate::api::simple::SchedulerPolicy
+{
+    fn into_into_dart(self) -> crate::api::simple::SchedulerPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SeccompMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Disabled => 0.into_dart(),
+            Self::Strict => 1.into_dart(),
+            Self::Filter => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SeccompMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SeccompMode>
+    for crate::api::simple::SeccompMode
+{
+    fn into_into_dart(self) -> crate::api::simple::SeccompMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SecurityContext {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.effective.into_into_dart().into_dart(),
+            self.permitted.into_into_dart().into_dart(),
+            self.inheritable.into_into_dart().into_dart(),
+            self.bounding.into_into_dart().into_dart(),
+            self.ambient.into_into_dart().into_dart(),
+            self.seccomp.into_into_dart().into_dart(),
+            self.seccomp_filters.into_into_dart().into_dart(),
+            self.no_new_privs.into_into_dart().into_dart(),
+            self.lsm.into_into_dart().into_dart(),
+            self.lsm_label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SecurityContext
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SecurityContext>
+    for crate::api::simple::SecurityContext
+{
+    fn into_into_dart(self) -> crate::api::simple::SecurityContext {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SystemInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.os_name.into_into_dart().into_dart(),
+            self.os_version.into_into_dart().into_dart(),
+            self.kernel_version.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.cpu_brand.into_into_dart().into_dart(),
+            self.cpu_cores.into_into_dart().into_dart(),
+            self.total_memory.into_into_dart().into_dart(),
+            self.boot_time.into_into_dart().into_dart(),
+            self.uptime.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SystemInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SystemInfo>
+    for crate::api::simple::SystemInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::SystemInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SystemResourceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.cpu_per_core.into_into_dart().into_dart(),
+            self.cpu_times.into_into_dart().into_dart(),
+            self.cpu_times_per_core.into_into_dart().into_dart(),
+            self.memory_total.into_into_dart().into_dart(),
+            self.memory_used.into_into_dart().into_dart(),
+            self.memory_available.into_into_dart().into_dart(),
+            self.swap_total.into_into_dart().into_dart(),
+            self.swap_used.into_into_dart().into_dart(),
+            self.swap_free.into_into_dart().into_dart(),
+            self.disk_usage.into_into_dart().into_dart(),
+            self.network_usage.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SystemResourceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SystemResourceInfo>
+    for crate::api::simple::SystemResourceInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::SystemResourceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::TerminationOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Terminated => 0.into_dart(),
+            Self::Killed => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::TerminationOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TerminationOutcome>
+    for crate::api::simple::TerminationOutcome
+{
+    fn into_into_dart(self) -> crate::api::simple::TerminationOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ThreadInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.user_time_ms.into_into_dart().into_dart(),
+            self.system_time_ms.into_into_dart().into_dart(),
+            self.last_cpu.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.nice.into_into_dart().into_dart(),
+            self.voluntary_context_switches.into_into_dart().into_dart(),
+            self.involuntary_context_switches
+                .into_into_dart()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ThreadInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ThreadInfo>
+    for crate::api::simple::ThreadInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::ThreadInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ThreadScheduling {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.affinity.into_into_dart().into_dart(),
+            self.scheduler.into_into_dart().into_dart(),
+            self.last_cpu.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ThreadScheduling
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ThreadScheduling>
+    for crate::api::simple::ThreadScheduling
+{
+    fn into_into_dart(self) -> crate::api::simple::ThreadScheduling {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::TransportProtocol {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Tcp => 0.into_dart(),
+            Self::Udp => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::TransportProtocol
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TransportProtocol>
+    for crate::api::simple::TransportProtocol
+{
+    fn into_into_dart(self) -> crate::api::simple::TransportProtocol {
+        self
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::simple::CapabilitySet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.mask, serializer);
+        <Vec<String>>::sse_encode(self.names, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::CgroupCpuStat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.usage_usec, serializer);
+        <u64>::sse_encode(self.user_usec, serializer);
+        <u64>::sse_encode(self.system_usec, serializer);
+        <u64>::sse_encode(self.nr_periods, serializer);
+        <u64>::sse_encode(self.nr_throttled, serializer);
+        <u64>::sse_encode(self.throttled_usec, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::CgroupInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.parent, serializer);
+        <crate::api::simple::CgroupKind>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.process_count, serializer);
+        <Option<crate::api::simple::CgroupCpuStat>>::sse_encode(self.cpu, serializer);
+        <f64>::sse_encode(self.cpu_usage, serializer);
+        <Option<u64>>::sse_encode(self.memory_current, serializer);
+        <Option<u64>>::sse_encode(self.memory_max, serializer);
+        <Option<crate::api::simple::CgroupMemoryEvents>>::sse_encode(
+            self.memory_events,
+            serializer,
+        );
+        <Vec<crate::api::simple::CgroupIoStat>>::sse_encode(self.io, serializer);
+        <u64>::sse_encode(self.io_read_bytes, serializer);
+        <u64>::sse_encode(self.io_write_bytes, serializer);
+        <Option<u64>>::sse_encode(self.pids_current, serializer);
+        <Option<crate::api::simple::Pressure>>::sse_encode(self.cpu_pressure, serializer);
+        <Option<crate::api::simple::Pressure>>::sse_encode(self.memory_pressure, serializer);
+        <Option<crate::api::simple::Pressure>>::sse_encode(self.io_pressure, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::CgroupIoStat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.device, serializer);
+        <u64>::sse_encode(self.read_bytes, serializer);
+        <u64>::sse_encode(self.write_bytes, serializer);
+        <u64>::sse_encode(self.read_ios, serializer);
+        <u64>::sse_encode(self.write_ios, serializer);
+        <u64>::sse_encode(self.discard_bytes, serializer);
+        <u64>::sse_encode(self.discard_ios, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::CgroupKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::CgroupKind::Root => 0,
+                crate::api::simple::CgroupKind::Slice => 1,
+                crate::api::simple::CgroupKind::Service => 2,
+                crate::api::simple::CgroupKind::Scope => 3,
+                crate::api::simple::CgroupKind::Other => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::CgroupMemoryEvents {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.low, serializer);
+        <u64>::sse_encode(self.high, serializer);
+        <u64>::sse_encode(self.max, serializer);
+        <u64>::sse_encode(self.oom, serializer);
+        <u64>::sse_encode(self.oom_kill, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ConnectionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::TransportProtocol>::sse_encode(self.protocol, serializer);
+        <bool>::sse_encode(self.ipv6, serializer);
+        <String>::sse_encode(self.local_address, serializer);
+        <u16>::sse_encode(self.local_port, serializer);
+        <String>::sse_encode(self.remote_address, serializer);
+        <u16>::sse_encode(self.remote_port, serializer);
+        <crate::api::simple::ConnectionState>::sse_encode(self.state, serializer);
+        <u64>::sse_encode(self.send_queue, serializer);
+        <u64>::sse_encode(self.receive_queue, serializer);
+        <Option<u32>>::sse_encode(self.pid, serializer);
+        <u32>::sse_encode(self.uid, serializer);
+        <u64>::sse_encode(self.inode, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ConnectionState::Established => 0,
+                crate::api::simple::ConnectionState::SynSent => 1,
+                crate::api::simple::ConnectionState::SynReceived => 2,
+                crate::api::simple::ConnectionState::FinWait1 => 3,
+                crate::api::simple::ConnectionState::FinWait2 => 4,
+                crate::api::simple::ConnectionState::TimeWait => 5,
+                crate::api::simple::ConnectionState::Closed => 6,
+                crate::api::simple::ConnectionState::CloseWait => 7,
+                crate::api::simple::ConnectionState::LastAck => 8,
+                crate::api::simple::ConnectionState::Listen => 9,
+                crate::api::simple::ConnectionState::Closing => 10,
+                crate::api::simple::ConnectionState::Unconnected => 11,
+                crate::api::simple::ConnectionState::Unknown => 12,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::CpuTimesBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.user, serializer);
+        <f64>::sse_encode(self.nice, serializer);
+        <f64>::sse_encode(self.system, serializer);
+        <f64>::sse_encode(self.idle, serializer);
+        <f64>::sse_encode(self.iowait, serializer);
+        <f64>::sse_encode(self.irq, serializer);
+        <f64>::sse_encode(self.softirq, serializer);
+        <f64>::sse_encode(self.steal, serializer);
+        <f64>::sse_encode(self.guest, serializer);
+        <f64>::sse_encode(self.guest_nice, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::CpuUsageMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::CpuUsageMode::Machine => 0,
+                crate::api::simple::CpuUsageMode::SingleCore => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::DiskActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.parent, serializer);
+        <u64>::sse_encode(self.read_bytes, serializer);
+        <u64>::sse_encode(self.write_bytes, serializer);
+        <f64>::sse_encode(self.read_bytes_per_sec, serializer);
+        <f64>::sse_encode(self.write_bytes_per_sec, serializer);
+        <f64>::sse_encode(self.read_iops, serializer);
+        <f64>::sse_encode(self.write_iops, serializer);
+        <f64>::sse_encode(self.avg_wait_ms, serializer);
+        <f64>::sse_encode(self.utilization, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::DiskInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.mount_point, serializer);
+        <u64>::sse_encode(self.total_space, serializer);
+        <u64>::sse_encode(self.used_space, serializer);
+        <u64>::sse_encode(self.available_space, serializer);
+        <String>::sse_encode(self.fs_type, serializer);
+        <String>::sse_encode(self.device, serializer);
+        <Vec<String>>::sse_encode(self.mount_options, serializer);
+        <bool>::sse_encode(self.read_only, serializer);
+        <u64>::sse_encode(self.inodes_total, serializer);
+        <u64>::sse_encode(self.inodes_used, serializer);
+        <u64>::sse_encode(self.inodes_free, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::EnvironmentVariable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::simple::FileDescriptorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::FileDescriptorKind::File => 0,
+                crate::api::simple::FileDescriptorKind::Directory => 1,
+                crate::api::simple::FileDescriptorKind::Device => 2,
+                crate::api::simple::FileDescriptorKind::Socket => 3,
+                crate::api::simple::FileDescriptorKind::Pipe => 4,
+                crate::api::simple::FileDescriptorKind::AnonInode => 5,
+                crate::api::simple::FileDescriptorKind::Other => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::simple::IoPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::IoPriorityClass>::sse_encode(self.class, serializer);
+        <u8>::sse_encode(self.level, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::IoPriorityClass {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::IoPriorityClass::None => 0,
+                crate::api::simple::IoPriorityClass::Realtime => 1,
+                crate::api::simple::IoPriorityClass::BestEffort => 2,
+                crate::api::simple::IoPriorityClass::Idle => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::KillOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::KillOutcome::Killed => 0,
+                crate::api::simple::KillOutcome::AlreadyExited => 1,
+                crate::api::simple::KillOutcome::Failed => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::KillTreeStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::KillTreeStrategy::LeavesFirst => 0,
+                crate::api::simple::KillTreeStrategy::ProcessGroup => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::CgroupInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::CgroupInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::CgroupIoStat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::CgroupIoStat>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ConnectionInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::CpuTimesBreakdown> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::CpuTimesBreakdown>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::DiskActivity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::DiskActivity>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::DiskInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::DiskInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::EnvironmentVariable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::EnvironmentVariable>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::MappedFileSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::MappedFileSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::MemoryRegion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::MemoryRegion>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::NetworkInterfaceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::NetworkInterfaceInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::OpenFileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::OpenFileInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::PathUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::PathUsage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ProcessDetailError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ProcessDetailError>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ProcessInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ProcessInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ProcessKillResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ProcessKillResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ProcessNetworkUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ProcessNetworkUsage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ThreadInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ThreadInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ThreadScheduling> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ThreadScheduling>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::simple::DiskInfo {
+impl SseEncode for crate::api::simple::MappedFileSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_mountPoint = <String>::sse_decode(deserializer);
-        let mut var_totalSpace = <u64>::sse_decode(deserializer);
-        let mut var_usedSpace = <u64>::sse_decode(deserializer);
-        let mut var_availableSpace = <u64>::sse_decode(deserializer);
-        return crate::api::simple::DiskInfo {
-            name: var_name,
-            mount_point: var_mountPoint,
-            total_space: var_totalSpace,
-            used_space: var_usedSpace,
-            available_space: var_availableSpace,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.regions, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <u64>::sse_encode(self.rss, serializer);
+        <u64>::sse_encode(self.pss, serializer);
+        <u64>::sse_encode(self.private, serializer);
+        <u64>::sse_encode(self.swap, serializer);
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for crate::api::simple::MemoryMaps {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::MemoryRegion>>::sse_encode(self.regions, serializer);
+        <Vec<crate::api::simple::MappedFileSummary>>::sse_encode(self.files, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::DiskInfo> {
+impl SseEncode for crate::api::simple::MemoryMetric {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::DiskInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::MemoryMetric::Rss => 0,
+                crate::api::simple::MemoryMetric::Pss => 1,
+                crate::api::simple::MemoryMetric::Uss => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<f64> {
+impl SseEncode for crate::api::simple::MemoryRegion {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f64>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start, serializer);
+        <u64>::sse_encode(self.end, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.permissions, serializer);
+        <u64>::sse_encode(self.offset, serializer);
+        <String>::sse_encode(self.device, serializer);
+        <u64>::sse_encode(self.inode, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.deleted, serializer);
+        <u64>::sse_encode(self.rss, serializer);
+        <u64>::sse_encode(self.pss, serializer);
+        <u64>::sse_encode(self.private, serializer);
+        <u64>::sse_encode(self.swap, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::MountFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.include_pseudo, serializer);
+        <bool>::sse_encode(self.collapse_bind_mounts, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::NetworkAttribution {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::NetworkAttribution::Socket => 0,
+                crate::api::simple::NetworkAttribution::Namespace => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::NetworkInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <u64>::sse_encode(self.packets_sent, serializer);
+        <u64>::sse_encode(self.packets_received, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::NetworkInterfaceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <bool>::sse_encode(self.is_loopback, serializer);
+        <bool>::sse_encode(self.is_virtual, serializer);
+        <String>::sse_encode(self.operstate, serializer);
+        <Option<u64>>::sse_encode(self.link_speed_mbps, serializer);
+        <u32>::sse_encode(self.mtu, serializer);
+        <String>::sse_encode(self.mac_address, serializer);
+        <Vec<String>>::sse_encode(self.ipv4_addresses, serializer);
+        <Vec<String>>::sse_encode(self.ipv6_addresses, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.packets_received, serializer);
+        <u64>::sse_encode(self.packets_sent, serializer);
+        <u64>::sse_encode(self.receive_errors, serializer);
+        <u64>::sse_encode(self.send_errors, serializer);
+        <u64>::sse_encode(self.receive_dropped, serializer);
+        <u64>::sse_encode(self.send_dropped, serializer);
+        <f64>::sse_encode(self.receive_bytes_per_sec, serializer);
+        <f64>::sse_encode(self.send_bytes_per_sec, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::OpenFileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.fd, serializer);
+        <crate::api::simple::FileDescriptorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.flags, serializer);
+        <bool>::sse_encode(self.readable, serializer);
+        <bool>::sse_encode(self.writable, serializer);
+        <u64>::sse_encode(self.position, serializer);
+        <bool>::sse_encode(self.deleted, serializer);
+        <u64>::sse_encode(self.inode, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::ProcessInfo> {
+impl SseEncode for Option<crate::api::simple::CgroupCpuStat> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::ProcessInfo>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::CgroupCpuStat>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::simple::NetworkInfo {
+impl SseEncode for Option<crate::api::simple::CgroupMemoryEvents> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_bytesSent = <u64>::sse_decode(deserializer);
-        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
-        let mut var_packetsSent = <u64>::sse_decode(deserializer);
-        let mut var_packetsReceived = <u64>::sse_decode(deserializer);
-        return crate::api::simple::NetworkInfo {
-            bytes_sent: var_bytesSent,
-            bytes_received: var_bytesReceived,
-            packets_sent: var_packetsSent,
-            packets_received: var_packetsReceived,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::CgroupMemoryEvents>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for Option<crate::api::simple::Pressure> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::Pressure>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::simple::ProcessInfo {
+impl SseEncode for Option<crate::api::simple::PressureLine> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_pid = <u32>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
-        let mut var_memoryUsage = <u64>::sse_decode(deserializer);
-        let mut var_parentPid = <Option<u32>>::sse_decode(deserializer);
-        let mut var_status = <String>::sse_decode(deserializer);
-        let mut var_command = <String>::sse_decode(deserializer);
-        let mut var_startTime = <u64>::sse_decode(deserializer);
-        return crate::api::simple::ProcessInfo {
-            pid: var_pid,
-            name: var_name,
-            cpu_usage: var_cpuUsage,
-            memory_usage: var_memoryUsage,
-            parent_pid: var_parentPid,
-            status: var_status,
-            command: var_command,
-            start_time: var_startTime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::PressureLine>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::simple::SystemInfo {
+impl SseEncode for Option<crate::api::simple::ProcessControlError> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_osName = <String>::sse_decode(deserializer);
-        let mut var_osVersion = <String>::sse_decode(deserializer);
-        let mut var_kernelVersion = <String>::sse_decode(deserializer);
-        let mut var_hostname = <String>::sse_decode(deserializer);
-        let mut var_cpuBrand = <String>::sse_decode(deserializer);
-        let mut var_cpuCores = <u32>::sse_decode(deserializer);
-        let mut var_totalMemory = <u64>::sse_decode(deserializer);
-        let mut var_bootTime = <u64>::sse_decode(deserializer);
-        let mut var_uptime = <u64>::sse_decode(deserializer);
-        return crate::api::simple::SystemInfo {
-            os_name: var_osName,
-            os_version: var_osVersion,
-            kernel_version: var_kernelVersion,
-            hostname: var_hostname,
-            cpu_brand: var_cpuBrand,
-            cpu_cores: var_cpuCores,
-            total_memory: var_totalMemory,
-            boot_time: var_bootTime,
-            uptime: var_uptime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::ProcessControlError>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::simple::SystemResourceInfo {
+impl SseEncode for Option<crate::api::simple::ProcessCredentials> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_cpuUsage = <f64>::sse_decode(deserializer);
-        let mut var_cpuPerCore = <Vec<f64>>::sse_decode(deserializer);
-        let mut var_memoryTotal = <u64>::sse_decode(deserializer);
-        let mut var_memoryUsed = <u64>::sse_decode(deserializer);
-        let mut var_memoryAvailable = <u64>::sse_decode(deserializer);
-        let mut var_swapTotal = <u64>::sse_decode(deserializer);
-        let mut var_swapUsed = <u64>::sse_decode(deserializer);
-        let mut var_swapFree = <u64>::sse_decode(deserializer);
-        let mut var_diskUsage = <Vec<crate::api::simple::DiskInfo>>::sse_decode(deserializer);
-        let mut var_networkUsage = <crate::api::simple::NetworkInfo>::sse_decode(deserializer);
-        return crate::api::simple::SystemResourceInfo {
-            cpu_usage: var_cpuUsage,
-            cpu_per_core: var_cpuPerCore,
-            memory_total: var_memoryTotal,
-            memory_used: var_memoryUsed,
-            memory_available: var_memoryAvailable,
-            swap_total: var_swapTotal,
-            swap_used: var_swapUsed,
-            swap_free: var_swapFree,
-            disk_usage: var_diskUsage,
-            network_usage: var_networkUsage,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::ProcessCredentials>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Option<crate::api::simple::ProcessDiskIo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::ProcessDiskIo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Option<crate::api::simple::SeccompMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::SeccompMode>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        6 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<Vec<crate::api::simple::EnvironmentVariable>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::api::simple::EnvironmentVariable>>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__simple__get_backend_version_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple__get_processes_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__get_system_info_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__simple__get_system_resources_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__kill_process_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::simple::PathUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::simple::PathUsageKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <Option<u32>>::sse_encode(self.fd, serializer);
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::DiskInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.mount_point.into_into_dart().into_dart(),
-            self.total_space.into_into_dart().into_dart(),
-            self.used_space.into_into_dart().into_dart(),
-            self.available_space.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::simple::PathUsageKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::PathUsageKind::OpenFile => 0,
+                crate::api::simple::PathUsageKind::WorkingDirectory => 1,
+                crate::api::simple::PathUsageKind::RootDirectory => 2,
+                crate::api::simple::PathUsageKind::Executable => 3,
+                crate::api::simple::PathUsageKind::MemoryMapped => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::DiskInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::DiskInfo>
-    for crate::api::simple::DiskInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::DiskInfo {
-        self
+
+impl SseEncode for crate::api::simple::Pressure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::PressureLine>::sse_encode(self.some, serializer);
+        <Option<crate::api::simple::PressureLine>>::sse_encode(self.full, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::NetworkInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.bytes_sent.into_into_dart().into_dart(),
-            self.bytes_received.into_into_dart().into_dart(),
-            self.packets_sent.into_into_dart().into_dart(),
-            self.packets_received.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::PressureLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.avg10, serializer);
+        <f64>::sse_encode(self.avg60, serializer);
+        <f64>::sse_encode(self.avg300, serializer);
+        <u64>::sse_encode(self.total_usec, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::NetworkInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NetworkInfo>
-    for crate::api::simple::NetworkInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::NetworkInfo {
-        self
+
+impl SseEncode for crate::api::simple::PriorityLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::PriorityLevel::Idle => 0,
+                crate::api::simple::PriorityLevel::BelowNormal => 1,
+                crate::api::simple::PriorityLevel::Normal => 2,
+                crate::api::simple::PriorityLevel::AboveNormal => 3,
+                crate::api::simple::PriorityLevel::High => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::ProcessInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.pid.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.cpu_usage.into_into_dart().into_dart(),
-            self.memory_usage.into_into_dart().into_dart(),
-            self.parent_pid.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.command.into_into_dart().into_dart(),
-            self.start_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ProcessControlError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::ProcessControlErrorKind>::sse_encode(self.kind, serializer);
+        <i32>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::ProcessInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ProcessInfo>
-    for crate::api::simple::ProcessInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::ProcessInfo {
-        self
+
+impl SseEncode for crate::api::simple::ProcessControlErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ProcessControlErrorKind::PermissionDenied => 0,
+                crate::api::simple::ProcessControlErrorKind::NoSuchProcess => 1,
+                crate::api::simple::ProcessControlErrorKind::ProtectedProcess => 2,
+                crate::api::simple::ProcessControlErrorKind::InvalidArgument => 3,
+                crate::api::simple::ProcessControlErrorKind::Unsupported => 4,
+                crate::api::simple::ProcessControlErrorKind::TimedOut => 5,
+                crate::api::simple::ProcessControlErrorKind::Other => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::SystemInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.os_name.into_into_dart().into_dart(),
-            self.os_version.into_into_dart().into_dart(),
-            self.kernel_version.into_into_dart().into_dart(),
-            self.hostname.into_into_dart().into_dart(),
-            self.cpu_brand.into_into_dart().into_dart(),
-            self.cpu_cores.into_into_dart().into_dart(),
-            self.total_memory.into_into_dart().into_dart(),
-            self.boot_time.into_into_dart().into_dart(),
-            self.uptime.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ProcessCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.real_uid, serializer);
+        <u32>::sse_encode(self.effective_uid, serializer);
+        <u32>::sse_encode(self.saved_uid, serializer);
+        <u32>::sse_encode(self.real_gid, serializer);
+        <u32>::sse_encode(self.effective_gid, serializer);
+        <u32>::sse_encode(self.saved_gid, serializer);
+        <Vec<u32>>::sse_encode(self.supplementary_groups, serializer);
+        <String>::sse_encode(self.real_user, serializer);
+        <String>::sse_encode(self.effective_user, serializer);
+        <String>::sse_encode(self.group, serializer);
+        <Vec<String>>::sse_encode(self.supplementary_group_names, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::SystemInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SystemInfo>
-    for crate::api::simple::SystemInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::SystemInfo {
-        self
+
+impl SseEncode for crate::api::simple::ProcessDetailError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::ProcessDetailField>::sse_encode(self.field, serializer);
+        <crate::api::simple::ProcessControlError>::sse_encode(self.error, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::SystemResourceInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.cpu_usage.into_into_dart().into_dart(),
-            self.cpu_per_core.into_into_dart().into_dart(),
-            self.memory_total.into_into_dart().into_dart(),
-            self.memory_used.into_into_dart().into_dart(),
-            self.memory_available.into_into_dart().into_dart(),
-            self.swap_total.into_into_dart().into_dart(),
-            self.swap_used.into_into_dart().into_dart(),
-            self.swap_free.into_into_dart().into_dart(),
-            self.disk_usage.into_into_dart().into_dart(),
-            self.network_usage.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ProcessDetailField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ProcessDetailField::Executable => 0,
+                crate::api::simple::ProcessDetailField::WorkingDirectory => 1,
+                crate::api::simple::ProcessDetailField::RootDirectory => 2,
+                crate::api::simple::ProcessDetailField::CommandLine => 3,
+                crate::api::simple::ProcessDetailField::Environment => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::SystemResourceInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SystemResourceInfo>
-    for crate::api::simple::SystemResourceInfo
-{
-    fn into_into_dart(self) -> crate::api::simple::SystemResourceInfo {
-        self
+
+impl SseEncode for crate::api::simple::ProcessDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <Option<String>>::sse_encode(self.exe, serializer);
+        <bool>::sse_encode(self.exe_deleted, serializer);
+        <Option<String>>::sse_encode(self.cwd, serializer);
+        <Option<String>>::sse_encode(self.root, serializer);
+        <Option<Vec<String>>>::sse_encode(self.argv, serializer);
+        <Option<Vec<crate::api::simple::EnvironmentVariable>>>::sse_encode(
+            self.environment,
+            serializer,
+        );
+        <Vec<crate::api::simple::ProcessDetailError>>::sse_encode(self.errors, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::simple::ProcessDiskIo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <u64>::sse_encode(self.read_bytes, serializer);
+        <u64>::sse_encode(self.write_bytes, serializer);
+        <u64>::sse_encode(self.cancelled_write_bytes, serializer);
+        <u64>::sse_encode(self.read_chars, serializer);
+        <u64>::sse_encode(self.write_chars, serializer);
+        <u64>::sse_encode(self.read_syscalls, serializer);
+        <u64>::sse_encode(self.write_syscalls, serializer);
+        <f64>::sse_encode(self.read_bytes_per_sec, serializer);
+        <f64>::sse_encode(self.write_bytes_per_sec, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::simple::ProcessInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <f64>::sse_encode(self.cpu_usage, serializer);
+        <u64>::sse_encode(self.memory_usage, serializer);
+        <crate::api::simple::MemoryMetric>::sse_encode(self.memory_metric, serializer);
+        <Option<u32>>::sse_encode(self.parent_pid, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <crate::api::simple::ProcessState>::sse_encode(self.state, serializer);
+        <String>::sse_encode(self.command, serializer);
+        <u64>::sse_encode(self.start_time, serializer);
+        <u64>::sse_encode(self.age, serializer);
+        <crate::api::simple::PriorityLevel>::sse_encode(self.priority, serializer);
+        <i32>::sse_encode(self.nice, serializer);
+        <crate::api::simple::IoPriority>::sse_encode(self.io_priority, serializer);
+        <Option<u32>>::sse_encode(self.last_cpu, serializer);
+        <u32>::sse_encode(self.thread_count, serializer);
+        <Option<crate::api::simple::ProcessDiskIo>>::sse_encode(self.disk_io, serializer);
+        <Option<String>>::sse_encode(self.user, serializer);
+        <Option<crate::api::simple::ProcessCredentials>>::sse_encode(self.credentials, serializer);
+        <bool>::sse_encode(self.privileged, serializer);
+        <Option<String>>::sse_encode(self.cgroup, serializer);
     }
 }
 
-impl SseEncode for crate::api::simple::DiskInfo {
+impl SseEncode for crate::api::simple::ProcessKillResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <Option<u32>>::sse_encode(self.parent_pid, serializer);
         <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.mount_point, serializer);
-        <u64>::sse_encode(self.total_space, serializer);
-        <u64>::sse_encode(self.used_space, serializer);
-        <u64>::sse_encode(self.available_space, serializer);
+        <crate::api::simple::KillOutcome>::sse_encode(self.outcome, serializer);
+        <Option<crate::api::simple::ProcessControlError>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::api::simple::ProcessMemory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <u64>::sse_encode(self.rss, serializer);
+        <u64>::sse_encode(self.pss, serializer);
+        <u64>::sse_encode(self.uss, serializer);
+        <u64>::sse_encode(self.shared_clean, serializer);
+        <u64>::sse_encode(self.shared_dirty, serializer);
+        <u64>::sse_encode(self.private_clean, serializer);
+        <u64>::sse_encode(self.private_dirty, serializer);
+        <u64>::sse_encode(self.swap, serializer);
+        <u64>::sse_encode(self.swap_pss, serializer);
+        <u64>::sse_encode(self.locked, serializer);
+        <u64>::sse_encode(self.huge_pages, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::DiskInfo> {
+impl SseEncode for crate::api::simple::ProcessNetworkUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::DiskInfo>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.received_bytes, serializer);
+        <u64>::sse_encode(self.sent_bytes, serializer);
+        <f64>::sse_encode(self.receive_bytes_per_sec, serializer);
+        <f64>::sse_encode(self.send_bytes_per_sec, serializer);
+        <u32>::sse_encode(self.connections, serializer);
+        <crate::api::simple::NetworkAttribution>::sse_encode(self.source, serializer);
+        <Option<u64>>::sse_encode(self.net_namespace, serializer);
     }
 }
 
-impl SseEncode for Vec<f64> {
+impl SseEncode for crate::api::simple::ProcessPriority {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f64>::sse_encode(item, serializer);
-        }
+        <crate::api::simple::PriorityLevel>::sse_encode(self.level, serializer);
+        <i32>::sse_encode(self.nice, serializer);
+        <crate::api::simple::IoPriority>::sse_encode(self.io_priority, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::simple::ProcessSignal {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ProcessSignal::Hangup => 0,
+                crate::api::simple::ProcessSignal::Interrupt => 1,
+                crate::api::simple::ProcessSignal::Quit => 2,
+                crate::api::simple::ProcessSignal::Abort => 3,
+                crate::api::simple::ProcessSignal::Kill => 4,
+                crate::api::simple::ProcessSignal::User1 => 5,
+                crate::api::simple::ProcessSignal::User2 => 6,
+                crate::api::simple::ProcessSignal::Terminate => 7,
+                crate::api::simple::ProcessSignal::Continue => 8,
+                crate::api::simple::ProcessSignal::Stop => 9,
+                crate::api::simple::ProcessSignal::TerminalStop => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::simple::ProcessInfo> {
+impl SseEncode for crate::api::simple::ProcessState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::simple::ProcessInfo>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ProcessState::Running => 0,
+                crate::api::simple::ProcessState::Sleeping => 1,
+                crate::api::simple::ProcessState::DiskSleep => 2,
+                crate::api::simple::ProcessState::Stopped => 3,
+                crate::api::simple::ProcessState::Suspended => 4,
+                crate::api::simple::ProcessState::Traced => 5,
+                crate::api::simple::ProcessState::Zombie => 6,
+                crate::api::simple::ProcessState::Dead => 7,
+                crate::api::simple::ProcessState::Idle => 8,
+                crate::api::simple::ProcessState::Parked => 9,
+                crate::api::simple::ProcessState::Unknown => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::simple::NetworkInfo {
+impl SseEncode for crate::api::simple::SchedulerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.bytes_sent, serializer);
-        <u64>::sse_encode(self.bytes_received, serializer);
-        <u64>::sse_encode(self.packets_sent, serializer);
-        <u64>::sse_encode(self.packets_received, serializer);
+        <crate::api::simple::SchedulerPolicy>::sse_encode(self.policy, serializer);
+        <u32>::sse_encode(self.priority, serializer);
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::api::simple::SchedulerPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::SchedulerPolicy::Other => 0,
+                crate::api::simple::SchedulerPolicy::Batch => 1,
+                crate::api::simple::SchedulerPolicy::Idle => 2,
+                crate::api::simple::SchedulerPolicy::Fifo => 3,
+                crate::api::simple::SchedulerPolicy::RoundRobin => 4,
+                crate::api::simple::SchedulerPolicy::Deadline => 5,
+                crate::api::simple::SchedulerPolicy::Unknown => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::simple::ProcessInfo {
+impl SseEncode for crate::api::simple::SeccompMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::SeccompMode::Disabled => 0,
+                crate::api::simple::SeccompMode::Strict => 1,
+                crate::api::simple::SeccompMode::Filter => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::SecurityContext {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u32>::sse_encode(self.pid, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <f64>::sse_encode(self.cpu_usage, serializer);
-        <u64>::sse_encode(self.memory_usage, serializer);
-        <Option<u32>>::sse_encode(self.parent_pid, serializer);
-        <String>::sse_encode(self.status, serializer);
-        <String>::sse_encode(self.command, serializer);
-        <u64>::sse_encode(self.start_time, serializer);
+        <crate::api::simple::CapabilitySet>::sse_encode(self.effective, serializer);
+        <crate::api::simple::CapabilitySet>::sse_encode(self.permitted, serializer);
+        <crate::api::simple::CapabilitySet>::sse_encode(self.inheritable, serializer);
+        <crate::api::simple::CapabilitySet>::sse_encode(self.bounding, serializer);
+        <crate::api::simple::CapabilitySet>::sse_encode(self.ambient, serializer);
+        <Option<crate::api::simple::SeccompMode>>::sse_encode(self.seccomp, serializer);
+        <Option<u32>>::sse_encode(self.seccomp_filters, serializer);
+        <Option<bool>>::sse_encode(self.no_new_privs, serializer);
+        <Option<String>>::sse_encode(self.lsm, serializer);
+        <Option<String>>::sse_encode(self.lsm_label, serializer);
     }
 }
 
@@ -773,6 +6060,11 @@ impl SseEncode for crate::api::simple::SystemResourceInfo {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <f64>::sse_encode(self.cpu_usage, serializer);
         <Vec<f64>>::sse_encode(self.cpu_per_core, serializer);
+        <crate::api::simple::CpuTimesBreakdown>::sse_encode(self.cpu_times, serializer);
+        <Vec<crate::api::simple::CpuTimesBreakdown>>::sse_encode(
+            self.cpu_times_per_core,
+            serializer,
+        );
         <u64>::sse_encode(self.memory_total, serializer);
         <u64>::sse_encode(self.memory_used, serializer);
         <u64>::sse_encode(self.memory_available, serializer);
@@ -784,6 +6076,74 @@ impl SseEncode for crate::api::simple::SystemResourceInfo {
     }
 }
 
+impl SseEncode for crate::api::simple::TerminationOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::TerminationOutcome::Terminated => 0,
+                crate::api::simple::TerminationOutcome::Killed => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::ThreadInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.tid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::simple::ProcessState>::sse_encode(self.state, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <f64>::sse_encode(self.cpu_usage, serializer);
+        <u64>::sse_encode(self.user_time_ms, serializer);
+        <u64>::sse_encode(self.system_time_ms, serializer);
+        <Option<u32>>::sse_encode(self.last_cpu, serializer);
+        <i32>::sse_encode(self.priority, serializer);
+        <i32>::sse_encode(self.nice, serializer);
+        <u64>::sse_encode(self.voluntary_context_switches, serializer);
+        <u64>::sse_encode(self.involuntary_context_switches, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ThreadScheduling {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.tid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Vec<u32>>::sse_encode(self.affinity, serializer);
+        <crate::api::simple::SchedulerInfo>::sse_encode(self.scheduler, serializer);
+        <Option<u32>>::sse_encode(self.last_cpu, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::TransportProtocol {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::TransportProtocol::Tcp => 0,
+                crate::api::simple::TransportProtocol::Udp => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -810,13 +6170,6 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
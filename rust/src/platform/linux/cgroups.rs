@@ -0,0 +1,314 @@
+// cgroup v2 membership and per-cgroup resource accounting.
+use crate::api::simple::{
+    CgroupCpuStat, CgroupInfo, CgroupIoStat, CgroupKind, CgroupMemoryEvents, CpuUsageMode, Pressure, PressureLine,
+};
+use super::process;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+const CGROUP_MOUNT: &str = "/sys/fs/cgroup";
+// Hybrid setups keep the v1 controllers at /sys/fs/cgroup and mount the v2 hierarchy here.
+const HYBRID_UNIFIED: &str = "/sys/fs/cgroup/unified";
+
+struct CgroupCpuSample {
+    last_instant: Instant,
+    usage_usec: HashMap<String, u64>,
+}
+
+lazy_static::lazy_static! {
+    static ref CGROUP_CPU_CACHE: Mutex<Option<CgroupCpuSample>> = Mutex::new(None);
+}
+
+/// Mount point of the cgroup v2 hierarchy.
+pub fn cgroup_root() -> PathBuf {
+    let v2 = |dir: &str| Path::new(dir).join("cgroup.controllers").exists();
+    PathBuf::from(if !v2(CGROUP_MOUNT) && v2(HYBRID_UNIFIED) { HYBRID_UNIFIED } else { CGROUP_MOUNT })
+}
+
+/// The cgroup path in /proc/<pid>/cgroup: the v2 "0::" line, or on v1-only hosts the
+/// name=systemd hierarchy, which follows the same unit layout.
+pub fn parse_proc_cgroup(content: &str) -> Option<String> {
+    let mut systemd = None;
+    for line in content.lines() {
+        let mut fields = line.splitn(3, ':');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some("0"), Some(""), Some(path)) => return Some(path.to_string()),
+            (_, Some("name=systemd"), Some(path)) => systemd = Some(path.to_string()),
+            _ => {}
+        }
+    }
+    systemd
+}
+
+pub fn process_cgroup(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok().and_then(|s| parse_proc_cgroup(&s))
+}
+
+/// "key value" lines, as in cpu.stat and memory.events.
+fn parse_flat_keyed(content: &str) -> HashMap<&str, u64> {
+    content.lines().filter_map(|l| {
+        let (k, v) = l.split_once(' ')?;
+        Some((k, v.trim().parse().ok()?))
+    }).collect()
+}
+
+pub fn parse_cpu_stat(content: &str) -> CgroupCpuStat {
+    let v = parse_flat_keyed(content);
+    let get = |k: &str| v.get(k).copied().unwrap_or(0);
+    CgroupCpuStat {
+        usage_usec: get("usage_usec"),
+        user_usec: get("user_usec"),
+        system_usec: get("system_usec"),
+        nr_periods: get("nr_periods"),
+        nr_throttled: get("nr_throttled"),
+        throttled_usec: get("throttled_usec"),
+    }
+}
+
+pub fn parse_memory_events(content: &str) -> CgroupMemoryEvents {
+    let v = parse_flat_keyed(content);
+    let get = |k: &str| v.get(k).copied().unwrap_or(0);
+    CgroupMemoryEvents { low: get("low"), high: get("high"), max: get("max"), oom: get("oom"), oom_kill: get("oom_kill") }
+}
+
+/// One line per device: "8:0 rbytes=1459200 wbytes=314773504 rios=192 wios=353 dbytes=0 dios=0".
+pub fn parse_io_stat(content: &str) -> Vec<CgroupIoStat> {
+    content.lines().filter_map(|line| {
+        let mut fields = line.split_whitespace();
+        let mut stat = CgroupIoStat { device: fields.next()?.to_string(), ..Default::default() };
+        for (key, value) in fields.filter_map(|f| f.split_once('=')) {
+            let value = value.parse().unwrap_or(0);
+            match key {
+                "rbytes" => stat.read_bytes = value,
+                "wbytes" => stat.write_bytes = value,
+                "rios" => stat.read_ios = value,
+                "wios" => stat.write_ios = value,
+                "dbytes" => stat.discard_bytes = value,
+                "dios" => stat.discard_ios = value,
+                _ => {}
+            }
+        }
+        Some(stat)
+    }).collect()
+}
+
+/// A PSI file: "some avg10=0.00 avg60=0.00 avg300=0.00 total=0" and, except for
+/// cpu.pressure before Linux 5.13, a "full" line of the same form.
+pub fn parse_pressure(content: &str) -> Option<Pressure> {
+    let mut pressure = Pressure::default();
+    let mut found = false;
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let mut p = PressureLine::default();
+        for (key, value) in fields.filter_map(|f| f.split_once('=')) {
+            match key {
+                "avg10" => p.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => p.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => p.avg300 = value.parse().unwrap_or(0.0),
+                "total" => p.total_usec = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        match kind {
+            Some("some") => { pressure.some = p; found = true; }
+            Some("full") => pressure.full = Some(p),
+            _ => {}
+        }
+    }
+    if found { Some(pressure) } else { None }
+}
+
+fn kind_of(path: &str) -> CgroupKind {
+    if path == "/" { return CgroupKind::Root; }
+    if path.ends_with(".slice") { CgroupKind::Slice }
+    else if path.ends_with(".service") { CgroupKind::Service }
+    else if path.ends_with(".scope") { CgroupKind::Scope }
+    else { CgroupKind::Other }
+}
+
+fn read_cgroup(dir: &Path, path: String, parent: Option<String>) -> CgroupInfo {
+    let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+    let read_u64 = |file: &str| read(file).and_then(|s| s.trim().parse::<u64>().ok());
+    let io = read("io.stat").map(|s| parse_io_stat(&s)).unwrap_or_default();
+    CgroupInfo {
+        name: if path == "/" { path.clone() } else { path.rsplit('/').next().unwrap_or_default().to_string() },
+        kind: kind_of(&path),
+        path,
+        parent,
+        process_count: read("cgroup.procs").map(|s| s.lines().filter(|l| !l.trim().is_empty()).count() as u32).unwrap_or(0),
+        cpu: read("cpu.stat").map(|s| parse_cpu_stat(&s)),
+        cpu_usage: 0.0,
+        memory_current: read_u64("memory.current"),
+        // "max" means no limit
+        memory_max: read_u64("memory.max"),
+        memory_events: read("memory.events").map(|s| parse_memory_events(&s)),
+        io_read_bytes: io.iter().map(|d| d.read_bytes).sum(),
+        io_write_bytes: io.iter().map(|d| d.write_bytes).sum(),
+        io,
+        pids_current: read_u64("pids.current"),
+        cpu_pressure: read("cpu.pressure").and_then(|s| parse_pressure(&s)),
+        memory_pressure: read("memory.pressure").and_then(|s| parse_pressure(&s)),
+        io_pressure: read("io.pressure").and_then(|s| parse_pressure(&s)),
+    }
+}
+
+/// Every cgroup under `root`, parents before their children and siblings by name.
+/// Paths are relative to `root` in the form of /proc/<pid>/cgroup, so the root is "/".
+/// `root` can be any directory laid out like cgroupfs, such as a test fixture.
+pub fn read_cgroup_tree(root: &Path) -> Vec<CgroupInfo> {
+    let mut out = Vec::new();
+    let mut stack = vec![(root.to_path_buf(), "/".to_string(), None::<String>)];
+    while let Some((dir, path, parent)) = stack.pop() {
+        let mut children: Vec<String> = fs::read_dir(&dir)
+            .map(|entries| entries.flatten()
+                .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+                .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+                .collect())
+            .unwrap_or_default();
+        // Reversed so that popping visits them in name order
+        children.sort_unstable_by(|a, b| b.cmp(a));
+        for child in children {
+            let child_path = if path == "/" { format!("/{}", child) } else { format!("{}/{}", path, child) };
+            stack.push((dir.join(&child), child_path, Some(path.clone())));
+        }
+        out.push(read_cgroup(&dir, path, parent));
+    }
+    out
+}
+
+/// The cgroup tree of this machine. `cpu_usage` covers the interval since the previous
+/// call and follows the process list's `CpuUsageMode`; it is 0 on the first call.
+pub fn get_cgroup_tree_impl() -> Vec<CgroupInfo> {
+    let mut tree = read_cgroup_tree(&cgroup_root());
+    let now = Instant::now();
+    let cores = num_cpus::get().max(1) as f64;
+    let mode = process::cpu_usage_mode();
+    let mut cache = CGROUP_CPU_CACHE.lock().unwrap();
+    let elapsed_usec = cache.as_ref().map(|c| now.duration_since(c.last_instant).as_micros() as f64).unwrap_or(0.0);
+    for cg in tree.iter_mut() {
+        let (usage, last) = match (&cg.cpu, cache.as_ref().and_then(|c| c.usage_usec.get(&cg.path))) {
+            (Some(cpu), Some(last)) => (cpu.usage_usec, *last),
+            _ => continue,
+        };
+        if elapsed_usec <= 0.0 { continue; }
+        let machine = (usage.saturating_sub(last) as f64 / elapsed_usec / cores * 100.0).clamp(0.0, 100.0);
+        cg.cpu_usage = match mode {
+            CpuUsageMode::Machine => machine,
+            CpuUsageMode::SingleCore => machine * cores,
+        };
+    }
+    *cache = Some(CgroupCpuSample {
+        last_instant: now,
+        usage_usec: tree.iter().filter_map(|cg| Some((cg.path.clone(), cg.cpu.as_ref()?.usage_usec))).collect(),
+    });
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A throwaway cgroupfs-like tree under the temp dir, removed on drop.
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("fltask-cgroup-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            Fixture(root)
+        }
+
+        fn write(&self, file: &str, content: &str) {
+            let path = self.0.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) { let _ = fs::remove_dir_all(&self.0); }
+    }
+
+    fn find<'a>(tree: &'a [CgroupInfo], path: &str) -> &'a CgroupInfo {
+        tree.iter().find(|c| c.path == path).unwrap_or_else(|| panic!("no cgroup {}", path))
+    }
+
+    #[test]
+    fn reads_cpu_stat_and_throttling() {
+        let fx = Fixture::new("cpu");
+        fx.write("system.slice/cpu.stat", "usage_usec 5000\nuser_usec 3000\nsystem_usec 2000\nnr_periods 10\nnr_throttled 4\nthrottled_usec 1234\n");
+        let tree = read_cgroup_tree(&fx.0);
+        let cpu = find(&tree, "/system.slice").cpu.clone().unwrap();
+        assert_eq!((cpu.usage_usec, cpu.user_usec, cpu.system_usec), (5000, 3000, 2000));
+        assert_eq!((cpu.nr_periods, cpu.nr_throttled, cpu.throttled_usec), (10, 4, 1234));
+        assert!(find(&tree, "/").cpu.is_none());
+    }
+
+    #[test]
+    fn unlimited_memory_max_is_none() {
+        let fx = Fixture::new("memory");
+        fx.write("a.slice/memory.current", "4096\n");
+        fx.write("a.slice/memory.max", "max\n");
+        fx.write("b.slice/memory.max", "1048576\n");
+        fx.write("b.slice/memory.events", "low 0\nhigh 1\nmax 2\noom 3\noom_kill 4\n");
+        let tree = read_cgroup_tree(&fx.0);
+        let a = find(&tree, "/a.slice");
+        assert_eq!((a.memory_current, a.memory_max), (Some(4096), None));
+        let b = find(&tree, "/b.slice");
+        assert_eq!(b.memory_max, Some(1048576));
+        assert_eq!(b.memory_events.as_ref().map(|e| (e.max, e.oom_kill)), Some((2, 4)));
+    }
+
+    #[test]
+    fn pressure_without_full_line() {
+        let fx = Fixture::new("psi");
+        fx.write("cpu.pressure", "some avg10=1.50 avg60=0.25 avg300=0.00 total=987\n");
+        fx.write("memory.pressure", "some avg10=0.00 avg60=0.00 avg300=0.00 total=1\nfull avg10=2.00 avg60=0.00 avg300=0.00 total=2\n");
+        let tree = read_cgroup_tree(&fx.0);
+        let cpu = find(&tree, "/").cpu_pressure.clone().unwrap();
+        assert_eq!((cpu.some.avg10, cpu.some.avg60, cpu.some.total_usec), (1.5, 0.25, 987));
+        assert!(cpu.full.is_none());
+        let memory = find(&tree, "/").memory_pressure.clone().unwrap();
+        assert_eq!(memory.full.map(|f| (f.avg10, f.total_usec)), Some((2.0, 2)));
+        assert!(find(&tree, "/").io_pressure.is_none());
+    }
+
+    #[test]
+    fn io_stat_is_summed_over_devices() {
+        let fx = Fixture::new("io");
+        fx.write("io.stat", "8:0 rbytes=100 wbytes=200 rios=1 wios=2 dbytes=0 dios=0\n259:0 rbytes=1000 wbytes=2000 rios=10 wios=20 dbytes=5 dios=1\n");
+        let tree = read_cgroup_tree(&fx.0);
+        let root = find(&tree, "/");
+        assert_eq!(root.io.len(), 2);
+        assert_eq!((root.io[1].device.as_str(), root.io[1].discard_bytes), ("259:0", 5));
+        assert_eq!((root.io_read_bytes, root.io_write_bytes), (1100, 2200));
+    }
+
+    #[test]
+    fn children_are_nested_under_their_parents() {
+        let fx = Fixture::new("tree");
+        fx.write("system.slice/ssh.service/cgroup.procs", "12\n34\n");
+        fx.write("system.slice/docker-abc.scope/pids.current", "3\n");
+        fx.write("user.slice/cgroup.procs", "");
+        let tree = read_cgroup_tree(&fx.0);
+        let paths: Vec<&str> = tree.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["/", "/system.slice", "/system.slice/docker-abc.scope", "/system.slice/ssh.service", "/user.slice"]);
+        let ssh = find(&tree, "/system.slice/ssh.service");
+        assert_eq!((ssh.name.as_str(), ssh.parent.as_deref(), ssh.kind, ssh.process_count), ("ssh.service", Some("/system.slice"), CgroupKind::Service, 2));
+        assert_eq!(find(&tree, "/system.slice/docker-abc.scope").kind, CgroupKind::Scope);
+        assert_eq!(find(&tree, "/system.slice").parent.as_deref(), Some("/"));
+        assert_eq!(find(&tree, "/").kind, CgroupKind::Root);
+    }
+
+    #[test]
+    fn proc_cgroup_prefers_the_unified_line() {
+        assert_eq!(parse_proc_cgroup("12:cpu:/\n0::/user.slice/session-1.scope\n").as_deref(), Some("/user.slice/session-1.scope"));
+        assert_eq!(parse_proc_cgroup("9:name=systemd:/system.slice/cron.service\n1:cpu:/\n").as_deref(), Some("/system.slice/cron.service"));
+        assert_eq!(parse_proc_cgroup("1:cpu:/\n"), None);
+    }
+}
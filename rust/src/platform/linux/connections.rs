@@ -0,0 +1,175 @@
+// TCP/UDP sockets from /proc/net/{tcp,tcp6,udp,udp6}, mapped to their owning processes.
+use crate::api::simple::{ConnectionInfo, ConnectionState, TransportProtocol};
+use super::procfs;
+use std::collections::HashMap;
+use std::fs;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// One socket line of /proc/net/{tcp,udp}[6].
+#[derive(Debug, Clone)]
+pub struct SocketEntry {
+    pub local_address: String,
+    pub local_port: u16,
+    pub remote_address: String,
+    pub remote_port: u16,
+    pub state: u8,
+    pub send_queue: u64,
+    pub receive_queue: u64,
+    pub uid: u32,
+    pub inode: u64,
+}
+
+// The kernel prints addresses as 32-bit words in host byte order, so each word is
+// parsed as a number and turned back into its in-memory (network order) bytes.
+fn parse_address(hex: &str) -> Option<(String, u16)> {
+    let (addr, port) = hex.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let words: Vec<u32> = (0..addr.len() / 8)
+        .map(|i| u32::from_str_radix(&addr[i * 8..i * 8 + 8], 16))
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_ne_bytes()).collect();
+    let ip = match bytes.len() {
+        4 => Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]).to_string(),
+        16 => {
+            let ip = Ipv6Addr::from(<[u8; 16]>::try_from(bytes.as_slice()).ok()?);
+            // IPv4 clients of a dual-stack socket show up as ::ffff:a.b.c.d
+            ip.to_ipv4_mapped().map(|v4| v4.to_string()).unwrap_or_else(|| ip.to_string())
+        }
+        _ => return None,
+    };
+    Some((ip, port))
+}
+
+pub fn parse_net_sockets(content: &str) -> Vec<SocketEntry> {
+    let mut out = Vec::new();
+    for line in content.lines().skip(1) {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 10 { continue; }
+        let (local_address, local_port) = match parse_address(parts[1]) { Some(a) => a, None => continue };
+        let (remote_address, remote_port) = match parse_address(parts[2]) { Some(a) => a, None => continue };
+        let (tx, rx) = parts[4].split_once(':').unwrap_or(("0", "0"));
+        out.push(SocketEntry {
+            local_address,
+            local_port,
+            remote_address,
+            remote_port,
+            state: u8::from_str_radix(parts[3], 16).unwrap_or(0),
+            send_queue: u64::from_str_radix(tx, 16).unwrap_or(0),
+            receive_queue: u64::from_str_radix(rx, 16).unwrap_or(0),
+            uid: parts[7].parse().unwrap_or(0),
+            inode: parts[9].parse().unwrap_or(0),
+        });
+    }
+    out
+}
+
+// TCP_* states from include/net/tcp_states.h
+fn decode_state(state: u8) -> ConnectionState {
+    match state {
+        0x01 => ConnectionState::Established,
+        0x02 => ConnectionState::SynSent,
+        0x03 => ConnectionState::SynReceived,
+        0x04 => ConnectionState::FinWait1,
+        0x05 => ConnectionState::FinWait2,
+        0x06 => ConnectionState::TimeWait,
+        0x07 => ConnectionState::Closed,
+        0x08 => ConnectionState::CloseWait,
+        0x09 => ConnectionState::LastAck,
+        0x0A => ConnectionState::Listen,
+        0x0B => ConnectionState::Closing,
+        0x0C => ConnectionState::SynReceived, // TCP_NEW_SYN_RECV
+        _ => ConnectionState::Unknown,
+    }
+}
+
+/// Map socket inodes to the PID holding them, from the socket:[inode] links under
+/// /proc/<pid>/fd. Processes we may not inspect are skipped. A socket shared by
+/// several processes (e.g. after fork) is attributed to the lowest PID.
+pub fn socket_inode_owners() -> HashMap<u64, u32> {
+    let mut owners = HashMap::new();
+    let mut pids = procfs::list_pids();
+    pids.sort_unstable();
+    for pid in pids {
+        let entries = match fs::read_dir(format!("/proc/{}/fd", pid)) { Ok(e) => e, Err(_) => continue };
+        for entry in entries.flatten() {
+            let target = match fs::read_link(entry.path()) { Ok(t) => t, Err(_) => continue };
+            let target = target.to_string_lossy();
+            if let Some(inode) = target.strip_prefix("socket:[").and_then(|s| s.strip_suffix(']')) {
+                if let Ok(inode) = inode.parse::<u64>() { owners.entry(inode).or_insert(pid); }
+            }
+        }
+    }
+    owners
+}
+
+pub fn get_connections_impl() -> Vec<ConnectionInfo> {
+    let owners = socket_inode_owners();
+    let tables = [
+        ("/proc/net/tcp", TransportProtocol::Tcp, false),
+        ("/proc/net/tcp6", TransportProtocol::Tcp, true),
+        ("/proc/net/udp", TransportProtocol::Udp, false),
+        ("/proc/net/udp6", TransportProtocol::Udp, true),
+    ];
+    let mut out = Vec::new();
+    for (path, protocol, ipv6) in tables {
+        let content = match fs::read_to_string(path) { Ok(c) => c, Err(_) => continue };
+        for s in parse_net_sockets(&content) {
+            let state = match protocol {
+                TransportProtocol::Tcp => decode_state(s.state),
+                // UDP reuses TCP_ESTABLISHED for connected sockets and TCP_CLOSE for unconnected ones.
+                TransportProtocol::Udp if s.state == 0x01 => ConnectionState::Established,
+                TransportProtocol::Udp => ConnectionState::Unconnected,
+            };
+            out.push(ConnectionInfo {
+                protocol,
+                ipv6,
+                local_address: s.local_address,
+                local_port: s.local_port,
+                remote_address: s.remote_address,
+                remote_port: s.remote_port,
+                state,
+                send_queue: s.send_queue,
+                receive_queue: s.receive_queue,
+                // inode 0 means the socket is no longer attached to a file (e.g. TIME_WAIT)
+                pid: if s.inode == 0 { None } else { owners.get(&s.inode).copied() },
+                uid: s.uid,
+                inode: s.inode,
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lines as a little-endian kernel prints them.
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn addresses_are_decoded_from_host_order_words() {
+        assert_eq!(parse_address("0100007F:0016"), Some(("127.0.0.1".to_string(), 22)));
+        assert_eq!(parse_address("0A01A8C0:1F90"), Some(("192.168.1.10".to_string(), 8080)));
+        assert_eq!(parse_address("00000000000000000000000001000000:0035"), Some(("::1".to_string(), 53)));
+        assert_eq!(parse_address("B80D0120000000000000000001000000:01BB"), Some(("2001:db8::1".to_string(), 443)));
+        assert_eq!(parse_address("0000000000000000FFFF00000A01A8C0:C350"), Some(("192.168.1.10".to_string(), 50000)));
+        assert_eq!(parse_address("0100007F"), None);
+        assert_eq!(parse_address("0100:0016"), None);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn socket_lines_after_the_header() {
+        let content = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+            0: 0100007F:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 12345 1 0000000000000000 100 0 0 10 0\n   \
+            1: 0A01A8C0:A2F4 0101A8C0:01BB 01 0000001C:00000004 01:00000014 00000000  1000        0 67890 2 0000000000000000 20 4 30 10 -1\n";
+        let sockets = parse_net_sockets(content);
+        assert_eq!(sockets.len(), 2);
+        assert_eq!((sockets[0].local_address.as_str(), sockets[0].local_port, sockets[0].state), ("127.0.0.1", 22, 0x0A));
+        let s = &sockets[1];
+        assert_eq!((s.remote_address.as_str(), s.remote_port), ("192.168.1.1", 443));
+        assert_eq!((s.send_queue, s.receive_queue), (0x1C, 4));
+        assert_eq!((s.uid, s.inode), (1000, 67890));
+    }
+}
@@ -0,0 +1,78 @@
+// Executable, directories, argv and environment of a process from /proc/<pid>.
+use crate::api::simple::{EnvironmentVariable, ProcessControlError, ProcessDetailError, ProcessDetailField, ProcessDetails};
+use super::{procfs, signal};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Split a NUL-terminated /proc file (cmdline, environ) into its strings. Empty strings
+/// are kept, so `prog "" x` stays three arguments.
+pub fn split_nul(content: &[u8]) -> Vec<String> {
+    if content.is_empty() { return Vec::new(); }
+    let content = content.strip_suffix(&[0]).unwrap_or(content);
+    content.split(|b| *b == 0).map(|s| String::from_utf8_lossy(s).into_owned()).collect()
+}
+
+/// "NAME=value" entries of /proc/<pid>/environ. An entry without '=' is kept as a name
+/// with an empty value.
+pub fn parse_environ(content: &[u8]) -> Vec<EnvironmentVariable> {
+    split_nul(content)
+        .into_iter()
+        .map(|e| match e.split_once('=') {
+            Some((name, value)) => EnvironmentVariable { name: name.to_string(), value: value.to_string() },
+            None => EnvironmentVariable { name: e, value: String::new() },
+        })
+        .collect()
+}
+
+// Kernel threads have no exe, cwd or root: those links are missing rather than unreadable.
+fn read_link(pid: u32, name: &str) -> io::Result<Option<String>> {
+    match fs::read_link(format!("/proc/{}/{}", pid, name)) {
+        Ok(t) => Ok(Some(t.to_string_lossy().into_owned())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Keep the value of a field, or note why it could not be read.
+fn record<T>(pid: u32, errors: &mut Vec<ProcessDetailError>, field: ProcessDetailField, result: io::Result<T>) -> Option<T> {
+    result.map_err(|e| errors.push(ProcessDetailError { field, error: procfs::read_error(pid, &e) })).ok()
+}
+
+pub fn get_process_details_impl(pid: u32) -> Result<ProcessDetails, ProcessControlError> {
+    if !Path::new(&format!("/proc/{}", pid)).exists() {
+        return Err(signal::errno_error(pid, libc::ESRCH));
+    }
+    let mut errors = Vec::new();
+    let exe = record(pid, &mut errors, ProcessDetailField::Executable, read_link(pid, "exe")).flatten();
+    let exe_deleted = exe.as_deref().is_some_and(|e| e.ends_with(procfs::DELETED_SUFFIX));
+    let exe = exe.map(|e| e.strip_suffix(procfs::DELETED_SUFFIX).map(String::from).unwrap_or(e));
+    let cwd = record(pid, &mut errors, ProcessDetailField::WorkingDirectory, read_link(pid, "cwd")).flatten();
+    let root = record(pid, &mut errors, ProcessDetailField::RootDirectory, read_link(pid, "root")).flatten();
+    let argv = record(pid, &mut errors, ProcessDetailField::CommandLine, fs::read(format!("/proc/{}/cmdline", pid)))
+        .map(|c| split_nul(&c));
+    let environment = record(pid, &mut errors, ProcessDetailField::Environment, fs::read(format!("/proc/{}/environ", pid)))
+        .map(|c| parse_environ(&c));
+    Ok(ProcessDetails { pid, exe, exe_deleted, cwd, root, argv, environment, errors })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_nul_keeps_empty_arguments() {
+        assert_eq!(split_nul(b"prog\0\0x\0"), ["prog", "", "x"]);
+        assert_eq!(split_nul(b"prog\0"), ["prog"]);
+        // A process that rewrote its cmdline may leave the final NUL out.
+        assert_eq!(split_nul(b"nginx: worker"), ["nginx: worker"]);
+        assert!(split_nul(b"").is_empty());
+    }
+
+    #[test]
+    fn environ_entries_split_at_the_first_equals() {
+        let env = parse_environ(b"A=1\0B=x=y\0NOVALUE\0");
+        let pairs: Vec<(&str, &str)> = env.iter().map(|e| (e.name.as_str(), e.value.as_str())).collect();
+        assert_eq!(pairs, [("A", "1"), ("B", "x=y"), ("NOVALUE", "")]);
+    }
+}
@@ -0,0 +1,128 @@
+// Killing a process together with all of its descendants.
+use crate::api::simple::{
+    KillOutcome, KillTreeStrategy, ProcessControlError, ProcessControlErrorKind, ProcessKillResult, ProcessSignal,
+};
+use super::{procfs, signal};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::time::Instant;
+
+// Forks racing with the scan are caught by re-scanning; give up on a tree that keeps growing.
+const MAX_SCAN_ROUNDS: usize = 10;
+
+struct Member {
+    pid: u32,
+    ppid: u32,
+    pgrp: u32,
+    starttime: u64,
+    name: String,
+}
+
+fn member(pid: u32) -> Option<Member> {
+    let stat = procfs::read_pid_stat(pid)?;
+    let name = fs::read_to_string(format!("/proc/{}/comm", pid)).map(|s| s.trim_end().to_string()).unwrap_or_default();
+    Some(Member { pid, ppid: stat.ppid, pgrp: stat.pgrp, starttime: stat.starttime, name })
+}
+
+fn result(m: &Member, outcome: KillOutcome, error: Option<ProcessControlError>) -> ProcessKillResult {
+    ProcessKillResult { pid: m.pid, parent_pid: Some(m.ppid).filter(|p| *p != 0), name: m.name.clone(), outcome, error }
+}
+
+// Stop the root, then keep stopping descendants until a scan finds nothing new. Stopped
+// processes cannot fork, so the final list is a consistent snapshot of the tree. Members
+// are listed parents first.
+fn freeze_tree(root: Member) -> Vec<Member> {
+    let _ = signal::raw_signal(root.pid, ProcessSignal::Stop);
+    let mut seen: HashSet<u32> = HashSet::from([root.pid]);
+    let mut members = vec![root];
+    for _ in 0..MAX_SCAN_ROUNDS {
+        let new: Vec<u32> = procfs::descendants(members[0].pid).into_iter().filter(|p| !seen.contains(p)).collect();
+        if new.is_empty() { break; }
+        for pid in new {
+            seen.insert(pid);
+            let _ = signal::raw_signal(pid, ProcessSignal::Stop);
+            if let Some(m) = member(pid) { members.push(m); }
+        }
+    }
+    members
+}
+
+// Every process currently in process group `pgrp`.
+fn group_members(pgrp: u32) -> Vec<u32> {
+    procfs::list_pids().into_iter().filter(|p| procfs::read_pid_stat(*p).map(|st| st.pgrp == pgrp).unwrap_or(false)).collect()
+}
+
+fn is_alive(m: &Member) -> bool {
+    !signal::has_exited(m.pid, m.starttime)
+}
+
+pub fn kill_process_tree_impl(root_pid: u32, strategy: KillTreeStrategy) -> Result<Vec<ProcessKillResult>, ProcessControlError> {
+    signal::check_target(root_pid)?;
+    if root_pid == 1 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::ProtectedProcess, 0, "refusing to kill the tree of init"));
+    }
+    let me = std::process::id();
+    if root_pid == me || procfs::descendants(root_pid).contains(&me) {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("process {} is an ancestor of this process", root_pid)));
+    }
+    let root = member(root_pid).ok_or_else(|| {
+        ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", root_pid))
+    })?;
+    let members = freeze_tree(root);
+
+    // Leaves first: order by depth, deepest first. Parents precede children in `members`.
+    let mut depth: HashMap<u32, usize> = HashMap::new();
+    for m in &members {
+        let d = depth.get(&m.ppid).map_or(0, |d| d + 1);
+        depth.insert(m.pid, d);
+    }
+    let mut order: Vec<&Member> = members.iter().collect();
+    order.sort_by_key(|m| std::cmp::Reverse(depth[&m.pid]));
+
+    let alive: HashSet<u32> = members.iter().filter(|m| is_alive(m)).map(|m| m.pid).collect();
+    // A group is killed as a whole only when its leader is in the tree and it has no members
+    // outside the tree: the root's group is often the caller's shell, and a pipeline shares
+    // one group across siblings. Other groups fall back to killing members one by one.
+    let mut killed_groups = HashSet::new();
+    if strategy == KillTreeStrategy::ProcessGroup {
+        let leaders: HashSet<u32> = members.iter().filter(|m| m.pgrp == m.pid && alive.contains(&m.pid)).map(|m| m.pid).collect();
+        for pgrp in leaders {
+            if !group_members(pgrp).iter().all(|p| alive.contains(p)) { continue; }
+            if unsafe { libc::kill(-(pgrp as i32), libc::SIGKILL) } == 0 { killed_groups.insert(pgrp); }
+        }
+    }
+
+    let mut results = Vec::with_capacity(order.len());
+    let mut pending = Vec::new();
+    for m in order {
+        if !alive.contains(&m.pid) {
+            results.push(result(m, KillOutcome::AlreadyExited, None));
+            continue;
+        }
+        if killed_groups.contains(&m.pgrp) {
+            pending.push(m);
+            continue;
+        }
+        match signal::raw_signal(m.pid, ProcessSignal::Kill) {
+            Ok(()) => pending.push(m),
+            Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => results.push(result(m, KillOutcome::AlreadyExited, None)),
+            Err(e) => {
+                // Do not leave a process we could stop but not kill hanging.
+                let _ = signal::raw_signal(m.pid, ProcessSignal::Continue);
+                results.push(result(m, KillOutcome::Failed, Some(e)));
+            }
+        }
+    }
+
+    let deadline = Instant::now() + signal::KILL_GRACE;
+    for m in pending {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if signal::wait_for_exit(m.pid, m.starttime, remaining) {
+            results.push(result(m, KillOutcome::Killed, None));
+        } else {
+            let err = ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after SIGKILL", m.pid));
+            results.push(result(m, KillOutcome::Failed, Some(err)));
+        }
+    }
+    Ok(results)
+}
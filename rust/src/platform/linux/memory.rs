@@ -0,0 +1,136 @@
+// Per-process memory accounting from /proc/<pid>/smaps_rollup.
+use crate::api::simple::{MemoryMetric, ProcessControlError, ProcessMemory};
+use super::procfs;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// smaps_rollup walks every mapping of the process under its mmap lock, so the
+// process list re-reads it at most this often per process.
+const ROLLUP_REFRESH: Duration = Duration::from_secs(5);
+
+struct RollupSample {
+    starttime: u64,
+    read_at: Instant,
+    memory: ProcessMemory,
+}
+
+struct MemorySampler {
+    metric: MemoryMetric,
+    samples: HashMap<u32, RollupSample>,
+}
+
+lazy_static::lazy_static! {
+    static ref MEMORY_CACHE: Mutex<MemorySampler> = Mutex::new(MemorySampler {
+        metric: MemoryMetric::Rss,
+        samples: HashMap::new(),
+    });
+}
+
+/// Add one "Key:   1234 kB" line of smaps or smaps_rollup to `m`. Lines of other
+/// keys, and the mapping headers of smaps, are ignored.
+pub fn add_smaps_line(m: &mut ProcessMemory, line: &str) {
+    let (key, rest) = match line.split_once(':') { Some(p) => p, None => return };
+    let kb: u64 = match rest.split_whitespace().next().and_then(|v| v.parse().ok()) { Some(v) => v, None => return };
+    let bytes = kb * 1024;
+    match key {
+        "Rss" => m.rss += bytes,
+        "Pss" => m.pss += bytes,
+        "Shared_Clean" => m.shared_clean += bytes,
+        "Shared_Dirty" => m.shared_dirty += bytes,
+        "Private_Clean" => m.private_clean += bytes,
+        "Private_Dirty" => m.private_dirty += bytes,
+        "Swap" => m.swap += bytes,
+        "SwapPss" => m.swap_pss += bytes,
+        "Locked" => m.locked += bytes,
+        "AnonHugePages" | "ShmemPmdMapped" | "FilePmdMapped" | "Shared_Hugetlb" | "Private_Hugetlb" => m.huge_pages += bytes,
+        _ => {}
+    }
+}
+
+/// Sum the counters of smaps_rollup, or of every mapping in smaps.
+pub fn parse_smaps(content: &str) -> ProcessMemory {
+    let mut m = ProcessMemory::default();
+    for line in content.lines() { add_smaps_line(&mut m, line); }
+    m.uss = m.private_clean + m.private_dirty;
+    m
+}
+
+/// smaps_rollup needs Linux 4.14; older kernels get the same totals from smaps.
+pub fn read_process_memory(pid: u32) -> std::io::Result<ProcessMemory> {
+    fs::read_to_string(format!("/proc/{}/smaps_rollup", pid))
+        .or_else(|_| fs::read_to_string(format!("/proc/{}/smaps", pid)))
+        .map(|s| parse_smaps(&s))
+}
+
+pub fn get_process_memory_impl(pid: u32) -> Result<ProcessMemory, ProcessControlError> {
+    read_process_memory(pid).map_err(|e| procfs::read_error(pid, &e))
+}
+
+/// The value for `ProcessInfo.memory_usage` under the selected metric, and the metric
+/// it actually is. Falls back to `rss` (VmRSS) for processes whose smaps we may not read.
+pub fn memory_usage(pid: u32, starttime: u64, rss: u64) -> (u64, MemoryMetric) {
+    let mut sampler = MEMORY_CACHE.lock().unwrap();
+    let metric = sampler.metric;
+    if metric == MemoryMetric::Rss { return (rss, MemoryMetric::Rss); }
+    let now = Instant::now();
+    let fresh = matches!(sampler.samples.get(&pid), Some(s) if s.starttime == starttime && now.duration_since(s.read_at) < ROLLUP_REFRESH);
+    if !fresh {
+        match read_process_memory(pid) {
+            Ok(memory) => { sampler.samples.insert(pid, RollupSample { starttime, read_at: now, memory }); }
+            Err(_) => {
+                sampler.samples.remove(&pid);
+                return (rss, MemoryMetric::Rss);
+            }
+        }
+    }
+    let memory = &sampler.samples[&pid].memory;
+    match metric {
+        MemoryMetric::Pss => (memory.pss, metric),
+        MemoryMetric::Uss => (memory.uss, metric),
+        MemoryMetric::Rss => (rss, metric),
+    }
+}
+
+pub fn prune_memory_samples(live: &HashSet<u32>) {
+    MEMORY_CACHE.lock().unwrap().samples.retain(|pid, _| live.contains(pid));
+}
+
+pub fn set_memory_metric_impl(metric: MemoryMetric) {
+    let mut sampler = MEMORY_CACHE.lock().unwrap();
+    sampler.metric = metric;
+    if metric == MemoryMetric::Rss { sampler.samples.clear(); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollup_counts_pss_once_and_uss_as_private_pages() {
+        let content = "55d0c8a4e000-7ffd5e1f7000 ---p 00000000 00:00 0                          [rollup]\n\
+                       Rss:               13780 kB\n\
+                       Pss:                6221 kB\n\
+                       Pss_Dirty:          3100 kB\n\
+                       Pss_Anon:           3052 kB\n\
+                       Pss_File:           3169 kB\n\
+                       Pss_Shmem:             0 kB\n\
+                       Shared_Clean:       8040 kB\n\
+                       Shared_Dirty:        120 kB\n\
+                       Private_Clean:       572 kB\n\
+                       Private_Dirty:      5048 kB\n\
+                       Referenced:        13780 kB\n\
+                       Anonymous:          3052 kB\n\
+                       AnonHugePages:      2048 kB\n\
+                       Swap:                 64 kB\n\
+                       SwapPss:              32 kB\n\
+                       Locked:                0 kB\n";
+        let m = parse_smaps(content);
+        assert_eq!(m.rss, 13780 * 1024);
+        assert_eq!(m.pss, 6221 * 1024);
+        assert_eq!((m.private_clean, m.private_dirty), (572 * 1024, 5048 * 1024));
+        assert_eq!(m.uss, (572 + 5048) * 1024);
+        assert_eq!((m.swap, m.swap_pss, m.huge_pages), (64 * 1024, 32 * 1024, 2048 * 1024));
+    }
+}
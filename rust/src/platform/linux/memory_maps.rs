@@ -0,0 +1,114 @@
+// Memory mappings of a process from /proc/<pid>/smaps, like pmap -X.
+use crate::api::simple::{MappedFileSummary, MemoryMaps, MemoryRegion, ProcessControlError, ProcessMemory};
+use super::{memory, procfs};
+use std::collections::HashMap;
+use std::fs;
+
+fn region(map: procfs::MapsLine, counters: &ProcessMemory) -> MemoryRegion {
+    let deleted = map.inode != 0 && map.pathname.ends_with(procfs::DELETED_SUFFIX);
+    let path = match map.pathname.as_str() {
+        "" => "[anon]".to_string(),
+        p => p.strip_suffix(procfs::DELETED_SUFFIX).filter(|_| deleted).unwrap_or(p).to_string(),
+    };
+    MemoryRegion {
+        start: map.start,
+        end: map.end,
+        size: map.end - map.start,
+        permissions: map.perms,
+        offset: map.offset,
+        device: format!("{:02x}:{:02x}", map.dev_major, map.dev_minor),
+        inode: map.inode,
+        path,
+        deleted,
+        rss: counters.rss,
+        pss: counters.pss,
+        private: counters.private_clean + counters.private_dirty,
+        swap: counters.swap,
+    }
+}
+
+/// Split smaps into regions; each header line is followed by the counters of that mapping.
+pub fn parse_smaps_regions(content: &str) -> Vec<MemoryRegion> {
+    let mut out = Vec::new();
+    let mut current: Option<(procfs::MapsLine, ProcessMemory)> = None;
+    for line in content.lines() {
+        if let Some(map) = procfs::parse_maps_line(line) {
+            if let Some((m, c)) = current.take() { out.push(region(m, &c)); }
+            current = Some((map, ProcessMemory::default()));
+        } else if let Some((_, c)) = current.as_mut() {
+            memory::add_smaps_line(c, line);
+        }
+    }
+    if let Some((m, c)) = current { out.push(region(m, &c)); }
+    out
+}
+
+/// Totals per path, largest PSS first. Anonymous regions are pooled under "[anon]".
+pub fn summarize_by_file(regions: &[MemoryRegion]) -> Vec<MappedFileSummary> {
+    let mut by_path: HashMap<&str, MappedFileSummary> = HashMap::new();
+    for r in regions {
+        let s = by_path.entry(&r.path).or_insert_with(|| MappedFileSummary { path: r.path.clone(), ..Default::default() });
+        s.regions += 1;
+        s.size += r.size;
+        s.rss += r.rss;
+        s.pss += r.pss;
+        s.private += r.private;
+        s.swap += r.swap;
+    }
+    let mut out: Vec<MappedFileSummary> = by_path.into_values().collect();
+    out.sort_by(|a, b| b.pss.cmp(&a.pss).then_with(|| a.path.cmp(&b.path)));
+    out
+}
+
+pub fn get_memory_maps_impl(pid: u32) -> Result<MemoryMaps, ProcessControlError> {
+    let content = fs::read_to_string(format!("/proc/{}/smaps", pid)).map_err(|e| procfs::read_error(pid, &e))?;
+    let regions = parse_smaps_regions(&content);
+    let files = summarize_by_file(&regions);
+    Ok(MemoryMaps { regions, files })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMAPS: &str = "7f1a00000000-7f1a00028000 r--p 00000000 08:01 1835 /usr/lib/libc.so.6\n\
+                         Rss:                 160 kB\n\
+                         Pss:                  40 kB\n\
+                         Private_Clean:         8 kB\n\
+                         7f1a00028000-7f1a001bd000 r-xp 00028000 08:01 1835 /usr/lib/libc.so.6\n\
+                         Rss:                 900 kB\n\
+                         Pss:                 100 kB\n\
+                         Private_Dirty:         4 kB\n\
+                         7f1a00200000-7f1a00300000 rw-p 00000000 00:00 0 \n\
+                         Rss:                 512 kB\n\
+                         Pss:                 512 kB\n\
+                         Private_Dirty:       512 kB\n\
+                         Swap:                 16 kB\n\
+                         7f1a00400000-7f1a00401000 r--s 00000000 00:1a 4242 /dev/shm/cache (deleted)\n\
+                         Rss:                   4 kB\n\
+                         Pss:                   2 kB\n";
+
+    #[test]
+    fn regions_keep_their_own_counters() {
+        let regions = parse_smaps_regions(SMAPS);
+        assert_eq!(regions.len(), 4);
+        assert_eq!((regions[1].offset, regions[1].permissions.as_str()), (0x28000, "r-xp"));
+        assert_eq!((regions[1].rss, regions[1].pss, regions[1].private), (900 * 1024, 100 * 1024, 4 * 1024));
+        assert_eq!((regions[2].path.as_str(), regions[2].device.as_str()), ("[anon]", "00:00"));
+        assert_eq!(regions[2].swap, 16 * 1024);
+        assert_eq!(regions[3].path, "/dev/shm/cache");
+        assert!(regions[3].deleted && !regions[0].deleted);
+    }
+
+    #[test]
+    fn files_are_summed_across_their_regions() {
+        let files = summarize_by_file(&parse_smaps_regions(SMAPS));
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["[anon]", "/usr/lib/libc.so.6", "/dev/shm/cache"]);
+        let libc = &files[1];
+        assert_eq!(libc.regions, 2);
+        assert_eq!(libc.size, 0x1bd000);
+        assert_eq!((libc.rss, libc.pss, libc.private), (1060 * 1024, 140 * 1024, 12 * 1024));
+        assert_eq!((files[2].regions, files[2].pss), (1, 2 * 1024));
+    }
+}
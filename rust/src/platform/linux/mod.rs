@@ -1,7 +1,48 @@
+mod cgroups;
+mod connections;
+mod details;
+mod disk_io;
+mod kill_tree;
+mod memory;
+mod memory_maps;
+mod mounts;
+mod net_usage;
+mod network;
+mod open_files;
+mod path_usage;
+mod priority;
 mod process;
+mod procfs;
+mod sched;
+mod security;
+mod signal;
+mod suspend;
 mod system_info;
 mod system_resources;
+mod threads;
+mod users;
 
-pub use process::{get_processes_impl, kill_process_impl};
+pub use cgroups::get_cgroup_tree_impl;
+pub use connections::get_connections_impl;
+pub use details::get_process_details_impl;
+pub use disk_io::get_disk_activity_impl;
+pub use kill_tree::kill_process_tree_impl;
+pub use memory::{get_process_memory_impl, set_memory_metric_impl};
+pub use memory_maps::get_memory_maps_impl;
+pub use net_usage::get_process_network_usage_impl;
+pub use network::get_network_interfaces_impl;
+pub use open_files::get_open_files_impl;
+pub use path_usage::find_processes_using_impl;
+pub use priority::{get_priority_impl, set_io_priority_impl, set_priority_impl};
+pub use process::{get_processes_impl, set_cpu_usage_mode_impl};
+pub use sched::{
+    get_cpu_affinity_impl, get_scheduler_impl, get_thread_scheduling_impl, set_cpu_affinity_impl,
+    set_scheduler_impl,
+};
+pub use security::get_security_context_impl;
+pub use signal::{kill_process_impl, send_signal_impl, terminate_gracefully_impl};
+pub use suspend::{resume_process_impl, suspend_process_impl};
 pub use system_info::get_system_info_impl;
-pub use system_resources::get_system_resources_impl;
+pub use system_resources::{get_disks_impl, get_system_resources_impl};
+pub use threads::get_threads_impl;
+pub use users::get_current_user_impl;
@@ -0,0 +1,175 @@
+// Mounted filesystems from /proc/self/mountinfo.
+use crate::api::simple::{DiskInfo, MountFilter};
+use nix::sys::statvfs;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+// Filesystems with no backing storage of their own.
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc", "sysfs", "cgroup", "cgroup2", "devpts", "devtmpfs", "ramfs", "securityfs", "debugfs",
+    "tracefs", "pstore", "bpf", "configfs", "fusectl", "mqueue", "hugetlbfs", "autofs", "binfmt_misc",
+    "rpc_pipefs", "nsfs", "efivarfs", "selinuxfs", "fuse.gvfsd-fuse", "fuse.portal", "nfsd", "overlay",
+];
+
+/// One line of /proc/<pid>/mountinfo (see proc(5)).
+#[derive(Debug, Clone)]
+pub struct MountEntry {
+    /// "major:minor" of the backing device
+    pub dev: String,
+    /// Path inside the filesystem that is mounted here; not "/" for bind mounts of a subtree
+    pub root: String,
+    pub mount_point: String,
+    pub mount_options: Vec<String>,
+    pub fs_type: String,
+    pub source: String,
+    pub super_options: Vec<String>,
+}
+
+impl MountEntry {
+    pub fn read_only(&self) -> bool {
+        self.mount_options.iter().chain(&self.super_options).any(|o| o == "ro")
+    }
+
+    pub fn is_pseudo(&self) -> bool {
+        // The root overlay of a container is its real disk; other overlays are image layers.
+        if self.fs_type == "overlay" { return self.mount_point != "/"; }
+        PSEUDO_FS_TYPES.contains(&self.fs_type.as_str())
+    }
+}
+
+// Paths in mountinfo escape space, tab, newline and backslash as \ooo octal.
+fn unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 3 < bytes.len() && bytes[i + 1..i + 4].iter().all(|b| (b'0'..=b'7').contains(b)) {
+            let code = (bytes[i + 1] - b'0') * 64 + (bytes[i + 2] - b'0') * 8 + (bytes[i + 3] - b'0');
+            out.push(code);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+pub fn parse_mountinfo(content: &str) -> Vec<MountEntry> {
+    let mut mounts = Vec::new();
+    for line in content.lines() {
+        // The optional fields before " - " vary in number, so split there first.
+        let (left, right) = match line.split_once(" - ") { Some(p) => p, None => continue };
+        let l: Vec<&str> = left.split_whitespace().collect();
+        let r: Vec<&str> = right.split_whitespace().collect();
+        if l.len() < 6 || r.len() < 2 { continue; }
+        mounts.push(MountEntry {
+            dev: l[2].to_string(),
+            root: unescape(l[3]),
+            mount_point: unescape(l[4]),
+            mount_options: l[5].split(',').map(String::from).collect(),
+            fs_type: r[0].to_string(),
+            source: unescape(r[1]),
+            super_options: r.get(2).map(|o| o.split(',').map(String::from).collect()).unwrap_or_default(),
+        });
+    }
+    mounts
+}
+
+pub fn read_mounts() -> Vec<MountEntry> {
+    fs::read_to_string("/proc/self/mountinfo").map(|s| parse_mountinfo(&s)).unwrap_or_default()
+}
+
+// Apply the pseudo filesystem and bind mount policy. When a device is mounted more
+// than once, the mount of the filesystem root wins, then the shortest mount point.
+pub fn filter_mounts(mounts: Vec<MountEntry>, filter: &MountFilter) -> Vec<MountEntry> {
+    let mounts: Vec<MountEntry> = mounts.into_iter().filter(|m| filter.include_pseudo || !m.is_pseudo()).collect();
+    if !filter.collapse_bind_mounts { return mounts; }
+    let mut best: HashMap<&str, usize> = HashMap::new();
+    for (i, m) in mounts.iter().enumerate() {
+        // Pseudo filesystems share anonymous device numbers per instance; only collapse real devices.
+        if m.is_pseudo() { continue; }
+        let rank = |m: &MountEntry| (m.root != "/", m.mount_point.len());
+        match best.get(m.dev.as_str()) {
+            Some(&j) if rank(&mounts[j]) <= rank(m) => {}
+            _ => { best.insert(&m.dev, i); }
+        }
+    }
+    let keep: Vec<bool> = mounts.iter().enumerate()
+        .map(|(i, m)| m.is_pseudo() || best.get(m.dev.as_str()) == Some(&i))
+        .collect();
+    mounts.into_iter().zip(keep).filter(|(_, k)| *k).map(|(m, _)| m).collect()
+}
+
+pub fn get_disk_info(filter: &MountFilter) -> Vec<DiskInfo> {
+    let mut disks = Vec::new();
+    for m in filter_mounts(read_mounts(), filter) {
+        // Mounts that statvfs cannot read (e.g. permission denied) are still listed,
+        // just without capacity figures.
+        let (mut total_space, mut available_space, mut used_space) = (0, 0, 0);
+        let (mut inodes_total, mut inodes_free) = (0, 0);
+        if let Ok(stat) = statvfs::statvfs(Path::new(&m.mount_point)) {
+            let frsize = stat.fragment_size();
+            total_space = stat.blocks() * frsize;
+            available_space = stat.blocks_available() * frsize;
+            used_space = total_space.saturating_sub(available_space);
+            inodes_total = stat.files();
+            inodes_free = stat.files_free();
+        }
+        disks.push(DiskInfo {
+            name: m.mount_point.clone(),
+            read_only: m.read_only(),
+            mount_point: m.mount_point,
+            total_space,
+            used_space,
+            available_space,
+            fs_type: m.fs_type,
+            device: m.source,
+            mount_options: m.mount_options,
+            inodes_total,
+            inodes_used: inodes_total.saturating_sub(inodes_free),
+            inodes_free,
+        });
+    }
+    disks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mountinfo_paths_are_unescaped() {
+        let content = "36 25 8:1 /data\\134x /mnt/my\\040disk\\011tab rw,noatime shared:1 master:2 - ext4 /dev/sd\\040a rw,errors=remount-ro\n\
+                       37 25 0:5 / /proc rw,nosuid - proc proc rw\n\
+                       malformed line without separator\n";
+        let mounts = parse_mountinfo(content);
+        assert_eq!(mounts.len(), 2);
+        let m = &mounts[0];
+        assert_eq!(m.dev, "8:1");
+        assert_eq!(m.root, "/data\\x");
+        assert_eq!(m.mount_point, "/mnt/my disk\ttab");
+        assert_eq!(m.mount_options, ["rw", "noatime"]);
+        assert_eq!((m.fs_type.as_str(), m.source.as_str()), ("ext4", "/dev/sd a"));
+        assert_eq!(m.super_options, ["rw", "errors=remount-ro"]);
+        assert!(!m.read_only() && !m.is_pseudo());
+        assert!(mounts[1].is_pseudo());
+    }
+
+    #[test]
+    fn tmpfs_and_squashfs_are_real_mounts() {
+        let content = "40 25 0:40 / /tmp rw,nosuid,nodev - tmpfs tmpfs rw,size=8G\n\
+                       41 25 7:3 / /snap/core22/1380 ro,nodev - squashfs /dev/loop3 ro\n\
+                       42 25 0:41 / /sys/fs/bpf rw - bpf bpf rw\n";
+        let pseudo: Vec<bool> = parse_mountinfo(content).iter().map(MountEntry::is_pseudo).collect();
+        assert_eq!(pseudo, [false, false, true]);
+    }
+
+    #[test]
+    fn unescape_leaves_incomplete_sequences_alone() {
+        assert_eq!(unescape("a\\04"), "a\\04");
+        assert_eq!(unescape("a\\09b"), "a\\09b");
+        assert_eq!(unescape("\\012"), "\n");
+    }
+}
@@ -0,0 +1,349 @@
+// Per-process network traffic: TCP byte counters from sock_diag (INET_DIAG) netlink,
+// mapped to processes through their socket inodes.
+use crate::api::simple::{NetworkAttribution, ProcessNetworkUsage};
+use super::connections::socket_inode_owners;
+use super::network::parse_net_dev;
+use super::procfs;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::sync::Mutex;
+use std::time::Instant;
+
+// <linux/netlink.h>, <linux/sock_diag.h> and <linux/inet_diag.h>
+const NLMSG_HDRLEN: usize = 16;
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_INFO: u16 = 2;
+const INET_DIAG_REQ_V2_LEN: usize = 56;
+const INET_DIAG_MSG_LEN: usize = 72;
+const INET_DIAG_MSG_INODE: usize = 68;
+// Offsets of tcpi_bytes_acked and tcpi_bytes_received in struct tcp_info (Linux 4.1+)
+const TCPI_BYTES_ACKED: usize = 120;
+const TCPI_BYTES_RECEIVED: usize = 128;
+
+/// Counters of one TCP socket: bytes sent and acknowledged by the peer, and bytes received.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpSocketBytes {
+    pub acked: u64,
+    pub received: u64,
+}
+
+struct NetUsageSample {
+    last_instant: Instant,
+    sockets: HashMap<u64, TcpSocketBytes>,
+    /// Non-loopback rx/tx totals of each network namespace, by namespace inode
+    namespaces: HashMap<u64, (u64, u64)>,
+}
+
+lazy_static::lazy_static! {
+    static ref NET_USAGE_CACHE: Mutex<Option<NetUsageSample>> = Mutex::new(None);
+}
+
+fn u16_at(buf: &[u8], at: usize) -> u16 { u16::from_ne_bytes([buf[at], buf[at + 1]]) }
+fn u32_at(buf: &[u8], at: usize) -> u32 { u32::from_ne_bytes(buf[at..at + 4].try_into().unwrap()) }
+fn u64_at(buf: &[u8], at: usize) -> u64 { u64::from_ne_bytes(buf[at..at + 8].try_into().unwrap()) }
+
+fn align4(len: usize) -> usize { (len + 3) & !3 }
+
+/// A SOCK_DIAG_BY_FAMILY dump request for every TCP socket of `family`, asking for tcp_info.
+fn dump_request(family: u8, seq: u32) -> Vec<u8> {
+    let len = NLMSG_HDRLEN + INET_DIAG_REQ_V2_LEN;
+    let mut req = Vec::with_capacity(len);
+    req.extend_from_slice(&(len as u32).to_ne_bytes());
+    req.extend_from_slice(&SOCK_DIAG_BY_FAMILY.to_ne_bytes());
+    req.extend_from_slice(&((libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16).to_ne_bytes());
+    req.extend_from_slice(&seq.to_ne_bytes());
+    req.extend_from_slice(&0u32.to_ne_bytes());
+    // inet_diag_req_v2: family, protocol, extensions bitmask, pad, states, then a zeroed sockid
+    req.extend_from_slice(&[family, libc::IPPROTO_TCP as u8, 1 << (INET_DIAG_INFO - 1), 0]);
+    req.extend_from_slice(&u32::MAX.to_ne_bytes());
+    req.resize(len, 0);
+    req
+}
+
+/// Walk the netlink messages of one recv. Returns true once the dump is complete.
+fn parse_dump(buf: &[u8], out: &mut HashMap<u64, TcpSocketBytes>) -> io::Result<bool> {
+    let mut pos = 0;
+    while pos + NLMSG_HDRLEN <= buf.len() {
+        let len = u32_at(buf, pos) as usize;
+        if len < NLMSG_HDRLEN || pos + len > buf.len() { break; }
+        let payload = &buf[pos + NLMSG_HDRLEN..pos + len];
+        match u16_at(buf, pos + 4) {
+            NLMSG_DONE => return Ok(true),
+            NLMSG_ERROR => {
+                let errno = if payload.len() >= 4 { -(u32_at(payload, 0) as i32) } else { libc::EIO };
+                return Err(io::Error::from_raw_os_error(errno));
+            }
+            SOCK_DIAG_BY_FAMILY if payload.len() >= INET_DIAG_MSG_LEN => {
+                let inode = u32_at(payload, INET_DIAG_MSG_INODE) as u64;
+                let mut at = INET_DIAG_MSG_LEN;
+                while at + 4 <= payload.len() {
+                    let attr_len = u16_at(payload, at) as usize;
+                    if attr_len < 4 || at + attr_len > payload.len() { break; }
+                    let data = &payload[at + 4..at + attr_len];
+                    if u16_at(payload, at + 2) == INET_DIAG_INFO && data.len() >= TCPI_BYTES_RECEIVED + 8 && inode != 0 {
+                        out.insert(inode, TcpSocketBytes {
+                            acked: u64_at(data, TCPI_BYTES_ACKED),
+                            received: u64_at(data, TCPI_BYTES_RECEIVED),
+                        });
+                    }
+                    at += align4(attr_len);
+                }
+            }
+            _ => {}
+        }
+        pos += align4(len);
+    }
+    Ok(false)
+}
+
+/// Byte counters of every TCP socket in our network namespace, by socket inode.
+/// Sockets in TIME_WAIT or not yet accepted have no inode and are left out.
+pub fn tcp_socket_bytes() -> io::Result<HashMap<u64, TcpSocketBytes>> {
+    let raw = unsafe { libc::socket(libc::AF_NETLINK, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, libc::NETLINK_INET_DIAG) };
+    if raw < 0 { return Err(io::Error::last_os_error()); }
+    let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+    // A dump never blocks for long; the timeout only guards against a kernel that stays silent.
+    let timeout = libc::timeval { tv_sec: 1, tv_usec: 0 };
+    unsafe {
+        libc::setsockopt(fd.as_raw_fd(), libc::SOL_SOCKET, libc::SO_RCVTIMEO,
+            &timeout as *const _ as *const libc::c_void, std::mem::size_of::<libc::timeval>() as libc::socklen_t);
+    }
+
+    let mut out = HashMap::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    for (seq, family) in [(1, libc::AF_INET), (2, libc::AF_INET6)] {
+        let req = dump_request(family as u8, seq);
+        let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        let sent = unsafe {
+            libc::sendto(fd.as_raw_fd(), req.as_ptr() as *const libc::c_void, req.len(), 0,
+                &addr as *const _ as *const libc::sockaddr, std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t)
+        };
+        if sent < 0 { return Err(io::Error::last_os_error()); }
+        loop {
+            let n = unsafe { libc::recv(fd.as_raw_fd(), buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+            if n < 0 { return Err(io::Error::last_os_error()); }
+            if n == 0 || parse_dump(&buf[..n as usize], &mut out)? { break; }
+        }
+    }
+    Ok(out)
+}
+
+/// Inode of the network namespace of `pid`, from the "net:[4026531840]" link.
+fn net_namespace(pid: &str) -> Option<u64> {
+    let link = fs::read_link(format!("/proc/{}/ns/net", pid)).ok()?;
+    link.to_str()?.strip_prefix("net:[")?.strip_suffix(']')?.parse().ok()
+}
+
+fn read_comm(pid: u32) -> String {
+    fs::read_to_string(format!("/proc/{}/comm", pid)).map(|s| s.trim_end().to_string()).unwrap_or_default()
+}
+
+/// Rows of per-process traffic. TCP sockets of our own network namespace are
+/// attributed to the process holding them. Processes in other namespaces (containers),
+/// or all of them when sock_diag is unavailable, get the non-loopback totals of their
+/// namespace's /proc/<pid>/net/dev, reported once, on the lowest pid of the namespace.
+/// Rates cover the interval since the previous call and are 0 on the first one.
+pub fn get_process_network_usage_impl() -> Vec<ProcessNetworkUsage> {
+    let now = Instant::now();
+    let own_namespace = net_namespace("self");
+    let sockets = tcp_socket_bytes().ok();
+
+    let mut cache = NET_USAGE_CACHE.lock().unwrap();
+    let prev = cache.as_ref();
+    let elapsed = prev.map(|c| now.duration_since(c.last_instant).as_secs_f64()).unwrap_or(0.0);
+    let rate = |delta: u64| if elapsed > 0.0 { delta as f64 / elapsed } else { 0.0 };
+    let mut rows: BTreeMap<u32, ProcessNetworkUsage> = BTreeMap::new();
+
+    if let Some(sockets) = &sockets {
+        let owners = socket_inode_owners();
+        for (inode, bytes) in sockets {
+            let pid = match owners.get(inode) { Some(p) => *p, None => continue };
+            // A socket first seen after the previous call carried all of its traffic within the interval.
+            let (sent_delta, recv_delta) = match prev {
+                Some(c) => {
+                    let last = c.sockets.get(inode).copied().unwrap_or_default();
+                    (bytes.acked.saturating_sub(last.acked), bytes.received.saturating_sub(last.received))
+                }
+                None => (0, 0),
+            };
+            let row = rows.entry(pid).or_insert_with(|| ProcessNetworkUsage {
+                pid,
+                name: read_comm(pid),
+                received_bytes: 0,
+                sent_bytes: 0,
+                receive_bytes_per_sec: 0.0,
+                send_bytes_per_sec: 0.0,
+                connections: 0,
+                source: NetworkAttribution::Socket,
+                net_namespace: own_namespace,
+            });
+            row.received_bytes += bytes.received;
+            row.sent_bytes += bytes.acked;
+            row.receive_bytes_per_sec += rate(recv_delta);
+            row.send_bytes_per_sec += rate(sent_delta);
+            row.connections += 1;
+        }
+    }
+
+    // Lowest pid of every namespace that sock_diag did not cover
+    let mut namespace_pids: BTreeMap<u64, u32> = BTreeMap::new();
+    for pid in procfs::list_pids() {
+        let ns = match net_namespace(&pid.to_string()) { Some(n) => n, None => continue };
+        if sockets.is_some() && Some(ns) == own_namespace { continue; }
+        namespace_pids.entry(ns).and_modify(|p| *p = (*p).min(pid)).or_insert(pid);
+    }
+    let mut namespaces = HashMap::new();
+    for (ns, pid) in namespace_pids {
+        let content = match fs::read_to_string(format!("/proc/{}/net/dev", pid)) { Ok(c) => c, Err(_) => continue };
+        let (rx, tx) = parse_net_dev(&content).iter()
+            .filter(|(name, _)| name != "lo")
+            .fold((0u64, 0u64), |(rx, tx), (_, st)| (rx + st.rx_bytes, tx + st.tx_bytes));
+        let (last_rx, last_tx) = prev.and_then(|c| c.namespaces.get(&ns)).copied().unwrap_or((rx, tx));
+        namespaces.insert(ns, (rx, tx));
+        rows.insert(pid, ProcessNetworkUsage {
+            pid,
+            name: read_comm(pid),
+            received_bytes: rx,
+            sent_bytes: tx,
+            receive_bytes_per_sec: rate(rx.saturating_sub(last_rx)),
+            send_bytes_per_sec: rate(tx.saturating_sub(last_tx)),
+            connections: 0,
+            source: NetworkAttribution::Namespace,
+            net_namespace: Some(ns),
+        });
+    }
+
+    *cache = Some(NetUsageSample { last_instant: now, sockets: sockets.unwrap_or_default(), namespaces });
+    rows.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+
+    fn nlmsg(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let len = NLMSG_HDRLEN + payload.len();
+        let mut msg = Vec::new();
+        msg.extend_from_slice(&(len as u32).to_ne_bytes());
+        msg.extend_from_slice(&kind.to_ne_bytes());
+        msg.extend_from_slice(&[0; 10]);
+        msg.extend_from_slice(payload);
+        msg.resize(align4(msg.len()), 0);
+        msg
+    }
+
+    fn rtattr(kind: u16, data: &[u8]) -> Vec<u8> {
+        let mut attr = Vec::new();
+        attr.extend_from_slice(&((4 + data.len()) as u16).to_ne_bytes());
+        attr.extend_from_slice(&kind.to_ne_bytes());
+        attr.extend_from_slice(data);
+        attr.resize(align4(attr.len()), 0);
+        attr
+    }
+
+    fn tcp_info(acked: u64, received: u64) -> Vec<u8> {
+        let mut info = vec![0u8; TCPI_BYTES_RECEIVED + 8];
+        info[TCPI_BYTES_ACKED..TCPI_BYTES_ACKED + 8].copy_from_slice(&acked.to_ne_bytes());
+        info[TCPI_BYTES_RECEIVED..TCPI_BYTES_RECEIVED + 8].copy_from_slice(&received.to_ne_bytes());
+        info
+    }
+
+    fn diag_msg(inode: u32, attrs: &[u8]) -> Vec<u8> {
+        let mut msg = vec![0u8; INET_DIAG_MSG_LEN];
+        msg[INET_DIAG_MSG_INODE..INET_DIAG_MSG_INODE + 4].copy_from_slice(&inode.to_ne_bytes());
+        msg.extend_from_slice(attrs);
+        msg
+    }
+
+    #[test]
+    fn parses_several_sockets_then_done() {
+        let mut buf = nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(100, &[rtattr(1, &[7; 3]), rtattr(INET_DIAG_INFO, &tcp_info(10, 20))].concat()));
+        buf.extend(nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(200, &rtattr(INET_DIAG_INFO, &tcp_info(30, 40)))));
+        let mut out = HashMap::new();
+        assert!(!parse_dump(&buf, &mut out).unwrap());
+        assert_eq!(out.len(), 2);
+        assert_eq!((out[&100].acked, out[&100].received), (10, 20));
+        assert_eq!((out[&200].acked, out[&200].received), (30, 40));
+
+        buf.extend(nlmsg(NLMSG_DONE, &0i32.to_ne_bytes()));
+        assert!(parse_dump(&buf, &mut HashMap::new()).unwrap());
+    }
+
+    #[test]
+    fn reports_netlink_errors() {
+        let buf = nlmsg(NLMSG_ERROR, &(-libc::EPERM).to_ne_bytes());
+        let err = parse_dump(&buf, &mut HashMap::new()).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(libc::EPERM));
+    }
+
+    #[test]
+    fn skips_truncated_attributes_and_inode_zero() {
+        // The attribute claims more bytes than the message holds.
+        let mut attr = rtattr(INET_DIAG_INFO, &tcp_info(1, 2));
+        let claimed = attr.len() as u16 + 16;
+        attr[0..2].copy_from_slice(&claimed.to_ne_bytes());
+        let mut buf = nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(100, &attr));
+        // tcp_info from a kernel older than 4.1 has no byte counters.
+        buf.extend(nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(200, &rtattr(INET_DIAG_INFO, &[0; 104]))));
+        // TIME_WAIT sockets have no inode.
+        buf.extend(nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(0, &rtattr(INET_DIAG_INFO, &tcp_info(1, 2)))));
+        let mut out = HashMap::new();
+        assert!(!parse_dump(&buf, &mut out).unwrap());
+        assert!(out.is_empty());
+        // A message cut off in the middle is ignored rather than read past.
+        let cut = nlmsg(SOCK_DIAG_BY_FAMILY, &diag_msg(300, &rtattr(INET_DIAG_INFO, &tcp_info(1, 2))));
+        assert!(!parse_dump(&cut[..cut.len() - 20], &mut out).unwrap());
+        assert!(out.is_empty());
+    }
+
+    fn socket_inode(stream: &TcpStream) -> u64 {
+        use std::os::fd::AsRawFd;
+        let mut st: libc::stat = unsafe { std::mem::zeroed() };
+        assert_eq!(unsafe { libc::fstat(stream.as_raw_fd(), &mut st) }, 0);
+        st.st_ino
+    }
+
+    #[test]
+    fn counts_loopback_traffic_per_socket() {
+        const SENT: usize = 1 << 20;
+        // Sandboxes and some containers refuse NETLINK_SOCK_DIAG outright.
+        if let Err(e) = tcp_socket_bytes() {
+            if matches!(e.raw_os_error(), Some(libc::EPERM | libc::EACCES | libc::EPROTONOSUPPORT)) {
+                eprintln!("skipping: sock_diag unavailable ({})", e);
+                return;
+            }
+        }
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        let writer = std::thread::spawn(move || {
+            client.write_all(&vec![1u8; SENT]).unwrap();
+            client
+        });
+        let mut got = 0;
+        let mut buf = vec![0u8; 64 * 1024];
+        while got < SENT { got += server.read(&mut buf).unwrap(); }
+        let client = writer.join().unwrap();
+
+        let (client_inode, server_inode) = (socket_inode(&client), socket_inode(&server));
+        // The last ACK may still be in flight when the reader has everything.
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let sockets = loop {
+            let sockets = tcp_socket_bytes().unwrap();
+            if sockets.get(&client_inode).map(|s| s.acked >= SENT as u64).unwrap_or(false) || Instant::now() > deadline { break sockets; }
+            std::thread::sleep(Duration::from_millis(20));
+        };
+        // bytes_acked also counts the SYN
+        assert!(sockets[&client_inode].acked >= SENT as u64);
+        assert_eq!(sockets[&client_inode].received, 0);
+        assert_eq!(sockets[&server_inode].received, SENT as u64);
+    }
+}
@@ -0,0 +1,212 @@
+// Network interfaces from /proc/net/dev, /sys/class/net and getifaddrs.
+use crate::api::simple::{NetworkInfo, NetworkInterfaceInfo};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fs;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Mutex;
+use std::time::Instant;
+
+// ARPHRD_LOOPBACK from <linux/if_arp.h>, as found in /sys/class/net/<iface>/type
+const ARPHRD_LOOPBACK: u32 = 772;
+
+/// Counters of one /proc/net/dev line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NetDevStat {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+}
+
+pub fn parse_net_dev(content: &str) -> Vec<(String, NetDevStat)> {
+    let mut out = Vec::new();
+    for line in content.lines().skip(2) {
+        if let Some((iface, data)) = line.split_once(':') {
+            let parts: Vec<&str> = data.split_whitespace().collect();
+            if parts.len() < 16 { continue; }
+            let v = |i: usize| parts[i].parse::<u64>().unwrap_or(0);
+            out.push((iface.trim().to_string(), NetDevStat {
+                rx_bytes: v(0),
+                rx_packets: v(1),
+                rx_errors: v(2),
+                rx_dropped: v(3),
+                tx_bytes: v(8),
+                tx_packets: v(9),
+                tx_errors: v(10),
+                tx_dropped: v(11),
+            }));
+        }
+    }
+    out
+}
+
+fn read_net_dev() -> Vec<(String, NetDevStat)> {
+    fs::read_to_string("/proc/net/dev").map(|s| parse_net_dev(&s)).unwrap_or_default()
+}
+
+fn read_sys(iface: &str, attr: &str) -> Option<String> {
+    fs::read_to_string(format!("/sys/class/net/{}/{}", iface, attr)).ok().map(|s| s.trim().to_string())
+}
+
+fn is_loopback(iface: &str) -> bool {
+    read_sys(iface, "type").and_then(|t| t.parse::<u32>().ok()) == Some(ARPHRD_LOOPBACK)
+}
+
+// Interfaces not backed by a device (lo, veth, bridges, tun/tap, docker0, ...) live
+// under /sys/devices/virtual.
+fn is_virtual(iface: &str) -> bool {
+    fs::canonicalize(format!("/sys/class/net/{}", iface))
+        .map(|p| p.to_string_lossy().contains("/devices/virtual/"))
+        .unwrap_or(false)
+}
+
+fn interface_kind(iface: &str, loopback: bool, virtual_iface: bool) -> String {
+    if loopback { return "loopback".into(); }
+    let base = format!("/sys/class/net/{}", iface);
+    if fs::metadata(format!("{}/wireless", base)).is_ok() { return "wireless".into(); }
+    if fs::metadata(format!("{}/bridge", base)).is_ok() { return "bridge".into(); }
+    if fs::metadata(format!("{}/bonding", base)).is_ok() { return "bond".into(); }
+    let devtype = read_sys(iface, "uevent").and_then(|u| {
+        u.lines().find_map(|l| l.strip_prefix("DEVTYPE=").map(String::from))
+    });
+    if let Some(t) = devtype { return t; }
+    if iface.starts_with("veth") { return "veth".into(); }
+    if fs::metadata(format!("{}/tun_flags", base)).is_ok() { return "tun".into(); }
+    if virtual_iface { "virtual".into() } else { "ethernet".into() }
+}
+
+// IPv4 and IPv6 addresses per interface, as "address/prefix".
+fn interface_addresses() -> HashMap<String, (Vec<String>, Vec<String>)> {
+    let mut map: HashMap<String, (Vec<String>, Vec<String>)> = HashMap::new();
+    unsafe {
+        let mut ifap: *mut libc::ifaddrs = std::ptr::null_mut();
+        if libc::getifaddrs(&mut ifap) != 0 { return map; }
+        let mut cur = ifap;
+        while !cur.is_null() {
+            let ifa = &*cur;
+            cur = ifa.ifa_next;
+            if ifa.ifa_addr.is_null() { continue; }
+            let name = CStr::from_ptr(ifa.ifa_name).to_string_lossy().into_owned();
+            match (*ifa.ifa_addr).sa_family as i32 {
+                libc::AF_INET => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in);
+                    let ip = Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr));
+                    let prefix = if ifa.ifa_netmask.is_null() { 32 } else {
+                        (*(ifa.ifa_netmask as *const libc::sockaddr_in)).sin_addr.s_addr.count_ones()
+                    };
+                    map.entry(name).or_default().0.push(format!("{}/{}", ip, prefix));
+                }
+                libc::AF_INET6 => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in6);
+                    let ip = Ipv6Addr::from(addr.sin6_addr.s6_addr);
+                    let prefix = if ifa.ifa_netmask.is_null() { 128 } else {
+                        let mask = &*(ifa.ifa_netmask as *const libc::sockaddr_in6);
+                        mask.sin6_addr.s6_addr.iter().map(|b| b.count_ones()).sum()
+                    };
+                    map.entry(name).or_default().1.push(format!("{}/{}", ip, prefix));
+                }
+                _ => {}
+            }
+        }
+        libc::freeifaddrs(ifap);
+    }
+    map
+}
+
+struct NetRateCache {
+    last_instant: Instant,
+    last_bytes: HashMap<String, (u64, u64)>,
+}
+
+lazy_static::lazy_static! {
+    static ref NET_RATE_CACHE: Mutex<Option<NetRateCache>> = Mutex::new(None);
+}
+
+pub fn get_network_interfaces_impl() -> Vec<NetworkInterfaceInfo> {
+    let stats = read_net_dev();
+    let mut addresses = interface_addresses();
+    let now = Instant::now();
+    let mut cache = NET_RATE_CACHE.lock().unwrap();
+    let prev = cache.as_ref();
+    let elapsed = prev.map(|c| now.duration_since(c.last_instant).as_secs_f64()).unwrap_or(0.0);
+    // Counters can drop when an interface is recreated (e.g. a VPN reconnect); treat that as no traffic.
+    let rate = |cur: u64, last: u64| if elapsed > 0.0 { cur.saturating_sub(last) as f64 / elapsed } else { 0.0 };
+
+    let mut out = Vec::new();
+    for (name, st) in &stats {
+        let loopback = is_loopback(name);
+        let virtual_iface = is_virtual(name);
+        let (last_rx, last_tx) = prev.and_then(|c| c.last_bytes.get(name)).copied().unwrap_or((st.rx_bytes, st.tx_bytes));
+        let (ipv4_addresses, ipv6_addresses) = addresses.remove(name).unwrap_or_default();
+        out.push(NetworkInterfaceInfo {
+            name: name.clone(),
+            kind: interface_kind(name, loopback, virtual_iface),
+            is_loopback: loopback,
+            is_virtual: virtual_iface,
+            operstate: read_sys(name, "operstate").unwrap_or_else(|| "unknown".into()),
+            // speed reads -1 or fails with EINVAL when the link is down or the driver has no notion of speed
+            link_speed_mbps: read_sys(name, "speed").and_then(|s| s.parse::<i64>().ok()).filter(|s| *s > 0).map(|s| s as u64),
+            mtu: read_sys(name, "mtu").and_then(|s| s.parse().ok()).unwrap_or(0),
+            mac_address: read_sys(name, "address").unwrap_or_default(),
+            ipv4_addresses,
+            ipv6_addresses,
+            bytes_received: st.rx_bytes,
+            bytes_sent: st.tx_bytes,
+            packets_received: st.rx_packets,
+            packets_sent: st.tx_packets,
+            receive_errors: st.rx_errors,
+            send_errors: st.tx_errors,
+            receive_dropped: st.rx_dropped,
+            send_dropped: st.tx_dropped,
+            receive_bytes_per_sec: rate(st.rx_bytes, last_rx),
+            send_bytes_per_sec: rate(st.tx_bytes, last_tx),
+        });
+    }
+
+    *cache = Some(NetRateCache {
+        last_instant: now,
+        last_bytes: stats.iter().map(|(n, s)| (n.clone(), (s.rx_bytes, s.tx_bytes))).collect(),
+    });
+    out
+}
+
+/// System-wide totals. Loopback traffic never leaves the machine, so it is left out.
+pub fn get_network_info() -> NetworkInfo {
+    let mut net = NetworkInfo { bytes_sent: 0, bytes_received: 0, packets_sent: 0, packets_received: 0 };
+    for (name, st) in read_net_dev() {
+        if is_loopback(&name) { continue; }
+        net.bytes_received += st.rx_bytes;
+        net.packets_received += st.rx_packets;
+        net.bytes_sent += st.tx_bytes;
+        net.packets_sent += st.tx_packets;
+    }
+    net
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_dev_lines_with_and_without_padding() {
+        let content = "Inter-|   Receive                                                |  Transmit\n \
+                       face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n    \
+                       lo:  804290    7170    0    0    0     0          0         0   804290    7170    0    0    0     0       0          0\n  \
+                       eth0:1234567890 912345    3   17    0     0          0      1204 98765432  456789    1    2    0     0       0          0\n \
+                       wlan0: 12 1\n";
+        let devs = parse_net_dev(content);
+        assert_eq!(devs.len(), 2);
+        let (name, lo) = &devs[0];
+        assert_eq!(name, "lo");
+        assert_eq!((lo.rx_bytes, lo.tx_bytes, lo.rx_packets), (804290, 804290, 7170));
+        let (name, eth) = &devs[1];
+        assert_eq!(name, "eth0");
+        assert_eq!((eth.rx_bytes, eth.rx_packets, eth.rx_errors, eth.rx_dropped), (1234567890, 912345, 3, 17));
+        assert_eq!((eth.tx_bytes, eth.tx_packets, eth.tx_errors, eth.tx_dropped), (98765432, 456789, 1, 2));
+    }
+}
@@ -0,0 +1,110 @@
+// Open file descriptors of a process from /proc/<pid>/fd and /proc/<pid>/fdinfo.
+use crate::api::simple::{FileDescriptorKind, OpenFileInfo, ProcessControlError};
+use super::procfs;
+use std::fs;
+use std::os::unix::fs::FileTypeExt;
+
+/// pos and flags from /proc/<pid>/fdinfo/<fd>. flags is octal.
+pub fn parse_fdinfo(content: &str) -> (u64, u32) {
+    let mut pos = 0;
+    let mut flags = 0;
+    for line in content.lines() {
+        if let Some(v) = line.strip_prefix("pos:") {
+            pos = v.trim().parse().unwrap_or(0);
+        } else if let Some(v) = line.strip_prefix("flags:") {
+            flags = u32::from_str_radix(v.trim(), 8).unwrap_or(0);
+        }
+    }
+    (pos, flags)
+}
+
+// "socket:[1234]" -> 1234
+fn bracket_inode(target: &str) -> u64 {
+    target.split_once(":[").and_then(|(_, rest)| rest.strip_suffix(']')).and_then(|n| n.parse().ok()).unwrap_or(0)
+}
+
+// Unlinked files keep their last path with " (deleted)" appended, whatever their type.
+// Pseudo targets such as "socket:[1234]" are never absolute.
+fn strip_deleted(target: &mut String) -> bool {
+    let deleted = target.starts_with('/') && target.ends_with(procfs::DELETED_SUFFIX);
+    if deleted { target.truncate(target.len() - procfs::DELETED_SUFFIX.len()); }
+    deleted
+}
+
+fn classify(link: &str, target: &str) -> FileDescriptorKind {
+    if target.starts_with("socket:") { return FileDescriptorKind::Socket; }
+    if target.starts_with("pipe:") { return FileDescriptorKind::Pipe; }
+    if target.starts_with("anon_inode:") { return FileDescriptorKind::AnonInode; }
+    // Stat through the /proc link: it still reaches files that have been deleted.
+    match fs::metadata(link).map(|m| m.file_type()) {
+        Ok(t) if t.is_dir() => FileDescriptorKind::Directory,
+        Ok(t) if t.is_char_device() || t.is_block_device() => FileDescriptorKind::Device,
+        Ok(t) if t.is_fifo() => FileDescriptorKind::Pipe,
+        Ok(t) if t.is_socket() => FileDescriptorKind::Socket,
+        Ok(t) if t.is_file() => FileDescriptorKind::File,
+        _ => FileDescriptorKind::Other,
+    }
+}
+
+pub fn get_open_files_impl(pid: u32) -> Result<Vec<OpenFileInfo>, ProcessControlError> {
+    let entries = fs::read_dir(format!("/proc/{}/fd", pid))
+        .map_err(|e| procfs::read_error(pid, &e))?;
+    let mut out = Vec::new();
+    for entry in entries.flatten() {
+        let fd: u32 = match entry.file_name().to_str().and_then(|n| n.parse().ok()) { Some(fd) => fd, None => continue };
+        let link = format!("/proc/{}/fd/{}", pid, fd);
+        // Descriptors closed while listing are skipped.
+        let mut target = match fs::read_link(&link) { Ok(t) => t.to_string_lossy().into_owned(), Err(_) => continue };
+        let deleted = strip_deleted(&mut target);
+        let kind = classify(&link, &target);
+        let inode = match kind {
+            FileDescriptorKind::Socket | FileDescriptorKind::Pipe => bracket_inode(&target),
+            _ => 0,
+        };
+        let (position, flags) = fs::read_to_string(format!("/proc/{}/fdinfo/{}", pid, fd)).map(|s| parse_fdinfo(&s)).unwrap_or_default();
+        let access = flags & libc::O_ACCMODE as u32;
+        out.push(OpenFileInfo {
+            fd,
+            kind,
+            path: target,
+            flags,
+            readable: access == libc::O_RDONLY as u32 || access == libc::O_RDWR as u32,
+            writable: access == libc::O_WRONLY as u32 || access == libc::O_RDWR as u32,
+            position,
+            deleted,
+            inode,
+        });
+    }
+    out.sort_by_key(|f| f.fd);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fdinfo_reads_position_and_octal_flags() {
+        let content = "pos:\t4096\nflags:\t0100002\nmnt_id:\t29\nino:\t1311012\n";
+        let (pos, flags) = parse_fdinfo(content);
+        assert_eq!(pos, 4096);
+        assert_eq!(flags, 0o100002);
+        assert_eq!(flags & libc::O_ACCMODE as u32, libc::O_RDWR as u32);
+        assert_eq!(parse_fdinfo("mnt_id:\t29\n"), (0, 0));
+    }
+
+    #[test]
+    fn deleted_suffix_is_stripped_from_any_path() {
+        for (target, path, deleted) in [
+            ("/var/log/old.log (deleted)", "/var/log/old.log", true),
+            ("/dev/shm/queue (deleted)", "/dev/shm/queue", true),
+            ("/memfd:wayland-shm (deleted)", "/memfd:wayland-shm", true),
+            ("/home/me/a (deleted) b", "/home/me/a (deleted) b", false),
+            ("socket:[1234]", "socket:[1234]", false),
+        ] {
+            let mut t = target.to_string();
+            assert_eq!(strip_deleted(&mut t), deleted, "{}", target);
+            assert_eq!(t, path);
+        }
+    }
+}
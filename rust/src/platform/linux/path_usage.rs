@@ -0,0 +1,141 @@
+// Reverse lookup of the processes holding a path, like fuser / lsof <path>.
+use crate::api::simple::{PathUsage, PathUsageKind, ProcessControlError, ProcessControlErrorKind};
+use super::{mounts, procfs};
+use std::collections::HashSet;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+
+// What counts as "using" the queried path.
+enum Target {
+    /// A file: the same inode, whatever name it was opened under
+    Inode { dev: u64, ino: u64 },
+    /// A plain directory: anything at or below it
+    Tree(String),
+    /// A mount point: anything on that filesystem
+    Filesystem(u64),
+}
+
+impl Target {
+    // `link` is the /proc symlink and `target` its text; stat goes through the link
+    // so deleted files and other mount namespaces still resolve.
+    fn matches_link(&self, link: &str, target: &str) -> bool {
+        match self {
+            Target::Inode { dev, ino } => fs::metadata(link).map(|m| m.dev() == *dev && m.ino() == *ino).unwrap_or(false),
+            Target::Tree(dir) => under(target.strip_suffix(procfs::DELETED_SUFFIX).unwrap_or(target), dir),
+            Target::Filesystem(dev) => fs::metadata(link).map(|m| m.dev() == *dev).unwrap_or(false),
+        }
+    }
+
+    fn matches_map(&self, map: &procfs::MapsLine) -> bool {
+        let dev = libc::makedev(map.dev_major, map.dev_minor);
+        match self {
+            Target::Inode { dev: d, ino } => map.inode == *ino && dev == *d,
+            Target::Tree(dir) => under(map.pathname.strip_suffix(procfs::DELETED_SUFFIX).unwrap_or(&map.pathname), dir),
+            // Anonymous mappings have device 0:0
+            Target::Filesystem(d) => map.inode != 0 && dev == *d,
+        }
+    }
+}
+
+fn under(path: &str, dir: &str) -> bool {
+    path == dir || dir == "/" || path.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn resolve_target(path: &str) -> Result<Target, ProcessControlError> {
+    let canonical = fs::canonicalize(path).map_err(|e| {
+        let code = e.raw_os_error().unwrap_or(0);
+        let kind = if code == libc::EACCES { ProcessControlErrorKind::PermissionDenied } else { ProcessControlErrorKind::InvalidArgument };
+        ProcessControlError::new(kind, code, format!("{}: {}", path, nix::errno::Errno::from_raw(code).desc()))
+    })?;
+    let canonical = canonical.to_string_lossy().into_owned();
+    let meta = fs::metadata(&canonical).map_err(|e| {
+        ProcessControlError::new(ProcessControlErrorKind::Other, e.raw_os_error().unwrap_or(0), format!("{}: {}", canonical, e))
+    })?;
+    if !meta.is_dir() {
+        Ok(Target::Inode { dev: meta.dev(), ino: meta.ino() })
+    } else if mounts::read_mounts().iter().any(|m| m.mount_point == canonical) {
+        Ok(Target::Filesystem(meta.dev()))
+    } else {
+        Ok(Target::Tree(canonical))
+    }
+}
+
+pub fn find_processes_using_impl(path: String) -> Result<Vec<PathUsage>, ProcessControlError> {
+    let target = resolve_target(&path)?;
+    let mut out = Vec::new();
+    let mut pids = procfs::list_pids();
+    pids.sort_unstable();
+    for pid in pids {
+        // Processes we may not inspect, or that exit meanwhile, are skipped.
+        let name = fs::read_to_string(format!("/proc/{}/comm", pid)).map(|s| s.trim_end().to_string()).unwrap_or_default();
+        let mut push = |kind: PathUsageKind, path: String, fd: Option<u32>| {
+            let path = path.strip_suffix(procfs::DELETED_SUFFIX).map(String::from).unwrap_or(path);
+            out.push(PathUsage { pid, name: name.clone(), kind, path, fd });
+        };
+        for (link, kind) in [
+            ("exe", PathUsageKind::Executable),
+            ("cwd", PathUsageKind::WorkingDirectory),
+            ("root", PathUsageKind::RootDirectory),
+        ] {
+            let link = format!("/proc/{}/{}", pid, link);
+            if let Ok(t) = fs::read_link(&link) {
+                let t = t.to_string_lossy().into_owned();
+                if target.matches_link(&link, &t) { push(kind, t, None); }
+            }
+        }
+        if let Ok(entries) = fs::read_dir(format!("/proc/{}/fd", pid)) {
+            let mut fds: Vec<u32> = entries.flatten().filter_map(|e| e.file_name().to_str()?.parse().ok()).collect();
+            fds.sort_unstable();
+            for fd in fds {
+                let link = format!("/proc/{}/fd/{}", pid, fd);
+                let t = match fs::read_link(&link) { Ok(t) => t.to_string_lossy().into_owned(), Err(_) => continue };
+                if target.matches_link(&link, &t) { push(PathUsageKind::OpenFile, t, Some(fd)); }
+            }
+        }
+        // A file is usually mapped as several regions; report it once.
+        if let Ok(maps) = fs::read_to_string(format!("/proc/{}/maps", pid)) {
+            let mut seen = HashSet::new();
+            for map in maps.lines().filter_map(procfs::parse_maps_line) {
+                if target.matches_map(&map) && seen.insert((map.dev_major, map.dev_minor, map.inode)) {
+                    push(PathUsageKind::MemoryMapped, map.pathname, None);
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    // A directory under the temp dir holding one file, removed on drop.
+    struct Fixture(PathBuf);
+
+    impl Drop for Fixture {
+        fn drop(&mut self) { let _ = fs::remove_dir_all(&self.0); }
+    }
+
+    fn opened_by_us(usages: &[PathUsage], path: &str) -> bool {
+        usages.iter().any(|u| u.pid == std::process::id() && u.kind == PathUsageKind::OpenFile && u.path == path && u.fd.is_some())
+    }
+
+    #[test]
+    fn finds_our_own_open_file_by_inode_and_by_tree() {
+        let dir = std::env::temp_dir().join(format!("fltask-path-usage-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let fixture = Fixture(fs::canonicalize(&dir).unwrap());
+        let file = fixture.0.join("held.txt");
+        fs::write(&file, "x").unwrap();
+        let _held = fs::File::open(&file).unwrap();
+        let file = file.to_string_lossy().into_owned();
+        let dir = fixture.0.to_string_lossy().into_owned();
+
+        assert!(matches!(resolve_target(&file).unwrap(), Target::Inode { .. }));
+        assert!(opened_by_us(&find_processes_using_impl(file.clone()).unwrap(), &file));
+        assert!(matches!(resolve_target(&dir).unwrap(), Target::Tree(_)));
+        assert!(opened_by_us(&find_processes_using_impl(dir).unwrap(), &file));
+    }
+}
@@ -0,0 +1,61 @@
+// Scheduling priority (nice) and I/O priority. Both are per thread on Linux, so
+// changes are applied to every thread of the process.
+use crate::api::simple::{IoPriority, IoPriorityClass, PriorityLevel, ProcessControlError, ProcessControlErrorKind, ProcessPriority};
+use super::{procfs, signal};
+
+// include/uapi/linux/ioprio.h
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+const IOPRIO_CLASS_SHIFT: i64 = 13;
+
+/// I/O priority of a thread. Without an explicit class the kernel uses best-effort
+/// at level (nice + 20) / 5, which is reported as the level.
+pub fn io_priority(tid: u32, nice: i32) -> IoPriority {
+    let value = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, tid as libc::c_int) };
+    if value < 0 { return IoPriority::default(); }
+    let level = (value & 0x7) as u8;
+    match value >> IOPRIO_CLASS_SHIFT {
+        1 => IoPriority { class: IoPriorityClass::Realtime, level },
+        2 => IoPriority { class: IoPriorityClass::BestEffort, level },
+        3 => IoPriority { class: IoPriorityClass::Idle, level: 7 },
+        _ => IoPriority { class: IoPriorityClass::None, level: ((nice + 20) / 5).clamp(0, 7) as u8 },
+    }
+}
+
+pub fn get_priority_impl(pid: u32) -> Result<ProcessPriority, ProcessControlError> {
+    let stat = signal::check_target(pid)?;
+    Ok(ProcessPriority { level: PriorityLevel::from_nice(stat.nice), nice: stat.nice, io_priority: io_priority(pid, stat.nice) })
+}
+
+// Run `apply` on every thread. Only a failure on the main thread is an error; other
+// threads may exit in between.
+pub fn for_each_thread(pid: u32, apply: impl Fn(u32) -> bool) -> Result<(), ProcessControlError> {
+    for tid in procfs::list_tids(pid) {
+        if !apply(tid) {
+            let err = signal::os_error(pid);
+            if tid == pid || err.kind != ProcessControlErrorKind::NoSuchProcess { return Err(err); }
+        }
+    }
+    Ok(())
+}
+
+pub fn set_priority_impl(pid: u32, level: PriorityLevel) -> Result<(), ProcessControlError> {
+    signal::check_target(pid)?;
+    let nice = level.nice();
+    for_each_thread(pid, |tid| unsafe { libc::setpriority(libc::PRIO_PROCESS, tid as libc::id_t, nice) } == 0)
+}
+
+pub fn set_io_priority_impl(pid: u32, priority: IoPriority) -> Result<(), ProcessControlError> {
+    signal::check_target(pid)?;
+    if priority.level > 7 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("I/O priority level {} is out of range 0-7", priority.level)));
+    }
+    let value = match priority.class {
+        IoPriorityClass::None => 0,
+        IoPriorityClass::Realtime => (1 << IOPRIO_CLASS_SHIFT) | priority.level as i64,
+        IoPriorityClass::BestEffort => (2 << IOPRIO_CLASS_SHIFT) | priority.level as i64,
+        IoPriorityClass::Idle => 3 << IOPRIO_CLASS_SHIFT,
+    };
+    for_each_thread(pid, |tid| unsafe {
+        libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, tid as libc::c_int, value as libc::c_int) == 0
+    })
+}
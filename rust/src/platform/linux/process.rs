@@ -1,10 +1,130 @@
-use crate::api::simple::ProcessInfo;
+use crate::api::simple::{CpuUsageMode, IoPriority, MemoryMetric, PriorityLevel, ProcessDiskIo, ProcessInfo, ProcessState};
+use super::{cgroups, memory, priority, procfs, security, suspend, users};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use std::{fs, path::Path};
 use std::io::Read;
 
+// Per-process CPU times and I/O counters from the previous snapshot. Keyed by pid, with
+// the process start time kept alongside so a reused pid is not diffed against the old process.
+struct ProcCpuSample {
+    starttime: u64,
+    cpu_ticks: u64,
+    // read_bytes and write_bytes - cancelled_write_bytes
+    io_bytes: Option<(u64, u64)>,
+}
+
+struct ProcessCpuSampler {
+    mode: CpuUsageMode,
+    last_instant: Option<Instant>,
+    last_total_jiffies: u64,
+    samples: HashMap<u32, ProcCpuSample>,
+}
+
+lazy_static::lazy_static! {
+    static ref PROCESS_CPU_CACHE: Mutex<ProcessCpuSampler> = Mutex::new(ProcessCpuSampler {
+        mode: CpuUsageMode::Machine,
+        last_instant: None,
+        last_total_jiffies: 0,
+        samples: HashMap::new(),
+    });
+}
+
+impl ProcessCpuSampler {
+    // Jiffies elapsed on all CPUs since the previous snapshot. Falls back to wall time
+    // when /proc/stat cannot be read.
+    fn elapsed_jiffies(&self, now: Instant, total_jiffies: Option<u64>, cores: u64) -> f64 {
+        match (total_jiffies, self.last_instant) {
+            (_, None) => 0.0,
+            (Some(total), Some(_)) if total > self.last_total_jiffies => (total - self.last_total_jiffies) as f64,
+            (_, Some(last)) => now.duration_since(last).as_secs_f64() * procfs::clock_ticks() as f64 * cores as f64,
+        }
+    }
+
+    fn usage(&self, pid: u32, stat: &procfs::PidStat, elapsed: f64, cores: u64) -> f64 {
+        let prev = match self.samples.get(&pid) {
+            Some(p) if p.starttime == stat.starttime => p,
+            _ => return 0.0,
+        };
+        if elapsed <= 0.0 { return 0.0; }
+        let used = (stat.utime + stat.stime).saturating_sub(prev.cpu_ticks) as f64;
+        let machine = (used / elapsed * 100.0).clamp(0.0, 100.0);
+        match self.mode {
+            CpuUsageMode::Machine => machine,
+            CpuUsageMode::SingleCore => machine * cores as f64,
+        }
+    }
+
+    // Fill in the rates of `io` and return the byte counts to keep for the next snapshot.
+    fn io_rates(&self, pid: u32, stat: &procfs::PidStat, io: &mut ProcessDiskIo, now: Instant) -> (u64, u64) {
+        let bytes = (io.read_bytes, io.write_bytes.saturating_sub(io.cancelled_write_bytes));
+        let secs = self.last_instant.map(|last| now.duration_since(last).as_secs_f64()).unwrap_or(0.0);
+        if let Some((read, write)) = self.samples.get(&pid).filter(|p| p.starttime == stat.starttime).and_then(|p| p.io_bytes) {
+            if secs > 0.0 {
+                io.read_bytes_per_sec = bytes.0.saturating_sub(read) as f64 / secs;
+                io.write_bytes_per_sec = bytes.1.saturating_sub(write) as f64 / secs;
+            }
+        }
+        bytes
+    }
+}
+
+/// Parse /proc/<pid>/io. Rates are left at 0 for the caller to fill in.
+pub fn parse_pid_io(content: &str) -> Option<ProcessDiskIo> {
+    let mut io = ProcessDiskIo::default();
+    let mut seen = false;
+    for line in content.lines() {
+        let (key, value) = match line.split_once(':') { Some(p) => p, None => continue };
+        let value: u64 = match value.trim().parse() { Ok(v) => v, Err(_) => continue };
+        match key {
+            "rchar" => io.read_chars = value,
+            "wchar" => io.write_chars = value,
+            "syscr" => io.read_syscalls = value,
+            "syscw" => io.write_syscalls = value,
+            "read_bytes" => io.read_bytes = value,
+            "write_bytes" => io.write_bytes = value,
+            "cancelled_write_bytes" => io.cancelled_write_bytes = value,
+            _ => continue,
+        }
+        seen = true;
+    }
+    seen.then_some(io)
+}
+
+// Decode the state letter of /proc/<pid>/stat (see proc(5)).
+pub fn decode_state(state: char) -> ProcessState {
+    match state {
+        'R' => ProcessState::Running,
+        'S' => ProcessState::Sleeping,
+        'D' => ProcessState::DiskSleep,
+        'T' => ProcessState::Stopped,
+        't' => ProcessState::Traced,
+        'Z' => ProcessState::Zombie,
+        // 'x' is how 2.6.33 to 3.13 showed it
+        'X' | 'x' => ProcessState::Dead,
+        'I' => ProcessState::Idle,
+        'P' => ProcessState::Parked,
+        // Only 2.6.33 to 3.13: waking is about to run, wakekill is a killable sleep
+        'W' => ProcessState::Running,
+        'K' => ProcessState::DiskSleep,
+        _ => ProcessState::Unknown,
+    }
+}
+
 pub fn get_processes_impl() -> Vec<ProcessInfo> {
     let mut out = Vec::new();
     let proc_dir = Path::new("/proc");
+    let cores = num_cpus::get().max(1) as u64;
+    let now = Instant::now();
+    let total_jiffies = procfs::read_total_jiffies();
+    let mut sampler = PROCESS_CPU_CACHE.lock().unwrap();
+    let elapsed = sampler.elapsed_jiffies(now, total_jiffies, cores);
+    let mut next_samples = HashMap::new();
+    let ticks = procfs::clock_ticks();
+    let boot_time = procfs::read_boot_time().unwrap_or(0);
+    let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let names = users::names();
     if let Ok(entries) = fs::read_dir(proc_dir) {
         for entry in entries.flatten() {
             let file_name = entry.file_name();
@@ -22,6 +142,8 @@ pub fn get_processes_impl() -> Vec<ProcessInfo> {
 
             let mut parent_pid: Option<u32> = None;
             let mut memory_usage: u64 = 0;
+            let (mut uids, mut gids, mut groups) = (None, None, Vec::new());
+            let mut effective_caps = 0;
             if let Ok(mut f) = fs::File::open(format!("/proc/{}/status", pid)) {
                 let mut buf = String::new();
                 if f.read_to_string(&mut buf).is_ok() {
@@ -30,26 +152,87 @@ pub fn get_processes_impl() -> Vec<ProcessInfo> {
                             if let Some(val) = line.split_whitespace().nth(1) { parent_pid = val.parse().ok(); }
                         } else if line.starts_with("VmRSS:") {
                             if let Some(val) = line.split_whitespace().nth(1) { memory_usage = val.parse::<u64>().unwrap_or(0) * 1024; }
+                        } else if let Some(val) = line.strip_prefix("Uid:") {
+                            uids = users::parse_id_triple(val);
+                        } else if let Some(val) = line.strip_prefix("Gid:") {
+                            gids = users::parse_id_triple(val);
+                        } else if let Some(val) = line.strip_prefix("Groups:") {
+                            groups = val.split_whitespace().filter_map(|g| g.parse().ok()).collect();
+                        } else if let Some(val) = line.strip_prefix("CapEff:") {
+                            effective_caps = security::parse_cap_mask(val).unwrap_or(0);
                         }
                     }
                 }
             }
+            let credentials = match (uids, gids) {
+                (Some(uids), Some(gids)) => Some(users::credentials(&names, uids, gids, groups)),
+                _ => None,
+            };
+
+            let mut cpu_usage = 0.0;
+            let mut state = ProcessState::Unknown;
+            let mut start_time = 0;
+            let mut nice = 0;
+            let mut io_priority = IoPriority::default();
+            let mut last_cpu = None;
+            let mut thread_count = 0;
+            let mut memory_metric = MemoryMetric::Rss;
+            // Needs the same ptrace access as reading another process's memory.
+            let mut disk_io = fs::read_to_string(format!("/proc/{}/io", pid)).ok().and_then(|s| parse_pid_io(&s));
+            if let Some(stat) = procfs::read_pid_stat(pid) {
+                (memory_usage, memory_metric) = memory::memory_usage(pid, stat.starttime, memory_usage);
+                cpu_usage = sampler.usage(pid, &stat, elapsed, cores);
+                let io_bytes = disk_io.as_mut().map(|io| sampler.io_rates(pid, &stat, io, now));
+                next_samples.insert(pid, ProcCpuSample { starttime: stat.starttime, cpu_ticks: stat.utime + stat.stime, io_bytes });
+                state = if suspend::is_suspended(pid, &stat) { ProcessState::Suspended } else { decode_state(stat.state) };
+                // starttime is in clock ticks after boot
+                if boot_time > 0 { start_time = boot_time + stat.starttime / ticks; }
+                nice = stat.nice;
+                io_priority = priority::io_priority(pid, stat.nice);
+                last_cpu = stat.processor;
+                thread_count = stat.num_threads;
+            }
 
             out.push(ProcessInfo {
                 pid,
                 name: name.clone(),
-                cpu_usage: 0.0,
+                cpu_usage,
                 memory_usage,
+                memory_metric,
                 parent_pid,
-                status: String::new(),
+                status: state.label().to_string(),
+                state,
                 command,
-                start_time: 0,
+                start_time,
+                age: if start_time > 0 { now_secs.saturating_sub(start_time) } else { 0 },
+                priority: PriorityLevel::from_nice(nice),
+                nice,
+                io_priority,
+                last_cpu,
+                thread_count,
+                disk_io,
+                user: credentials.as_ref().map(|c| c.effective_user.clone()),
+                credentials,
+                // Root holds every capability unless it was dropped (e.g. in a container)
+                privileged: effective_caps != 0,
+                cgroup: cgroups::process_cgroup(pid),
             });
         }
     }
+    // Exited processes drop out of the caches here.
+    let live = next_samples.keys().copied().collect::<HashSet<u32>>();
+    suspend::prune_suspended(&live);
+    memory::prune_memory_samples(&live);
+    sampler.samples = next_samples;
+    sampler.last_instant = Some(now);
+    if let Some(total) = total_jiffies { sampler.last_total_jiffies = total; }
     out
 }
 
-pub fn kill_process_impl(pid: u32) -> bool {
-    unsafe { libc::kill(pid as i32, libc::SIGKILL) == 0 }
+pub fn set_cpu_usage_mode_impl(mode: CpuUsageMode) {
+    PROCESS_CPU_CACHE.lock().unwrap().mode = mode;
+}
+
+pub fn cpu_usage_mode() -> CpuUsageMode {
+    PROCESS_CPU_CACHE.lock().unwrap().mode
 }
@@ -0,0 +1,241 @@
+// Shared parsers for the /proc files used by several collectors.
+use crate::api::simple::ProcessControlError;
+use super::signal;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+
+/// PIDs of all processes currently visible in /proc.
+pub fn list_pids() -> Vec<u32> {
+    fs::read_dir("/proc")
+        .map(|entries| entries.flatten().filter_map(|e| e.file_name().to_str()?.parse::<u32>().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Thread ids of a process, from /proc/<pid>/task. Falls back to the pid itself.
+pub fn list_tids(pid: u32) -> Vec<u32> {
+    let tids: Vec<u32> = fs::read_dir(format!("/proc/{}/task", pid))
+        .map(|entries| entries.flatten().filter_map(|e| e.file_name().to_str()?.parse().ok()).collect())
+        .unwrap_or_default();
+    if tids.is_empty() { vec![pid] } else { tids }
+}
+
+/// The fields of /proc/<pid>/stat that the collectors use.
+#[derive(Debug, Clone, Default)]
+pub struct PidStat {
+    pub state: char,
+    pub ppid: u32,
+    pub pgrp: u32,
+    /// PF_* flags of the task (include/linux/sched.h)
+    pub flags: u64,
+    pub utime: u64,
+    pub stime: u64,
+    /// Kernel priority: 20 + nice for normal tasks, negative for real-time ones
+    pub priority: i32,
+    pub nice: i32,
+    pub num_threads: u32,
+    pub starttime: u64,
+    /// CPU the task last ran on; missing on very old kernels
+    pub processor: Option<u32>,
+}
+
+/// Parse the content of /proc/<pid>/stat. The comm field may contain spaces and
+/// parentheses, so the remaining fields are taken after the last ')'.
+pub fn parse_pid_stat(content: &str) -> Option<PidStat> {
+    let close = content.rfind(')')?;
+    // Fields after comm start at field 3 (state), so field N is rest[N - 3].
+    let rest: Vec<&str> = content[close + 1..].split_whitespace().collect();
+    if rest.len() < 20 { return None; }
+    let field = |n: usize| rest[n - 3].parse::<u64>().unwrap_or(0);
+    Some(PidStat {
+        state: rest[0].chars().next().unwrap_or('?'),
+        ppid: field(4) as u32,
+        pgrp: field(5) as u32,
+        flags: field(9),
+        utime: field(14),
+        stime: field(15),
+        priority: rest[18 - 3].parse().unwrap_or(0),
+        nice: rest[19 - 3].parse().unwrap_or(0),
+        num_threads: field(20) as u32,
+        starttime: field(22),
+        processor: rest.get(39 - 3).and_then(|v| v.parse().ok()),
+    })
+}
+
+pub fn read_pid_stat(pid: u32) -> Option<PidStat> {
+    fs::read_to_string(format!("/proc/{}/stat", pid)).ok().and_then(|s| parse_pid_stat(&s))
+}
+
+/// All descendants of `root`, from the ppid field of every /proc/<pid>/stat. Parents
+/// come before their children, so the reversed list is ordered leaves first.
+pub fn descendants(root: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for pid in list_pids() {
+        if let Some(stat) = read_pid_stat(pid) { children.entry(stat.ppid).or_default().push(pid); }
+    }
+    let mut out = Vec::new();
+    let mut queue = vec![root];
+    while let Some(pid) = queue.pop() {
+        for &child in children.get(&pid).map(Vec::as_slice).unwrap_or_default() {
+            out.push(child);
+            queue.push(child);
+        }
+    }
+    out
+}
+
+/// One "cpu" line of /proc/stat, in clock ticks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTimes {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+    pub guest: u64,
+    pub guest_nice: u64,
+}
+
+impl CpuTimes {
+    /// All ticks on this CPU. guest and guest_nice are already included in user and
+    /// nice, so they are left out to avoid counting them twice.
+    pub fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+
+    /// Ticks the CPU was not available for other work. Steal counts as busy: the
+    /// hypervisor ran someone else while this vCPU had work.
+    pub fn busy(&self) -> u64 {
+        self.total() - self.idle - self.iowait
+    }
+}
+
+fn parse_cpu_line(line: &str) -> Option<CpuTimes> {
+    let vals: Vec<u64> = line.split_whitespace().skip(1).map(|v| v.parse().unwrap_or(0)).collect();
+    // Older kernels lack steal/guest columns; missing ones read as 0.
+    if vals.len() < 4 { return None; }
+    let v = |i: usize| vals.get(i).copied().unwrap_or(0);
+    Some(CpuTimes {
+        user: v(0), nice: v(1), system: v(2), idle: v(3), iowait: v(4),
+        irq: v(5), softirq: v(6), steal: v(7), guest: v(8), guest_nice: v(9),
+    })
+}
+
+/// Parse the aggregate and per-CPU lines of /proc/stat. Per-CPU entries carry the
+/// CPU id from the line name, since offline CPUs are left out and ids can have gaps.
+pub fn parse_stat_cpus(content: &str) -> (Option<CpuTimes>, Vec<(usize, CpuTimes)>) {
+    let mut total = None;
+    let mut per_cpu = Vec::new();
+    for line in content.lines().filter(|l| l.starts_with("cpu")) {
+        let name = line.split_whitespace().next().unwrap_or("");
+        if name == "cpu" {
+            total = parse_cpu_line(line);
+        } else if let Ok(id) = name[3..].parse::<usize>() {
+            if let Some(times) = parse_cpu_line(line) { per_cpu.push((id, times)); }
+        }
+    }
+    (total, per_cpu)
+}
+
+/// Total clock ticks of all CPUs since boot, from the aggregate line of /proc/stat.
+pub fn read_total_jiffies() -> Option<u64> {
+    let stat = fs::read_to_string("/proc/stat").ok()?;
+    parse_stat_cpus(&stat).0.map(|t| t.total())
+}
+
+/// Boot time in seconds since the epoch, from the btime line of /proc/stat.
+pub fn read_boot_time() -> Option<u64> {
+    fs::read_to_string("/proc/stat").ok().and_then(|d| {
+        d.lines().find(|l| l.starts_with("btime "))
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|s| s.parse::<u64>().ok())
+    })
+}
+
+/// Kernel clock ticks per second (USER_HZ), used by every jiffies field in /proc.
+pub fn clock_ticks() -> u64 {
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks > 0 { ticks as u64 } else { 100 }
+}
+
+/// The header line of a mapping in /proc/<pid>/maps and /proc/<pid>/smaps.
+#[derive(Debug, Clone, Default)]
+pub struct MapsLine {
+    pub start: u64,
+    pub end: u64,
+    /// "r-xp" and the like
+    pub perms: String,
+    pub offset: u64,
+    pub dev_major: u32,
+    pub dev_minor: u32,
+    pub inode: u64,
+    /// File path or pseudo-name ([heap], [stack], [vdso]); empty for anonymous mappings
+    pub pathname: String,
+}
+
+/// Appended by the kernel to exe, fd and maps paths whose file has been unlinked.
+pub const DELETED_SUFFIX: &str = " (deleted)";
+
+/// Parse "start-end perms offset major:minor inode   pathname". The pathname may
+/// contain spaces, so it is everything after the inode column.
+pub fn parse_maps_line(line: &str) -> Option<MapsLine> {
+    let mut rest = line;
+    let mut next = || {
+        let t = rest.trim_start();
+        let end = t.find(char::is_whitespace).unwrap_or(t.len());
+        let (field, tail) = t.split_at(end);
+        rest = tail;
+        field
+    };
+    let (start, end) = next().split_once('-')?;
+    let (start, end) = (u64::from_str_radix(start, 16).ok()?, u64::from_str_radix(end, 16).ok()?);
+    let perms = next().to_string();
+    let offset = u64::from_str_radix(next(), 16).ok()?;
+    let (major, minor) = next().split_once(':')?;
+    let inode = next().parse().ok()?;
+    Some(MapsLine {
+        start,
+        end,
+        perms,
+        offset,
+        dev_major: u32::from_str_radix(major, 16).ok()?,
+        dev_minor: u32::from_str_radix(minor, 16).ok()?,
+        inode,
+        pathname: rest.trim_start().to_string(),
+    })
+}
+
+/// The error for a failed read below /proc/<pid>: a missing file means the process is gone.
+pub fn read_error(pid: u32, e: &io::Error) -> ProcessControlError {
+    let code = if e.kind() == io::ErrorKind::NotFound { libc::ESRCH } else { e.raw_os_error().unwrap_or(0) };
+    signal::errno_error(pid, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pid_stat_fields_after_a_comm_with_spaces_and_parens() {
+        let line = "4242 (my) (weird prog) S 1 4240 4240 0 -1 4194560 100 0 0 0 250 75 0 0 15 -5 3 0 98765 \
+                    12345678 456 18446744073709551615 1 1 0 0 0 0 0 4096 0 0 0 0 17 2 0 0 0 0 0\n";
+        let stat = parse_pid_stat(line).unwrap();
+        assert_eq!(stat.state, 'S');
+        assert_eq!((stat.ppid, stat.pgrp), (1, 4240));
+        assert_eq!(stat.flags, 4194560);
+        assert_eq!((stat.utime, stat.stime), (250, 75));
+        assert_eq!((stat.priority, stat.nice), (15, -5));
+        assert_eq!(stat.num_threads, 3);
+        assert_eq!(stat.starttime, 98765);
+        assert_eq!(stat.processor, Some(2));
+    }
+
+    #[test]
+    fn pid_stat_rejects_truncated_lines() {
+        assert!(parse_pid_stat("4242 (sh) S 1 4240").is_none());
+        assert!(parse_pid_stat("4242 sh S 1 4240 4240 0 -1 0 0 0 0 0 0 0 0 0 20 0 1 0 1").is_none());
+    }
+}
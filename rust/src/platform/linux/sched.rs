@@ -0,0 +1,112 @@
+// CPU affinity and scheduling policy of processes and threads.
+use crate::api::simple::{ProcessControlError, ProcessControlErrorKind, SchedulerInfo, SchedulerPolicy, ThreadScheduling};
+use super::{priority, procfs, signal};
+use nix::sched::{sched_getaffinity, sched_setaffinity, CpuSet};
+use nix::unistd::Pid;
+use std::fs;
+
+// Flag that may be or'ed into the policy returned by sched_getscheduler.
+const SCHED_RESET_ON_FORK: libc::c_int = 0x4000_0000;
+const SCHED_DEADLINE: libc::c_int = 6;
+
+fn not_found(pid: u32) -> ProcessControlError {
+    ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid))
+}
+
+fn affinity(tid: u32) -> Result<Vec<u32>, ProcessControlError> {
+    let set = sched_getaffinity(Pid::from_raw(tid as i32)).map_err(|e| signal::errno_error(tid, e as i32))?;
+    Ok((0..CpuSet::count()).filter(|&cpu| set.is_set(cpu).unwrap_or(false)).map(|cpu| cpu as u32).collect())
+}
+
+fn decode_policy(policy: libc::c_int) -> SchedulerPolicy {
+    match policy & !SCHED_RESET_ON_FORK {
+        libc::SCHED_OTHER => SchedulerPolicy::Other,
+        libc::SCHED_BATCH => SchedulerPolicy::Batch,
+        libc::SCHED_IDLE => SchedulerPolicy::Idle,
+        libc::SCHED_FIFO => SchedulerPolicy::Fifo,
+        libc::SCHED_RR => SchedulerPolicy::RoundRobin,
+        SCHED_DEADLINE => SchedulerPolicy::Deadline,
+        _ => SchedulerPolicy::Unknown,
+    }
+}
+
+fn scheduler(tid: u32) -> Result<SchedulerInfo, ProcessControlError> {
+    let policy = unsafe { libc::sched_getscheduler(tid as libc::pid_t) };
+    if policy < 0 { return Err(signal::os_error(tid)); }
+    let mut param = libc::sched_param { sched_priority: 0 };
+    if unsafe { libc::sched_getparam(tid as libc::pid_t, &mut param) } != 0 { return Err(signal::os_error(tid)); }
+    Ok(SchedulerInfo { policy: decode_policy(policy), priority: param.sched_priority.max(0) as u32 })
+}
+
+fn check_pid(pid: u32) -> Result<(), ProcessControlError> {
+    if pid == 0 || pid > i32::MAX as u32 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("invalid pid {}", pid)));
+    }
+    Ok(())
+}
+
+pub fn get_cpu_affinity_impl(pid: u32) -> Result<Vec<u32>, ProcessControlError> {
+    check_pid(pid)?;
+    affinity(pid)
+}
+
+pub fn set_cpu_affinity_impl(pid: u32, cpus: Vec<u32>, all_threads: bool) -> Result<(), ProcessControlError> {
+    signal::check_target(pid)?;
+    if cpus.is_empty() {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, "the CPU list is empty"));
+    }
+    let mut set = CpuSet::new();
+    for &cpu in &cpus {
+        set.set(cpu as usize).map_err(|_| {
+            ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("CPU {} is out of range", cpu))
+        })?;
+    }
+    let apply = |tid: u32| sched_setaffinity(Pid::from_raw(tid as i32), &set).is_ok();
+    if all_threads { priority::for_each_thread(pid, apply) } else if apply(pid) { Ok(()) } else { Err(signal::os_error(pid)) }
+}
+
+pub fn get_scheduler_impl(pid: u32) -> Result<SchedulerInfo, ProcessControlError> {
+    check_pid(pid)?;
+    scheduler(pid)
+}
+
+pub fn set_scheduler_impl(pid: u32, info: SchedulerInfo, all_threads: bool) -> Result<(), ProcessControlError> {
+    signal::check_target(pid)?;
+    let policy = match info.policy {
+        SchedulerPolicy::Other => libc::SCHED_OTHER,
+        SchedulerPolicy::Batch => libc::SCHED_BATCH,
+        SchedulerPolicy::Idle => libc::SCHED_IDLE,
+        SchedulerPolicy::Fifo => libc::SCHED_FIFO,
+        SchedulerPolicy::RoundRobin => libc::SCHED_RR,
+        // SCHED_DEADLINE takes runtime/deadline/period through sched_setattr.
+        SchedulerPolicy::Deadline | SchedulerPolicy::Unknown => {
+            return Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, format!("{:?} cannot be set", info.policy)));
+        }
+    };
+    let (min, max) = unsafe { (libc::sched_get_priority_min(policy), libc::sched_get_priority_max(policy)) };
+    if (info.priority as i32) < min || (info.priority as i32) > max {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("priority {} is out of range {}-{} for {:?}", info.priority, min, max, info.policy)));
+    }
+    let param = libc::sched_param { sched_priority: info.priority as libc::c_int };
+    let apply = |tid: u32| unsafe { libc::sched_setscheduler(tid as libc::pid_t, policy, &param) } == 0;
+    if all_threads { priority::for_each_thread(pid, apply) } else if apply(pid) { Ok(()) } else { Err(signal::os_error(pid)) }
+}
+
+pub fn get_thread_scheduling_impl(pid: u32) -> Result<Vec<ThreadScheduling>, ProcessControlError> {
+    check_pid(pid)?;
+    if procfs::read_pid_stat(pid).is_none() { return Err(not_found(pid)); }
+    let mut out = Vec::new();
+    for tid in procfs::list_tids(pid) {
+        // Threads that exit while being listed are skipped.
+        let (Ok(affinity), Ok(scheduler)) = (affinity(tid), scheduler(tid)) else { continue };
+        let stat = fs::read_to_string(format!("/proc/{}/task/{}/stat", pid, tid)).ok().and_then(|s| procfs::parse_pid_stat(&s));
+        out.push(ThreadScheduling {
+            tid,
+            name: fs::read_to_string(format!("/proc/{}/task/{}/comm", pid, tid)).map(|s| s.trim_end().to_string()).unwrap_or_default(),
+            affinity,
+            scheduler,
+            last_cpu: stat.and_then(|s| s.processor),
+        });
+    }
+    Ok(out)
+}
@@ -0,0 +1,122 @@
+// Capabilities, seccomp and LSM label of a process, from /proc/<pid>/status and attr/current.
+use crate::api::simple::{CapabilitySet, ProcessControlError, SeccompMode, SecurityContext};
+use super::procfs;
+use std::fs;
+
+// Bit numbers from <linux/capability.h>
+const CAPABILITY_NAMES: [&str; 41] = [
+    "CAP_CHOWN", "CAP_DAC_OVERRIDE", "CAP_DAC_READ_SEARCH", "CAP_FOWNER", "CAP_FSETID", "CAP_KILL",
+    "CAP_SETGID", "CAP_SETUID", "CAP_SETPCAP", "CAP_LINUX_IMMUTABLE", "CAP_NET_BIND_SERVICE",
+    "CAP_NET_BROADCAST", "CAP_NET_ADMIN", "CAP_NET_RAW", "CAP_IPC_LOCK", "CAP_IPC_OWNER", "CAP_SYS_MODULE",
+    "CAP_SYS_RAWIO", "CAP_SYS_CHROOT", "CAP_SYS_PTRACE", "CAP_SYS_PACCT", "CAP_SYS_ADMIN", "CAP_SYS_BOOT",
+    "CAP_SYS_NICE", "CAP_SYS_RESOURCE", "CAP_SYS_TIME", "CAP_SYS_TTY_CONFIG", "CAP_MKNOD", "CAP_LEASE",
+    "CAP_AUDIT_WRITE", "CAP_AUDIT_CONTROL", "CAP_SETFCAP", "CAP_MAC_OVERRIDE", "CAP_MAC_ADMIN", "CAP_SYSLOG",
+    "CAP_WAKE_ALARM", "CAP_BLOCK_SUSPEND", "CAP_AUDIT_READ", "CAP_PERFMON", "CAP_BPF",
+    "CAP_CHECKPOINT_RESTORE",
+];
+
+// LSMs that provide /proc/<pid>/attr/current
+const LABEL_LSMS: [&str; 4] = ["selinux", "apparmor", "smack", "tomoyo"];
+
+/// Names of the bits set in a capability mask. Bits newer than this table show as "CAP_<bit>".
+pub fn capability_names(mask: u64) -> Vec<String> {
+    (0..64)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| CAPABILITY_NAMES.get(bit).map(|n| n.to_string()).unwrap_or_else(|| format!("CAP_{}", bit)))
+        .collect()
+}
+
+/// A capability mask of /proc/<pid>/status, e.g. the "000001ffffffffff" of "CapEff:".
+pub fn parse_cap_mask(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.trim(), 16).ok()
+}
+
+fn capability_set(hex: &str) -> CapabilitySet {
+    let mask = parse_cap_mask(hex).unwrap_or(0);
+    CapabilitySet { mask, names: capability_names(mask) }
+}
+
+/// Fill the capability, seccomp and NoNewPrivs fields of `ctx` from /proc/<pid>/status.
+pub fn parse_status(content: &str, ctx: &mut SecurityContext) {
+    for line in content.lines() {
+        let (key, value) = match line.split_once(':') { Some(p) => p, None => continue };
+        let value = value.trim();
+        match key {
+            "CapInh" => ctx.inheritable = capability_set(value),
+            "CapPrm" => ctx.permitted = capability_set(value),
+            "CapEff" => ctx.effective = capability_set(value),
+            "CapBnd" => ctx.bounding = capability_set(value),
+            "CapAmb" => ctx.ambient = capability_set(value),
+            "NoNewPrivs" => ctx.no_new_privs = Some(value == "1"),
+            "Seccomp" => ctx.seccomp = match value {
+                "0" => Some(SeccompMode::Disabled),
+                "1" => Some(SeccompMode::Strict),
+                "2" => Some(SeccompMode::Filter),
+                _ => None,
+            },
+            "Seccomp_filters" => ctx.seccomp_filters = value.parse().ok(),
+            _ => {}
+        }
+    }
+}
+
+/// The first label-providing LSM in the boot order of /sys/kernel/security/lsm.
+fn active_lsm() -> Option<String> {
+    let list = fs::read_to_string("/sys/kernel/security/lsm").ok()?;
+    list.trim().split(',').find(|l| LABEL_LSMS.contains(l)).map(|l| l.to_string())
+}
+
+/// The label of attr/current, e.g. "system_u:system_r:sshd_t:s0" or "docker-default (enforce)".
+/// None when no LSM exposes a label for the process.
+fn read_label(pid: u32) -> Option<String> {
+    let raw = fs::read(format!("/proc/{}/attr/current", pid)).ok()?;
+    let label = String::from_utf8_lossy(&raw).trim_end_matches(['\0', '\n']).to_string();
+    if label.is_empty() { None } else { Some(label) }
+}
+
+pub fn get_security_context_impl(pid: u32) -> Result<SecurityContext, ProcessControlError> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).map_err(|e| procfs::read_error(pid, &e))?;
+    let mut ctx = SecurityContext { pid, ..Default::default() };
+    parse_status(&status, &mut ctx);
+    ctx.lsm_label = read_label(pid);
+    if ctx.lsm_label.is_some() { ctx.lsm = active_lsm(); }
+    Ok(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_fills_capabilities_and_seccomp() {
+        let content = "Name:\tping\nUid:\t1000\t1000\t1000\t1000\nCapInh:\t0000000000000000\n\
+                       CapPrm:\t0000000000003000\nCapEff:\t0000000000002000\nCapBnd:\t000001ffffffffff\n\
+                       CapAmb:\t0000000000000000\nNoNewPrivs:\t1\nSeccomp:\t2\nSeccomp_filters:\t3\n";
+        let mut ctx = SecurityContext::default();
+        parse_status(content, &mut ctx);
+        assert_eq!(ctx.effective.mask, 0x2000);
+        assert_eq!(ctx.effective.names, ["CAP_NET_RAW"]);
+        assert_eq!(ctx.permitted.names, ["CAP_NET_ADMIN", "CAP_NET_RAW"]);
+        assert_eq!(ctx.bounding.names.len(), 41);
+        assert!(ctx.inheritable.names.is_empty() && ctx.ambient.names.is_empty());
+        assert_eq!(ctx.no_new_privs, Some(true));
+        assert_eq!(ctx.seccomp, Some(SeccompMode::Filter));
+        assert_eq!(ctx.seccomp_filters, Some(3));
+    }
+
+    #[test]
+    fn status_without_seccomp_leaves_it_unset() {
+        let mut ctx = SecurityContext::default();
+        parse_status("CapEff:\tzz\nNoNewPrivs:\t0\n", &mut ctx);
+        assert_eq!(ctx.effective.mask, 0);
+        assert_eq!(ctx.no_new_privs, Some(false));
+        assert_eq!((ctx.seccomp, ctx.seccomp_filters), (None, None));
+    }
+
+    #[test]
+    fn capability_names_past_the_table_use_the_bit_number() {
+        assert_eq!(capability_names(1 << 0 | 1 << 40), ["CAP_CHOWN", "CAP_CHECKPOINT_RESTORE"]);
+        assert_eq!(capability_names(1 << 41 | 1 << 63), ["CAP_41", "CAP_63"]);
+        assert!(capability_names(0).is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+// Signal delivery and graceful termination.
+use crate::api::simple::{ProcessControlError, ProcessControlErrorKind, ProcessSignal, TerminationOutcome};
+use super::procfs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+// How long to wait for the kernel to tear the process down after SIGKILL.
+pub const KILL_GRACE: Duration = Duration::from_secs(2);
+
+fn signal_number(signal: ProcessSignal) -> libc::c_int {
+    match signal {
+        ProcessSignal::Hangup => libc::SIGHUP,
+        ProcessSignal::Interrupt => libc::SIGINT,
+        ProcessSignal::Quit => libc::SIGQUIT,
+        ProcessSignal::Abort => libc::SIGABRT,
+        ProcessSignal::Kill => libc::SIGKILL,
+        ProcessSignal::User1 => libc::SIGUSR1,
+        ProcessSignal::User2 => libc::SIGUSR2,
+        ProcessSignal::Terminate => libc::SIGTERM,
+        ProcessSignal::Continue => libc::SIGCONT,
+        ProcessSignal::Stop => libc::SIGSTOP,
+        ProcessSignal::TerminalStop => libc::SIGTSTP,
+    }
+}
+
+// PF_KTHREAD from include/linux/sched.h
+const PF_KTHREAD: u64 = 0x0020_0000;
+
+pub fn os_error(pid: u32) -> ProcessControlError {
+    errno_error(pid, std::io::Error::last_os_error().raw_os_error().unwrap_or(0))
+}
+
+pub fn errno_error(pid: u32, code: i32) -> ProcessControlError {
+    let kind = match code {
+        libc::EPERM | libc::EACCES => ProcessControlErrorKind::PermissionDenied,
+        libc::ESRCH => ProcessControlErrorKind::NoSuchProcess,
+        libc::EINVAL => ProcessControlErrorKind::InvalidArgument,
+        _ => ProcessControlErrorKind::Other,
+    };
+    // The code is shown separately, so use the bare description.
+    ProcessControlError::new(kind, code, format!("process {}: {}", pid, nix::errno::Errno::from_raw(code).desc()))
+}
+
+// kill(2) treats 0 and negative pids as process groups, so those never reach it.
+// Kernel threads ignore signals, so report them instead of pretending to succeed.
+pub fn check_target(pid: u32) -> Result<procfs::PidStat, ProcessControlError> {
+    if pid == 0 || pid > i32::MAX as u32 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("invalid pid {}", pid)));
+    }
+    let stat = procfs::read_pid_stat(pid).ok_or_else(|| {
+        ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid))
+    })?;
+    if stat.flags & PF_KTHREAD != 0 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::ProtectedProcess, 0, format!("process {} is a kernel thread", pid)));
+    }
+    Ok(stat)
+}
+
+pub fn raw_signal(pid: u32, signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    if unsafe { libc::kill(pid as i32, signal_number(signal)) } == 0 { Ok(()) } else { Err(os_error(pid)) }
+}
+
+pub fn send_signal_impl(pid: u32, signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    check_target(pid)?;
+    raw_signal(pid, signal)
+}
+
+pub fn kill_process_impl(pid: u32) -> Result<(), ProcessControlError> {
+    send_signal_impl(pid, ProcessSignal::Kill)
+}
+
+// A process counts as gone once its /proc entry disappears, it is a zombie waiting
+// to be reaped, or the pid now belongs to a different process.
+pub fn has_exited(pid: u32, starttime: u64) -> bool {
+    match procfs::read_pid_stat(pid) {
+        Some(stat) => stat.state == 'Z' || stat.starttime != starttime,
+        None => true,
+    }
+}
+
+pub fn wait_for_exit(pid: u32, starttime: u64, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if has_exited(pid, starttime) { return true; }
+        if Instant::now() >= deadline { return false; }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+pub fn terminate_gracefully_impl(pid: u32, timeout_ms: u64) -> Result<TerminationOutcome, ProcessControlError> {
+    // Remember which process we are dealing with, so a reused pid is never killed.
+    let stat = check_target(pid)?;
+    if stat.state == 'Z' {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} has already exited", pid)));
+    }
+    raw_signal(pid, ProcessSignal::Terminate)?;
+    // A stopped process cannot run its SIGTERM handler until it is continued.
+    let _ = raw_signal(pid, ProcessSignal::Continue);
+    if wait_for_exit(pid, stat.starttime, Duration::from_millis(timeout_ms)) {
+        return Ok(TerminationOutcome::Terminated);
+    }
+    // It may exit between the timeout and SIGKILL; that still counts as terminated.
+    match raw_signal(pid, ProcessSignal::Kill) {
+        Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => return Ok(TerminationOutcome::Terminated),
+        r => r?,
+    }
+    if wait_for_exit(pid, stat.starttime, KILL_GRACE) {
+        Ok(TerminationOutcome::Killed)
+    } else {
+        Err(ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after SIGKILL", pid)))
+    }
+}
@@ -0,0 +1,145 @@
+// Suspend and resume, by SIGSTOP/SIGCONT or the cgroup v2 freezer.
+use crate::api::simple::{ProcessControlError, ProcessControlErrorKind, ProcessSignal};
+use super::{cgroups, procfs, signal};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuspendMethod {
+    Signal,
+    Freezer,
+}
+
+lazy_static::lazy_static! {
+    // Processes suspended through this API: pid -> (starttime, method). The kernel does
+    // not tell a SIGSTOP from us apart from job control, so status relies on this.
+    static ref SUSPENDED: Mutex<HashMap<u32, (u64, SuspendMethod)>> = Mutex::new(HashMap::new());
+}
+
+// The cgroup v2 directory of a process.
+fn cgroup_dir(pid: u32) -> Option<PathBuf> {
+    Some(cgroups::cgroup_root().join(cgroups::process_cgroup(pid)?.trim_start_matches('/')))
+}
+
+// Processes in a cgroup and all cgroups below it, which cgroup.freeze also freezes.
+fn cgroup_members(dir: &Path, out: &mut Vec<u32>) {
+    if let Ok(procs) = fs::read_to_string(dir.join("cgroup.procs")) {
+        out.extend(procs.lines().filter_map(|l| l.trim().parse::<u32>().ok()));
+    }
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) { cgroup_members(&entry.path(), out); }
+        }
+    }
+}
+
+// The cgroup of `pid` if freezing it would only affect `targets`, i.e. the process
+// (or tree) has a cgroup of its own, such as a systemd service or scope.
+fn own_cgroup(pid: u32, targets: &HashSet<u32>) -> Option<PathBuf> {
+    let dir = cgroup_dir(pid)?;
+    if dir == cgroups::cgroup_root() || !dir.join("cgroup.freeze").exists() { return None; }
+    let mut members = Vec::new();
+    cgroup_members(&dir, &mut members);
+    if members.iter().all(|p| targets.contains(p)) { Some(dir) } else { None }
+}
+
+fn set_frozen(dir: &Path, frozen: bool) -> std::io::Result<()> {
+    fs::write(dir.join("cgroup.freeze"), if frozen { "1" } else { "0" })
+}
+
+fn is_frozen(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("cgroup.freeze")).map(|s| s.trim() == "1").unwrap_or(false)
+}
+
+// Signal every target; children may exit or change owner in the meantime, which is not an error.
+fn signal_all(root: u32, children: &[u32], signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    signal::raw_signal(root, signal)?;
+    for &pid in children {
+        match signal::raw_signal(pid, signal) {
+            Err(e) if e.kind != ProcessControlErrorKind::NoSuchProcess && e.kind != ProcessControlErrorKind::PermissionDenied => return Err(e),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+pub fn suspend_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    let stat = signal::check_target(pid)?;
+    if stat.state == 'Z' {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} has already exited", pid)));
+    }
+    let mut children = if include_children { procfs::descendants(pid) } else { Vec::new() };
+    let targets: HashSet<u32> = children.iter().copied().chain([pid]).collect();
+
+    // A frozen cgroup also holds processes forked while suspending; fall back to signals
+    // when the cgroup is shared or not writable.
+    let method = match own_cgroup(pid, &targets) {
+        Some(dir) if set_frozen(&dir, true).is_ok() => SuspendMethod::Freezer,
+        _ => {
+            signal_all(pid, &children, ProcessSignal::Stop)?;
+            // Children forked before the parent stopped are caught by a second pass.
+            if include_children {
+                let late: Vec<u32> = procfs::descendants(pid).into_iter().filter(|p| !targets.contains(p)).collect();
+                signal_all(pid, &late, ProcessSignal::Stop)?;
+                children.extend(late);
+            }
+            SuspendMethod::Signal
+        }
+    };
+
+    let mut suspended = SUSPENDED.lock().unwrap();
+    for p in children.into_iter().chain([pid]) {
+        if let Some(st) = procfs::read_pid_stat(p) { suspended.insert(p, (st.starttime, method)); }
+    }
+    Ok(())
+}
+
+fn thaw(dir: &Path) -> Result<(), ProcessControlError> {
+    if !is_frozen(dir) { return Ok(()); }
+    set_frozen(dir, false).map_err(|e| {
+        ProcessControlError::new(ProcessControlErrorKind::PermissionDenied, e.raw_os_error().unwrap_or(0), format!("cannot thaw cgroup {}: {}", dir.display(), e))
+    })
+}
+
+pub fn resume_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    let stat = signal::check_target(pid)?;
+    let children = if include_children { procfs::descendants(pid) } else { Vec::new() };
+    let mut targets: HashSet<u32> = children.iter().copied().chain([pid]).collect();
+    let frozen_by_us = matches!(SUSPENDED.lock().unwrap().get(&pid), Some(&(st, SuspendMethod::Freezer)) if st == stat.starttime);
+    if frozen_by_us {
+        // The freezer stopped the whole cgroup, so it is thawed whatever include_children
+        // says; the entries are kept when that fails, so the process still shows as suspended.
+        let dir = cgroup_dir(pid).ok_or_else(|| {
+            ProcessControlError::new(ProcessControlErrorKind::Other, 0, format!("cgroup of process {} was frozen but can no longer be found", pid))
+        })?;
+        thaw(&dir)?;
+        let mut members = Vec::new();
+        cgroup_members(&dir, &mut members);
+        targets.extend(members);
+    } else if let Some(dir) = own_cgroup(pid, &targets) {
+        thaw(&dir)?;
+    }
+    // SIGCONT is harmless for processes that were not stopped.
+    signal_all(pid, &children, ProcessSignal::Continue)?;
+    let mut suspended = SUSPENDED.lock().unwrap();
+    for p in &targets { suspended.remove(p); }
+    Ok(())
+}
+
+/// Whether the process was suspended through this API and still is.
+pub fn is_suspended(pid: u32, stat: &procfs::PidStat) -> bool {
+    match SUSPENDED.lock().unwrap().get(&pid) {
+        Some(&(starttime, SuspendMethod::Signal)) => starttime == stat.starttime && stat.state == 'T',
+        Some(&(starttime, SuspendMethod::Freezer)) => {
+            starttime == stat.starttime && cgroup_dir(pid).map(|d| is_frozen(&d)).unwrap_or(false)
+        }
+        None => false,
+    }
+}
+
+/// Forget processes that have exited.
+pub fn prune_suspended(live: &HashSet<u32>) {
+    SUSPENDED.lock().unwrap().retain(|pid, _| live.contains(pid));
+}
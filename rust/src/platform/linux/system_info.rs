@@ -1,4 +1,5 @@
 use crate::api::simple::SystemInfo;
+use super::procfs;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn get_system_info_impl() -> SystemInfo {
@@ -46,11 +47,7 @@ pub fn get_system_info_impl() -> SystemInfo {
         None
     }).unwrap_or(0);
 
-    let boot_time = std::fs::read_to_string("/proc/stat").ok().and_then(|d| {
-        d.lines().find(|l| l.starts_with("btime "))
-            .and_then(|l| l.split_whitespace().nth(1))
-            .and_then(|s| s.parse::<u64>().ok())
-    }).unwrap_or(0);
+    let boot_time = procfs::read_boot_time().unwrap_or(0);
 
     let uptime = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs().saturating_sub(boot_time);
 
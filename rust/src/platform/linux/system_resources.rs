@@ -1,6 +1,9 @@
-use crate::api::simple::{DiskInfo, NetworkInfo, SystemResourceInfo};
-use std::path::Path;
-use nix::sys::statvfs;
+use crate::api::simple::{CpuTimesBreakdown, DiskInfo, MountFilter, SystemResourceInfo};
+use super::{mounts, network};
+use super::procfs::{self, CpuTimes};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use std::fs;
 
 pub fn get_system_resources_impl() -> SystemResourceInfo {
@@ -21,16 +24,18 @@ pub fn get_system_resources_impl() -> SystemResourceInfo {
             }
         }
     }
-    let mem_used = if mem_total > mem_available { mem_total - mem_available } else { 0 };
-    let swap_used = if swap_total > swap_free { swap_total - swap_free } else { 0 };
+    let mem_used = mem_total.saturating_sub(mem_available);
+    let swap_used = swap_total.saturating_sub(swap_free);
 
-    let (cpu_usage, cpu_per_core) = read_cpu_usage();
-    let disk_usage = get_disk_info();
-    let network_usage = get_network_info();
+    let cpu = read_cpu_usage();
+    let disk_usage = mounts::get_disk_info(&MountFilter::default());
+    let network_usage = network::get_network_info();
 
     SystemResourceInfo {
-        cpu_usage,
-        cpu_per_core,
+        cpu_usage: cpu.total_pct,
+        cpu_per_core: cpu.per_core_pct,
+        cpu_times: cpu.times,
+        cpu_times_per_core: cpu.per_core_times,
         memory_total: mem_total,
         memory_used: mem_used,
         memory_available: mem_available,
@@ -42,78 +47,197 @@ pub fn get_system_resources_impl() -> SystemResourceInfo {
     }
 }
 
-fn read_cpu_usage() -> (f64, Vec<f64>) {
-    // Note: True CPU percentage needs delta over time. Here we parse current jiffies and return ratios of busy/total.
-    if let Ok(stat) = fs::read_to_string("/proc/stat") {
-        let mut per_core = Vec::new();
-        let mut total_busy: u64 = 0;
-        let mut total_all: u64 = 0;
-        for line in stat.lines() {
-            if line.starts_with("cpu") {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts[0] == "cpu" { // aggregate
-                    if parts.len() >= 8 {
-                        let user: u64 = parts[1].parse().unwrap_or(0);
-                        let nice: u64 = parts[2].parse().unwrap_or(0);
-                        let system: u64 = parts[3].parse().unwrap_or(0);
-                        let idle: u64 = parts[4].parse().unwrap_or(0);
-                        let iowait: u64 = parts[5].parse().unwrap_or(0);
-                        let irq: u64 = parts[6].parse().unwrap_or(0);
-                        let softirq: u64 = parts[7].parse().unwrap_or(0);
-                        let busy = user + nice + system + irq + softirq;
-                        total_busy = busy;
-                        total_all = busy + idle + iowait;
-                    }
-                } else { // per core
-                    if parts.len() >= 8 {
-                        let user: u64 = parts[1].parse().unwrap_or(0);
-                        let nice: u64 = parts[2].parse().unwrap_or(0);
-                        let system: u64 = parts[3].parse().unwrap_or(0);
-                        let idle: u64 = parts[4].parse().unwrap_or(0);
-                        let iowait: u64 = parts[5].parse().unwrap_or(0);
-                        let irq: u64 = parts[6].parse().unwrap_or(0);
-                        let softirq: u64 = parts[7].parse().unwrap_or(0);
-                        let busy = user + nice + system + irq + softirq;
-                        let all = busy + idle + iowait;
-                        if all > 0 { per_core.push((busy as f64 / all as f64) * 100.0); }
-                    }
-                }
-            }
+// Result of one CPU sample: busy percentages plus the per-category breakdown.
+#[derive(Clone, Default)]
+struct CpuSample {
+    total_pct: f64,
+    per_core_pct: Vec<f64>,
+    times: CpuTimesBreakdown,
+    per_core_times: Vec<CpuTimesBreakdown>,
+}
+
+// Previous /proc/stat reading, kept so usage covers the interval between calls
+// rather than the time since boot.
+struct CpuSampleCache {
+    last_instant: Instant,
+    last_total: CpuTimes,
+    last_per_cpu: HashMap<usize, CpuTimes>,
+    last_sample: CpuSample,
+}
+
+lazy_static::lazy_static! {
+    static ref CPU_CACHE: Mutex<Option<CpuSampleCache>> = Mutex::new(None);
+}
+
+// Busy percentage and category breakdown between two readings. Returns None when the
+// counters went backwards (wraparound, or a CPU that went offline and came back reset).
+fn interval_usage(prev: &CpuTimes, cur: &CpuTimes) -> Option<(f64, CpuTimesBreakdown)> {
+    if cur.total() < prev.total() { return None; }
+    let total = cur.total() - prev.total();
+    if total == 0 { return Some((0.0, CpuTimesBreakdown::default())); }
+    // iowait is known to occasionally decrease on its own, so every column is
+    // compared with saturation.
+    let pct = |c: u64, p: u64| (c.saturating_sub(p) as f64 / total as f64 * 100.0).clamp(0.0, 100.0);
+    let busy = pct(cur.busy(), prev.busy());
+    // user and nice include guest time; split it out so the categories sum to 100.
+    let guest = pct(cur.guest, prev.guest);
+    let guest_nice = pct(cur.guest_nice, prev.guest_nice);
+    let times = CpuTimesBreakdown {
+        user: (pct(cur.user, prev.user) - guest).max(0.0),
+        nice: (pct(cur.nice, prev.nice) - guest_nice).max(0.0),
+        system: pct(cur.system, prev.system),
+        idle: pct(cur.idle, prev.idle),
+        iowait: pct(cur.iowait, prev.iowait),
+        irq: pct(cur.irq, prev.irq),
+        softirq: pct(cur.softirq, prev.softirq),
+        steal: pct(cur.steal, prev.steal),
+        guest,
+        guest_nice,
+    };
+    Some((busy, times))
+}
+
+fn read_cpu_usage() -> CpuSample {
+    const MIN_INTERVAL: Duration = Duration::from_millis(400);
+    let mut cache = CPU_CACHE.lock().unwrap();
+    let now = Instant::now();
+
+    if let Some(ref existing) = *cache {
+        if now.duration_since(existing.last_instant) < MIN_INTERVAL {
+            return existing.last_sample.clone();
         }
-        if total_all > 0 { return ((total_busy as f64 / total_all as f64) * 100.0, per_core); }
     }
-    (0.0, Vec::new())
+
+    let (cur_total, cur_per_cpu) = match fs::read_to_string("/proc/stat") {
+        Ok(stat) => procfs::parse_stat_cpus(&stat),
+        Err(_) => return CpuSample::default(),
+    };
+    let cur_total = match cur_total { Some(t) => t, None => return CpuSample::default() };
+
+    let sample = sample_between(cache.as_ref(), &cur_total, &cur_per_cpu);
+    *cache = Some(CpuSampleCache {
+        last_instant: now,
+        last_total: cur_total,
+        last_per_cpu: cur_per_cpu.into_iter().collect(),
+        last_sample: sample.clone(),
+    });
+    sample
 }
 
-fn get_disk_info() -> Vec<DiskInfo> {
-    let mut disks = Vec::new();
-    if let Ok(stat) = statvfs::statvfs(Path::new("/")) {
-        let total_space = stat.blocks() * stat.fragment_size();
-        let available_space = stat.blocks_available() * stat.fragment_size();
-        let used_space = total_space - available_space;
-        disks.push(DiskInfo { name: "/".to_string(), mount_point: "/".to_string(), total_space, used_space, available_space });
+// Usage between the cached reading and the current one. A core whose counters went
+// backwards keeps its previous result.
+fn sample_between(prev: Option<&CpuSampleCache>, cur_total: &CpuTimes, cur_per_cpu: &[(usize, CpuTimes)]) -> CpuSample {
+    // Without a previous reading, diff against zero, i.e. the average since boot.
+    let zero = CpuTimes::default();
+    let (total_pct, times) = interval_usage(prev.map(|c| &c.last_total).unwrap_or(&zero), cur_total)
+        .or(prev.map(|c| (c.last_sample.total_pct, c.last_sample.times.clone())))
+        .unwrap_or_default();
+
+    // Index per-core results by CPU id, so a CPU going offline leaves a zero gap instead
+    // of shifting every later core down. A CPU that just came online starts from zero.
+    let slots = cur_per_cpu.iter().map(|(id, _)| id + 1).max().unwrap_or(0);
+    let mut per_core_pct = vec![0.0; slots];
+    let mut per_core_times = vec![CpuTimesBreakdown::default(); slots];
+    for (id, cur) in cur_per_cpu {
+        let last = prev.and_then(|c| c.last_per_cpu.get(id));
+        let usage = match last {
+            Some(p) => interval_usage(p, cur).or(prev.and_then(|c| {
+                let pct = c.last_sample.per_core_pct.get(*id)?;
+                Some((*pct, c.last_sample.per_core_times.get(*id)?.clone()))
+            })),
+            None if prev.is_none() => interval_usage(&zero, cur),
+            None => None,
+        };
+        if let Some((pct, t)) = usage {
+            per_core_pct[*id] = pct;
+            per_core_times[*id] = t;
+        }
     }
-    disks
+
+    CpuSample { total_pct, per_core_pct, times, per_core_times }
 }
 
-fn get_network_info() -> NetworkInfo {
-    let mut net = NetworkInfo { bytes_sent: 0, bytes_received: 0, packets_sent: 0, packets_received: 0 };
-    if let Ok(content) = fs::read_to_string("/proc/net/dev") {
-        for line in content.lines().skip(2) {
-            if let Some((_iface, data)) = line.split_once(':') {
-                let parts: Vec<&str> = data.split_whitespace().collect();
-                if parts.len() >= 16 {
-                    let rx_bytes = parts[0].parse::<u64>().unwrap_or(0);
-                    let rx_packets = parts[1].parse::<u64>().unwrap_or(0);
-                    let tx_bytes = parts[8].parse::<u64>().unwrap_or(0);
-                    let tx_packets = parts[9].parse::<u64>().unwrap_or(0);
-                    net.bytes_received += rx_bytes;
-                    net.packets_received += rx_packets;
-                    net.bytes_sent += tx_bytes;
-                    net.packets_sent += tx_packets;
-                }
-            }
+pub fn get_disks_impl(filter: MountFilter) -> Vec<DiskInfo> {
+    mounts::get_disk_info(&filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cache state after a first reading of `stat`, as read_cpu_usage would leave it.
+    fn cache_after(stat: &str) -> CpuSampleCache {
+        let (total, per_cpu) = procfs::parse_stat_cpus(stat);
+        let total = total.unwrap();
+        CpuSampleCache {
+            last_instant: Instant::now(),
+            last_sample: sample_between(None, &total, &per_cpu),
+            last_total: total,
+            last_per_cpu: per_cpu.into_iter().collect(),
         }
     }
-    net
+
+    fn sample(prev: &CpuSampleCache, stat: &str) -> CpuSample {
+        let (total, per_cpu) = procfs::parse_stat_cpus(stat);
+        sample_between(Some(prev), &total.unwrap(), &per_cpu)
+    }
+
+    #[test]
+    fn usage_covers_the_interval_between_readings() {
+        let first = cache_after("cpu  1000 0 500 8000 100 0 0 0 0 0\n\
+                                 cpu0 500 0 250 4000 50 0 0 0 0 0\n\
+                                 cpu1 500 0 250 4000 50 0 0 0 0 0\n\
+                                 intr 12345\n");
+        let s = sample(&first, "cpu  1030 0 510 8050 110 0 0 0 0 0\n\
+                                cpu0 520 0 255 4025 50 0 0 0 0 0\n\
+                                cpu1 510 0 255 4025 60 0 0 0 0 0\n");
+        assert_eq!(s.total_pct, 40.0);
+        assert_eq!(s.per_core_pct, [50.0, 30.0]);
+        assert_eq!(s.per_core_times[1].iowait, 20.0);
+    }
+
+    #[test]
+    fn offline_cpu_leaves_a_gap() {
+        let first = cache_after("cpu  300 0 0 900 0 0 0 0 0 0\n\
+                                 cpu0 100 0 0 300 0 0 0 0 0 0\n\
+                                 cpu1 100 0 0 300 0 0 0 0 0 0\n\
+                                 cpu2 100 0 0 300 0 0 0 0 0 0\n");
+        // cpu1 went offline; cpu2 keeps its own slot instead of moving down.
+        let s = sample(&first, "cpu  350 0 0 950 0 0 0 0 0 0\n\
+                                cpu0 150 0 0 300 0 0 0 0 0 0\n\
+                                cpu2 100 0 0 350 0 0 0 0 0 0\n");
+        assert_eq!(s.per_core_pct, [100.0, 0.0, 0.0]);
+        assert_eq!(s.per_core_times[2].idle, 100.0);
+        assert_eq!(s.total_pct, 50.0);
+    }
+
+    #[test]
+    fn counters_going_backwards_keep_the_previous_result() {
+        let first = cache_after("cpu  200 0 0 600 0 0 0 0 0 0\n\
+                                 cpu0 100 0 0 300 0 0 0 0 0 0\n\
+                                 cpu1 100 0 0 300 0 0 0 0 0 0\n");
+        // cpu1 was reset after coming back online; cpu0 moved on normally.
+        let stat = "cpu  160 0 0 500 0 0 0 0 0 0\n\
+                    cpu0 150 0 0 350 0 0 0 0 0 0\n\
+                    cpu1 10 0 0 150 0 0 0 0 0 0\n";
+        let (total, per_cpu) = procfs::parse_stat_cpus(stat);
+        assert!(interval_usage(&first.last_total, &total.unwrap()).is_none());
+        assert!(interval_usage(&first.last_per_cpu[&1], &per_cpu[1].1).is_none());
+        let s = sample(&first, stat);
+        assert_eq!(s.total_pct, 25.0);
+        assert_eq!(s.per_core_pct, [50.0, 25.0]);
+    }
+
+    #[test]
+    fn breakdown_sums_to_100_without_counting_guest_twice() {
+        let (prev, _) = procfs::parse_stat_cpus("cpu  1000 100 500 5000 50 20 30 10 200 40\n");
+        // 40 user ticks of which 10 ran a guest, 10 nice of which 5 ran a niced guest.
+        let (cur, _) = procfs::parse_stat_cpus("cpu  1040 110 510 5020 55 25 35 15 210 45\n");
+        let (busy, t) = interval_usage(&prev.unwrap(), &cur.unwrap()).unwrap();
+        assert_eq!((t.user, t.nice, t.guest, t.guest_nice), (30.0, 5.0, 10.0, 5.0));
+        assert_eq!((t.system, t.idle, t.iowait, t.irq, t.softirq, t.steal), (10.0, 20.0, 5.0, 5.0, 5.0, 5.0));
+        let sum = t.user + t.nice + t.system + t.idle + t.iowait + t.irq + t.softirq + t.steal + t.guest + t.guest_nice;
+        assert_eq!(sum, 100.0);
+        assert_eq!(busy, 75.0);
+    }
 }
@@ -0,0 +1,86 @@
+// Threads of a process from /proc/<pid>/task, with CPU usage sampled between calls.
+use crate::api::simple::{CpuUsageMode, ProcessControlError, ProcessControlErrorKind, ThreadInfo};
+use super::{process, procfs};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Samples of processes nobody has looked at for this long are dropped.
+const SAMPLE_TTL: Duration = Duration::from_secs(60);
+
+struct ThreadSample {
+    starttime: u64,
+    cpu_ticks: u64,
+}
+
+struct ThreadSampler {
+    last_instant: Instant,
+    samples: HashMap<u32, ThreadSample>,
+}
+
+lazy_static::lazy_static! {
+    // Keyed by pid; each process is sampled independently, whenever get_threads is called for it.
+    static ref THREAD_CPU_CACHE: Mutex<HashMap<u32, ThreadSampler>> = Mutex::new(HashMap::new());
+}
+
+// voluntary_ctxt_switches and nonvoluntary_ctxt_switches from the task's status file.
+fn context_switches(pid: u32, tid: u32) -> (u64, u64) {
+    let status = fs::read_to_string(format!("/proc/{}/task/{}/status", pid, tid)).unwrap_or_default();
+    let value = |key: &str| {
+        status.lines().find_map(|l| l.strip_prefix(key)).and_then(|v| v.trim().parse().ok()).unwrap_or(0)
+    };
+    (value("voluntary_ctxt_switches:"), value("nonvoluntary_ctxt_switches:"))
+}
+
+pub fn get_threads_impl(pid: u32) -> Result<Vec<ThreadInfo>, ProcessControlError> {
+    if procfs::read_pid_stat(pid).is_none() {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid)));
+    }
+    let ticks = procfs::clock_ticks();
+    let cores = num_cpus::get().max(1) as f64;
+    let mode = process::cpu_usage_mode();
+    let now = Instant::now();
+    let mut cache = THREAD_CPU_CACHE.lock().unwrap();
+    cache.retain(|_, s| now.duration_since(s.last_instant) < SAMPLE_TTL);
+    let prev = cache.get(&pid);
+    let elapsed_ticks = prev.map(|p| now.duration_since(p.last_instant).as_secs_f64() * ticks as f64).unwrap_or(0.0);
+
+    let mut out = Vec::new();
+    let mut samples = HashMap::new();
+    for tid in procfs::list_tids(pid) {
+        let stat = match fs::read_to_string(format!("/proc/{}/task/{}/stat", pid, tid)).ok().and_then(|s| procfs::parse_pid_stat(&s)) {
+            Some(s) => s,
+            None => continue, // exited while listing
+        };
+        let cpu_ticks = stat.utime + stat.stime;
+        // A thread saturating one core reads 100 in SingleCore mode.
+        let mut cpu_usage = match prev.and_then(|p| p.samples.get(&tid)) {
+            Some(s) if s.starttime == stat.starttime && elapsed_ticks > 0.0 => {
+                (cpu_ticks.saturating_sub(s.cpu_ticks) as f64 / elapsed_ticks * 100.0).clamp(0.0, 100.0)
+            }
+            _ => 0.0,
+        };
+        if mode == CpuUsageMode::Machine { cpu_usage /= cores; }
+        samples.insert(tid, ThreadSample { starttime: stat.starttime, cpu_ticks });
+
+        let state = process::decode_state(stat.state);
+        let (voluntary, involuntary) = context_switches(pid, tid);
+        out.push(ThreadInfo {
+            tid,
+            name: fs::read_to_string(format!("/proc/{}/task/{}/comm", pid, tid)).map(|s| s.trim_end().to_string()).unwrap_or_default(),
+            status: state.label().to_string(),
+            state,
+            cpu_usage,
+            user_time_ms: stat.utime * 1000 / ticks,
+            system_time_ms: stat.stime * 1000 / ticks,
+            last_cpu: stat.processor,
+            priority: stat.priority,
+            nice: stat.nice,
+            voluntary_context_switches: voluntary,
+            involuntary_context_switches: involuntary,
+        });
+    }
+    cache.insert(pid, ThreadSampler { last_instant: now, samples });
+    Ok(out)
+}
@@ -0,0 +1,109 @@
+// User and group names from /etc/passwd and /etc/group.
+use crate::api::simple::ProcessCredentials;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+
+const PASSWD: &str = "/etc/passwd";
+const GROUP: &str = "/etc/group";
+
+/// Modification time and size of a database file when it was last parsed.
+type FileStamp = Option<(SystemTime, u64)>;
+
+#[derive(Default)]
+pub struct NameCache {
+    passwd_stamp: FileStamp,
+    group_stamp: FileStamp,
+    users: HashMap<u32, String>,
+    groups: HashMap<u32, String>,
+}
+
+lazy_static::lazy_static! {
+    static ref NAME_CACHE: Mutex<NameCache> = Mutex::new(NameCache::default());
+}
+
+fn stamp(path: &str) -> FileStamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// Map the id field (third) of passwd or group lines to the name field (first).
+/// An id listed twice resolves to its first entry, as getpwuid does.
+pub fn parse_id_names(content: &str) -> HashMap<u32, String> {
+    let mut names = HashMap::new();
+    for line in content.lines() {
+        // "+" and "-" lines are NIS compat entries, not local accounts
+        if line.is_empty() || line.starts_with('#') || line.starts_with('+') || line.starts_with('-') { continue; }
+        let mut fields = line.split(':');
+        let name = match fields.next() { Some(n) if !n.is_empty() => n, _ => continue };
+        if let Some(id) = fields.nth(1).and_then(|id| id.parse::<u32>().ok()) {
+            names.entry(id).or_insert_with(|| name.to_string());
+        }
+    }
+    names
+}
+
+impl NameCache {
+    // Both files are re-read only when their mtime or size changed since the last parse.
+    fn refresh(&mut self) {
+        let passwd = stamp(PASSWD);
+        if passwd != self.passwd_stamp {
+            self.users = fs::read_to_string(PASSWD).map(|s| parse_id_names(&s)).unwrap_or_default();
+            self.passwd_stamp = passwd;
+        }
+        let group = stamp(GROUP);
+        if group != self.group_stamp {
+            self.groups = fs::read_to_string(GROUP).map(|s| parse_id_names(&s)).unwrap_or_default();
+            self.group_stamp = group;
+        }
+    }
+
+    /// Name of `uid`, or the number itself when it has no entry (e.g. a container's
+    /// user, or accounts only known to LDAP/sssd), like ps shows them.
+    pub fn user(&self, uid: u32) -> String {
+        self.users.get(&uid).cloned().unwrap_or_else(|| uid.to_string())
+    }
+
+    pub fn group(&self, gid: u32) -> String {
+        self.groups.get(&gid).cloned().unwrap_or_else(|| gid.to_string())
+    }
+}
+
+/// The name cache, reloaded first if /etc/passwd or /etc/group changed.
+pub fn names() -> MutexGuard<'static, NameCache> {
+    let mut cache = NAME_CACHE.lock().unwrap();
+    cache.refresh();
+    cache
+}
+
+/// The real, effective and saved ids of a "Uid:" or "Gid:" line of /proc/<pid>/status.
+/// The fourth value, the filesystem id, is left out.
+pub fn parse_id_triple(value: &str) -> Option<(u32, u32, u32)> {
+    let mut ids = value.split_whitespace().map(|v| v.parse::<u32>());
+    match (ids.next(), ids.next(), ids.next()) {
+        (Some(Ok(real)), Some(Ok(effective)), Some(Ok(saved))) => Some((real, effective, saved)),
+        _ => None,
+    }
+}
+
+/// Credentials from the Uid, Gid and Groups values of /proc/<pid>/status, with names resolved.
+pub fn credentials(names: &NameCache, uids: (u32, u32, u32), gids: (u32, u32, u32), groups: Vec<u32>) -> ProcessCredentials {
+    ProcessCredentials {
+        real_uid: uids.0,
+        effective_uid: uids.1,
+        saved_uid: uids.2,
+        real_gid: gids.0,
+        effective_gid: gids.1,
+        saved_gid: gids.2,
+        real_user: names.user(uids.0),
+        effective_user: names.user(uids.1),
+        group: names.group(gids.1),
+        supplementary_group_names: groups.iter().map(|g| names.group(*g)).collect(),
+        supplementary_groups: groups,
+    }
+}
+
+pub fn get_current_user_impl() -> String {
+    names().user(unsafe { libc::geteuid() })
+}
@@ -2,6 +2,17 @@ mod process;
 mod system_info;
 mod system_resources;
 
-pub use process::{get_processes_impl, kill_process_impl};
+pub use process::{
+    find_processes_using_impl, get_connections_impl, get_cpu_affinity_impl, get_current_user_impl,
+    get_memory_maps_impl, get_open_files_impl, get_priority_impl, get_process_details_impl,
+    get_process_memory_impl, get_process_network_usage_impl, get_processes_impl, get_scheduler_impl,
+    get_security_context_impl, get_thread_scheduling_impl, get_threads_impl, kill_process_impl,
+    kill_process_tree_impl, resume_process_impl, send_signal_impl, set_cpu_affinity_impl,
+    set_cpu_usage_mode_impl, set_io_priority_impl, set_memory_metric_impl, set_priority_impl,
+    set_scheduler_impl, suspend_process_impl, terminate_gracefully_impl,
+};
 pub use system_info::get_system_info_impl;
-pub use system_resources::get_system_resources_impl;
+pub use system_resources::{
+    get_cgroup_tree_impl, get_disk_activity_impl, get_disks_impl, get_network_interfaces_impl,
+    get_system_resources_impl,
+};
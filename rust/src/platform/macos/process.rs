@@ -1,9 +1,92 @@
-use crate::api::simple::ProcessInfo;
+use crate::api::simple::{
+    ConnectionInfo, CpuUsageMode, EnvironmentVariable, IoPriority, KillOutcome, KillTreeStrategy, MemoryMaps,
+    MemoryMetric, OpenFileInfo, PathUsage, PriorityLevel, ProcessControlError, ProcessControlErrorKind,
+    ProcessCredentials, ProcessDetailError, ProcessDetailField, ProcessDetails, ProcessInfo,
+    ProcessDiskIo, ProcessKillResult, ProcessMemory, ProcessNetworkUsage, ProcessPriority, ProcessSignal, ProcessState,
+    SchedulerInfo, SecurityContext, TerminationOutcome, ThreadInfo, ThreadScheduling,
+};
+use libproc::libproc::bsd_info::BSDInfo;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use libproc::libproc::{proc_pid, task_info};
+use libproc::libproc::pid_rusage::{pidrusage, RUsageInfoV2};
 use libproc::processes;
 
+// pid -> (start time, bytes read, bytes written)
+type DiskIoSamples = HashMap<u32, (u64, u64, u64)>;
+
+lazy_static::lazy_static! {
+    // Processes stopped by suspend_process: pid -> start time, to tell them from job control stops.
+    static ref SUSPENDED: Mutex<HashMap<u32, u64>> = Mutex::new(HashMap::new());
+    // uid -> user name and gid -> group name. Accounts live in Directory Services rather
+    // than /etc/passwd, so lookups go through getpwuid_r/getgrgid_r and are kept.
+    static ref USER_NAMES: Mutex<HashMap<u32, String>> = Mutex::new(HashMap::new());
+    static ref GROUP_NAMES: Mutex<HashMap<u32, String>> = Mutex::new(HashMap::new());
+    // Disk bytes seen by the last get_processes, and when
+    static ref DISK_IO_CACHE: Mutex<(Option<Instant>, DiskIoSamples)> = Mutex::new((None, HashMap::new()));
+}
+
+fn c_name(ptr: *const libc::c_char) -> String {
+    unsafe { std::ffi::CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+}
+
+/// Name of `uid`, or the number itself when it has no account.
+fn user_name(uid: u32) -> String {
+    USER_NAMES.lock().unwrap().entry(uid).or_insert_with(|| {
+        let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result = std::ptr::null_mut();
+        let mut buf = vec![0 as libc::c_char; 4096];
+        let rc = unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+        if rc == 0 && !result.is_null() { c_name(pwd.pw_name) } else { uid.to_string() }
+    }).clone()
+}
+
+fn group_name(gid: u32) -> String {
+    GROUP_NAMES.lock().unwrap().entry(gid).or_insert_with(|| {
+        let mut grp: libc::group = unsafe { std::mem::zeroed() };
+        let mut result = std::ptr::null_mut();
+        let mut buf = vec![0 as libc::c_char; 4096];
+        let rc = unsafe { libc::getgrgid_r(gid, &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+        if rc == 0 && !result.is_null() { c_name(grp.gr_name) } else { gid.to_string() }
+    }).clone()
+}
+
+pub fn get_current_user_impl() -> String {
+    user_name(unsafe { libc::geteuid() })
+}
+
+// pbi_status values from <sys/proc.h>
+fn decode_state(status: u32) -> ProcessState {
+    match status {
+        1 => ProcessState::Idle,     // SIDL: being created
+        2 => ProcessState::Running,  // SRUN
+        3 => ProcessState::Sleeping, // SSLEEP
+        4 => ProcessState::Stopped,  // SSTOP
+        5 => ProcessState::Zombie,   // SZOMB
+        _ => ProcessState::Unknown,
+    }
+}
+
+// Bytes read from and written to storage by `pid`. Fails for other users' processes
+// unless running as root.
+fn read_disk_io(pid: u32) -> Option<ProcessDiskIo> {
+    let usage = pidrusage::<RUsageInfoV2>(pid as i32).ok()?;
+    Some(ProcessDiskIo {
+        read_bytes: usage.ri_diskio_bytesread,
+        write_bytes: usage.ri_diskio_byteswritten,
+        ..Default::default()
+    })
+}
+
 pub fn get_processes_impl() -> Vec<ProcessInfo> {
     let mut processes = Vec::new();
+    let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let now = Instant::now();
+    let mut io_cache = DISK_IO_CACHE.lock().unwrap();
+    let io_secs = io_cache.0.map(|last| now.duration_since(last).as_secs_f64()).unwrap_or(0.0);
+    let mut io_samples = HashMap::new();
     if let Ok(pids) = processes::pids_by_type(processes::ProcFilter::All) {
         for pid in pids {
             // pidinfo requires (pid: i32, arg: u64). For TaskAllInfo the arg is 0.
@@ -14,22 +97,449 @@ pub fn get_processes_impl() -> Vec<ProcessInfo> {
                 let bytes: Vec<u8> = raw[..nul_pos].iter().map(|&c| c as u8).collect();
                 let name = String::from_utf8_lossy(&bytes).to_string();
                 let memory_usage = task_info.ptinfo.pti_resident_size as u64;
+                let start_time = task_info.pbsd.pbi_start_tvsec;
+                let state = match decode_state(task_info.pbsd.pbi_status) {
+                    ProcessState::Stopped if SUSPENDED.lock().unwrap().get(&(pid as u32)) == Some(&start_time) => ProcessState::Suspended,
+                    s => s,
+                };
+                let disk_io = read_disk_io(pid).map(|mut io| {
+                    // A reused pid has a different start time and starts over.
+                    if let Some(&(_, read, write)) = io_cache.1.get(&pid).filter(|p| p.0 == start_time) {
+                        if io_secs > 0.0 {
+                            io.read_bytes_per_sec = io.read_bytes.saturating_sub(read) as f64 / io_secs;
+                            io.write_bytes_per_sec = io.write_bytes.saturating_sub(write) as f64 / io_secs;
+                        }
+                    }
+                    io_samples.insert(pid, (start_time, io.read_bytes, io.write_bytes));
+                    io
+                });
                 processes.push(ProcessInfo {
                     pid: pid as u32,
                     name: name.clone(),
                     cpu_usage: 0.0, // TODO: collect per‑process CPU usage (requires task threads info / sampling)
                     memory_usage,
+                    memory_metric: MemoryMetric::Rss,
                     parent_pid: Some(task_info.pbsd.pbi_ppid as u32),
-                    status: String::new(),
+                    status: state.label().to_string(),
+                    state,
                     command: name,
-                    start_time: 0,
+                    start_time,
+                    age: now_secs.saturating_sub(start_time),
+                    priority: PriorityLevel::from_nice(task_info.pbsd.pbi_nice),
+                    nice: task_info.pbsd.pbi_nice,
+                    io_priority: IoPriority::default(),
+                    last_cpu: None,
+                    thread_count: task_info.ptinfo.pti_threadnum as u32,
+                    disk_io,
+                    user: Some(user_name(task_info.pbsd.pbi_uid)),
+                    // Supplementary groups are only in the kinfo_proc ucred (sysctl KERN_PROC_PID).
+                    credentials: Some(ProcessCredentials {
+                        real_uid: task_info.pbsd.pbi_ruid,
+                        effective_uid: task_info.pbsd.pbi_uid,
+                        saved_uid: task_info.pbsd.pbi_svuid,
+                        real_gid: task_info.pbsd.pbi_rgid,
+                        effective_gid: task_info.pbsd.pbi_gid,
+                        saved_gid: task_info.pbsd.pbi_svgid,
+                        supplementary_groups: Vec::new(),
+                        real_user: user_name(task_info.pbsd.pbi_ruid),
+                        effective_user: user_name(task_info.pbsd.pbi_uid),
+                        group: group_name(task_info.pbsd.pbi_gid),
+                        supplementary_group_names: Vec::new(),
+                    }),
+                    privileged: task_info.pbsd.pbi_uid == 0,
+                    cgroup: None,
                 });
             }
         }
     }
+    *io_cache = (Some(now), io_samples);
     processes
 }
 
-pub fn kill_process_impl(pid: u32) -> bool {
-    unsafe { libc::kill(pid as i32, libc::SIGKILL) == 0 }
+pub fn set_cpu_usage_mode_impl(_mode: CpuUsageMode) {
+    // Per-process CPU usage is not sampled on macOS yet, so there is nothing to scale.
+}
+
+pub fn set_memory_metric_impl(_metric: MemoryMetric) {
+    // Only resident size is collected on macOS; memory_metric stays Rss.
+}
+
+pub fn get_process_memory_impl(_pid: u32) -> Result<ProcessMemory, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "memory breakdown is not supported on this platform"))
+}
+
+pub fn get_security_context_impl(_pid: u32) -> Result<SecurityContext, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "capabilities and seccomp are Linux-only"))
+}
+
+// Needs mach_vm_region_recurse on the task port, which requires the task_for_pid entitlement.
+pub fn get_memory_maps_impl(_pid: u32) -> Result<MemoryMaps, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "memory maps are not supported on this platform"))
+}
+
+pub fn get_connections_impl() -> Vec<ConnectionInfo> {
+    // Needs proc_pidfdinfo(PROC_PIDFDSOCKETINFO) per descriptor; not collected yet.
+    Vec::new()
+}
+
+pub fn get_process_network_usage_impl() -> Vec<ProcessNetworkUsage> {
+    // Per-process byte counts need the private NetworkStatistics framework (what nettop uses).
+    Vec::new()
+}
+
+pub fn kill_process_impl(pid: u32) -> Result<(), ProcessControlError> {
+    send_signal_impl(pid, ProcessSignal::Kill)
+}
+
+fn signal_number(signal: ProcessSignal) -> libc::c_int {
+    match signal {
+        ProcessSignal::Hangup => libc::SIGHUP,
+        ProcessSignal::Interrupt => libc::SIGINT,
+        ProcessSignal::Quit => libc::SIGQUIT,
+        ProcessSignal::Abort => libc::SIGABRT,
+        ProcessSignal::Kill => libc::SIGKILL,
+        ProcessSignal::User1 => libc::SIGUSR1,
+        ProcessSignal::User2 => libc::SIGUSR2,
+        ProcessSignal::Terminate => libc::SIGTERM,
+        ProcessSignal::Continue => libc::SIGCONT,
+        ProcessSignal::Stop => libc::SIGSTOP,
+        ProcessSignal::TerminalStop => libc::SIGTSTP,
+    }
+}
+
+fn os_error(pid: u32) -> ProcessControlError {
+    let code = std::io::Error::last_os_error().raw_os_error().unwrap_or(0);
+    let kind = match code {
+        libc::EPERM | libc::EACCES => ProcessControlErrorKind::PermissionDenied,
+        libc::ESRCH => ProcessControlErrorKind::NoSuchProcess,
+        libc::EINVAL => ProcessControlErrorKind::InvalidArgument,
+        _ => ProcessControlErrorKind::Other,
+    };
+    ProcessControlError::new(kind, code, format!("process {}: {}", pid, nix::errno::Errno::from_raw(code).desc()))
+}
+
+pub fn send_signal_impl(pid: u32, signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    // pid 0 is kernel_task, and kill(2) would read it (or a negative pid) as a process group.
+    if pid == 0 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::ProtectedProcess, 0, "process 0 is kernel_task"));
+    }
+    if pid > i32::MAX as u32 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("invalid pid {}", pid)));
+    }
+    if unsafe { libc::kill(pid as i32, signal_number(signal)) } == 0 { Ok(()) } else { Err(os_error(pid)) }
+}
+
+// Start time identifies the process across pid reuse.
+fn start_time_of(pid: u32) -> Option<(u64, u32)> {
+    proc_pid::pidinfo::<BSDInfo>(pid as i32, 0).ok().map(|info| (info.pbi_start_tvsec, info.pbi_status))
+}
+
+// Gone once the pid disappears, turns into a zombie (SZOMB) or belongs to another process.
+fn has_exited(pid: u32, start: u64) -> bool {
+    match start_time_of(pid) {
+        Some((s, status)) => status == 5 || s != start,
+        None => true,
+    }
+}
+
+fn wait_for_exit(pid: u32, start: u64, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if has_exited(pid, start) { return true; }
+        if Instant::now() >= deadline { return false; }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+pub fn terminate_gracefully_impl(pid: u32, timeout_ms: u64) -> Result<TerminationOutcome, ProcessControlError> {
+    let no_such_process = || ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid));
+    let start = match start_time_of(pid) {
+        Some((_, 5)) | None => return Err(no_such_process()),
+        Some((s, _)) => s,
+    };
+    send_signal_impl(pid, ProcessSignal::Terminate)?;
+    // A stopped process cannot run its SIGTERM handler until it is continued.
+    let _ = send_signal_impl(pid, ProcessSignal::Continue);
+    if wait_for_exit(pid, start, Duration::from_millis(timeout_ms)) {
+        return Ok(TerminationOutcome::Terminated);
+    }
+    match send_signal_impl(pid, ProcessSignal::Kill) {
+        Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => return Ok(TerminationOutcome::Terminated),
+        r => r?,
+    }
+    if wait_for_exit(pid, start, Duration::from_secs(2)) {
+        Ok(TerminationOutcome::Killed)
+    } else {
+        Err(ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after SIGKILL", pid)))
+    }
+}
+
+// All descendants of `root`, from the parent pid of every process.
+fn descendants(root: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    if let Ok(pids) = processes::pids_by_type(processes::ProcFilter::All) {
+        for pid in pids {
+            if let Ok(info) = proc_pid::pidinfo::<BSDInfo>(pid as i32, 0) {
+                children.entry(info.pbi_ppid).or_default().push(pid);
+            }
+        }
+    }
+    let mut out = Vec::new();
+    let mut queue = vec![root];
+    while let Some(pid) = queue.pop() {
+        for &child in children.get(&pid).map(Vec::as_slice).unwrap_or_default() {
+            out.push(child);
+            queue.push(child);
+        }
+    }
+    out
+}
+
+// Signal the root, then its descendants; children that exit meanwhile are not an error.
+fn signal_tree(pid: u32, include_children: bool, signal: ProcessSignal) -> Result<Vec<u32>, ProcessControlError> {
+    send_signal_impl(pid, signal)?;
+    let mut targets = vec![pid];
+    if include_children {
+        for child in descendants(pid) {
+            match send_signal_impl(child, signal) {
+                Ok(()) => targets.push(child),
+                Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess || e.kind == ProcessControlErrorKind::PermissionDenied => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(targets)
+}
+
+// macOS has no cgroup freezer, so suspending is always SIGSTOP.
+pub fn suspend_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    let targets = signal_tree(pid, include_children, ProcessSignal::Stop)?;
+    let mut suspended = SUSPENDED.lock().unwrap();
+    for p in targets {
+        if let Some((start, _)) = start_time_of(p) { suspended.insert(p, start); }
+    }
+    Ok(())
+}
+
+pub fn resume_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    let targets = signal_tree(pid, include_children, ProcessSignal::Continue)?;
+    let mut suspended = SUSPENDED.lock().unwrap();
+    for p in targets { suspended.remove(&p); }
+    Ok(())
+}
+
+// Stop the root and then its descendants until a scan finds nothing new, so nothing can
+// fork while the tree is collected. Returns (pid, ppid, pgid, start, name), parents first.
+fn freeze_tree(root: u32) -> Vec<(u32, u32, u32, u64, String)> {
+    let info = |pid: u32| {
+        proc_pid::pidinfo::<BSDInfo>(pid as i32, 0).ok().map(|i| {
+            let nul = i.pbi_name.iter().position(|c| *c == 0).unwrap_or(i.pbi_name.len());
+            let name = String::from_utf8_lossy(&i.pbi_name[..nul].iter().map(|&c| c as u8).collect::<Vec<u8>>()).to_string();
+            (pid, i.pbi_ppid, i.pbi_pgid, i.pbi_start_tvsec, name)
+        })
+    };
+    let _ = send_signal_impl(root, ProcessSignal::Stop);
+    let mut seen = HashSet::from([root]);
+    let mut members: Vec<_> = info(root).into_iter().collect();
+    for _ in 0..10 {
+        let new: Vec<u32> = descendants(root).into_iter().filter(|p| !seen.contains(p)).collect();
+        if new.is_empty() { break; }
+        for pid in new {
+            seen.insert(pid);
+            let _ = send_signal_impl(pid, ProcessSignal::Stop);
+            members.extend(info(pid));
+        }
+    }
+    members
+}
+
+pub fn kill_process_tree_impl(root_pid: u32, strategy: KillTreeStrategy) -> Result<Vec<ProcessKillResult>, ProcessControlError> {
+    if root_pid == 1 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::ProtectedProcess, 0, "refusing to kill the tree of launchd"));
+    }
+    let me = std::process::id();
+    if root_pid == me || descendants(root_pid).contains(&me) {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("process {} is an ancestor of this process", root_pid)));
+    }
+    // Surfaces permission and existence errors for the root before anything is touched.
+    send_signal_impl(root_pid, ProcessSignal::Stop)?;
+    let members = freeze_tree(root_pid);
+
+    let mut depth: HashMap<u32, usize> = HashMap::new();
+    for m in &members {
+        let d = depth.get(&m.1).map_or(0, |d| d + 1);
+        depth.insert(m.0, d);
+    }
+    let mut order: Vec<_> = members.iter().collect();
+    order.sort_by_key(|m| std::cmp::Reverse(depth[&m.0]));
+    let alive: HashSet<u32> = members.iter().filter(|m| !has_exited(m.0, m.3)).map(|m| m.0).collect();
+
+    // killpg only for groups that lie entirely inside the tree; a pipeline shares its group
+    // with siblings, which are killed one by one instead.
+    let mut killed_groups = HashSet::new();
+    if strategy == KillTreeStrategy::ProcessGroup {
+        for m in members.iter().filter(|m| m.2 == m.0 && alive.contains(&m.0)) {
+            let group = match processes::pids_by_type(processes::ProcFilter::ByProgramGroup { pgrpid: m.0 }) { Ok(g) => g, Err(_) => continue };
+            if !group.iter().all(|p| alive.contains(p)) { continue; }
+            if unsafe { libc::kill(-(m.0 as i32), libc::SIGKILL) } == 0 { killed_groups.insert(m.0); }
+        }
+    }
+
+    let result = |m: &(u32, u32, u32, u64, String), outcome, error| ProcessKillResult {
+        pid: m.0,
+        parent_pid: Some(m.1).filter(|p| *p != 0),
+        name: m.4.clone(),
+        outcome,
+        error,
+    };
+    let mut results = Vec::with_capacity(order.len());
+    let mut pending = Vec::new();
+    for m in order {
+        if !alive.contains(&m.0) {
+            results.push(result(m, KillOutcome::AlreadyExited, None));
+        } else if killed_groups.contains(&m.2) {
+            pending.push(m);
+        } else {
+            match send_signal_impl(m.0, ProcessSignal::Kill) {
+                Ok(()) => pending.push(m),
+                Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => results.push(result(m, KillOutcome::AlreadyExited, None)),
+                Err(e) => {
+                    let _ = send_signal_impl(m.0, ProcessSignal::Continue);
+                    results.push(result(m, KillOutcome::Failed, Some(e)));
+                }
+            }
+        }
+    }
+    let deadline = Instant::now() + Duration::from_secs(2);
+    for m in pending {
+        if wait_for_exit(m.0, m.3, deadline.saturating_duration_since(Instant::now())) {
+            results.push(result(m, KillOutcome::Killed, None));
+        } else {
+            let err = ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after SIGKILL", m.0));
+            results.push(result(m, KillOutcome::Failed, Some(err)));
+        }
+    }
+    Ok(results)
+}
+
+pub fn get_priority_impl(pid: u32) -> Result<ProcessPriority, ProcessControlError> {
+    let info = proc_pid::pidinfo::<BSDInfo>(pid as i32, 0).map_err(|_| {
+        ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid))
+    })?;
+    Ok(ProcessPriority { level: PriorityLevel::from_nice(info.pbi_nice), nice: info.pbi_nice, io_priority: IoPriority::default() })
+}
+
+pub fn set_priority_impl(pid: u32, level: PriorityLevel) -> Result<(), ProcessControlError> {
+    if pid == 0 || pid > i32::MAX as u32 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, libc::EINVAL, format!("invalid pid {}", pid)));
+    }
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, level.nice()) } == 0 { Ok(()) } else { Err(os_error(pid)) }
+}
+
+// Darwin's I/O policies (setiopolicy_np) only apply to the calling process or thread.
+pub fn set_io_priority_impl(_pid: u32, _priority: IoPriority) -> Result<(), ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "I/O priority of other processes cannot be set on macOS"))
+}
+
+// proc_pidinfo(PROC_PIDLISTTHREADS) only yields opaque thread handles, not thread ids,
+// and has no context switch counts; not collected yet.
+pub fn get_threads_impl(_pid: u32) -> Result<Vec<ThreadInfo>, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "thread listing is not supported on macOS"))
+}
+
+// macOS only has affinity tags (hints grouping threads), and Mach scheduling policies
+// are per thread of the caller's task, so none of this maps.
+fn sched_unsupported() -> ProcessControlError {
+    ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "CPU affinity and scheduling policies are not supported on macOS")
+}
+
+pub fn get_cpu_affinity_impl(_pid: u32) -> Result<Vec<u32>, ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn set_cpu_affinity_impl(_pid: u32, _cpus: Vec<u32>, _all_threads: bool) -> Result<(), ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn get_scheduler_impl(_pid: u32) -> Result<SchedulerInfo, ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn set_scheduler_impl(_pid: u32, _scheduler: SchedulerInfo, _all_threads: bool) -> Result<(), ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn get_thread_scheduling_impl(_pid: u32) -> Result<Vec<ThreadScheduling>, ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+// Needs proc_pidfdinfo(PROC_PIDFDVNODEPATHINFO) per descriptor, whose struct libproc does
+// not export; not collected yet.
+pub fn get_open_files_impl(_pid: u32) -> Result<Vec<OpenFileInfo>, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "listing open files is not supported on this platform"))
+}
+
+pub fn find_processes_using_impl(_path: String) -> Result<Vec<PathUsage>, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "finding processes using a path is not supported on this platform"))
+}
+
+// KERN_PROCARGS2 returns argc (int), the exec path, NUL padding, argc arguments and
+// then the environment, all NUL-terminated.
+fn procargs(pid: u32) -> Result<(Vec<String>, Vec<EnvironmentVariable>), ProcessControlError> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_PROCARGS2, pid as libc::c_int];
+    let mut size: libc::size_t = 0;
+    if unsafe { libc::sysctl(mib.as_mut_ptr(), 3, std::ptr::null_mut(), &mut size, std::ptr::null_mut(), 0) } != 0 {
+        return Err(os_error(pid));
+    }
+    let mut buf = vec![0u8; size];
+    if unsafe { libc::sysctl(mib.as_mut_ptr(), 3, buf.as_mut_ptr().cast(), &mut size, std::ptr::null_mut(), 0) } != 0 {
+        return Err(os_error(pid));
+    }
+    buf.truncate(size);
+    if buf.len() < 4 { return Ok((Vec::new(), Vec::new())); }
+    let argc = i32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]).max(0) as usize;
+    let rest = &buf[4..];
+    // Skip the exec path and its padding. An empty argv[0] cannot be told apart from the
+    // padding, as with ps.
+    let path_end = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+    let start = rest[path_end..].iter().position(|b| *b != 0).map_or(rest.len(), |p| path_end + p);
+    // Arguments are split on every NUL so that empty ones are kept.
+    let mut strings = rest[start..].split(|b| *b == 0).map(|s| String::from_utf8_lossy(s).into_owned());
+    let argv: Vec<String> = strings.by_ref().take(argc).collect();
+    // The environment ends at the first empty string; the rest of the buffer is padding.
+    let environment = strings
+        .take_while(|e| !e.is_empty())
+        .map(|e| match e.split_once('=') {
+            Some((name, value)) => EnvironmentVariable { name: name.to_string(), value: value.to_string() },
+            None => EnvironmentVariable { name: e, value: String::new() },
+        })
+        .collect();
+    Ok((argv, environment))
+}
+
+pub fn get_process_details_impl(pid: u32) -> Result<ProcessDetails, ProcessControlError> {
+    if proc_pid::pidinfo::<BSDInfo>(pid as i32, 0).is_err() {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, libc::ESRCH, format!("process {} does not exist", pid)));
+    }
+    let mut errors = Vec::new();
+    let exe = match proc_pid::pidpath(pid as i32) {
+        Ok(path) => Some(path),
+        Err(message) => {
+            errors.push(ProcessDetailError { field: ProcessDetailField::Executable, error: ProcessControlError::new(ProcessControlErrorKind::Other, 0, message) });
+            None
+        }
+    };
+    // The cwd needs proc_pidinfo(PROC_PIDVNODEPATHINFO), which libproc does not wrap yet.
+    for field in [ProcessDetailField::WorkingDirectory, ProcessDetailField::RootDirectory] {
+        errors.push(ProcessDetailError { field, error: ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "not available on macOS") });
+    }
+    let (argv, environment) = match procargs(pid) {
+        Ok((argv, env)) => (Some(argv), Some(env)),
+        Err(error) => {
+            errors.push(ProcessDetailError { field: ProcessDetailField::CommandLine, error: error.clone() });
+            errors.push(ProcessDetailError { field: ProcessDetailField::Environment, error });
+            (None, None)
+        }
+    };
+    Ok(ProcessDetails { pid, exe, exe_deleted: false, cwd: None, root: None, argv, environment, errors })
 }
@@ -1,7 +1,6 @@
-use crate::api::simple::{DiskInfo, NetworkInfo, SystemResourceInfo};
+use crate::api::simple::{CgroupInfo, CpuTimesBreakdown, DiskActivity, DiskInfo, MountFilter, NetworkInfo, NetworkInterfaceInfo, SystemResourceInfo};
+use std::ffi::CStr;
 use sysctl::{Sysctl, CtlValue};
-use std::path::Path;
-use nix::sys::statvfs;
 
 pub fn get_system_resources_impl() -> SystemResourceInfo {
     let total_memory = sysctl::Ctl::new("hw.memsize").and_then(|c| c.value()).ok().and_then(|v| match v { CtlValue::Int(i) => Some(i as u64), CtlValue::Uint(i) => Some(i as u64), CtlValue::S64(i) => Some(i as u64), CtlValue::U64(i) => Some(i as u64), _ => None }).unwrap_or(0);
@@ -27,11 +26,11 @@ pub fn get_system_resources_impl() -> SystemResourceInfo {
     }
 
     let cpu_usage = get_cpu_usage();
-    let disk_usage = get_disk_info();
+    let disk_usage = get_disk_info(&MountFilter::default());
     let network_usage = get_network_info();
 
     let cpu_per_core = get_per_core_load();
-    SystemResourceInfo { cpu_usage, cpu_per_core, memory_total: total_memory, memory_used: used_memory, memory_available: free_memory, swap_total, swap_used, swap_free, disk_usage, network_usage }
+    SystemResourceInfo { cpu_usage, cpu_per_core, cpu_times: CpuTimesBreakdown::default(), cpu_times_per_core: Vec::new(), memory_total: total_memory, memory_used: used_memory, memory_available: free_memory, swap_total, swap_used, swap_free, disk_usage, network_usage }
 }
 
 fn get_cpu_usage() -> f64 {
@@ -46,23 +45,80 @@ fn get_cpu_usage() -> f64 {
     0.0
 }
 
-fn get_disk_info() -> Vec<DiskInfo> {
+// Filesystems with no backing storage of their own.
+const PSEUDO_FS_TYPES: &[&str] = &["devfs", "autofs", "fdesc", "nullfs"];
+
+fn c_chars_to_string(raw: &[libc::c_char]) -> String {
+    unsafe { CStr::from_ptr(raw.as_ptr()) }.to_string_lossy().into_owned()
+}
+
+fn get_disk_info(filter: &MountFilter) -> Vec<DiskInfo> {
     let mut disks = Vec::new();
-    if let Ok(stat) = statvfs::statvfs(Path::new("/")) {
-        let total_space = (stat.blocks() as u64).saturating_mul(stat.fragment_size() as u64);
-        let available_space = (stat.blocks_available() as u64).saturating_mul(stat.fragment_size() as u64);
-        let used_space = total_space.saturating_sub(available_space);
-        disks.push(DiskInfo { name: "/".into(), mount_point: "/".into(), total_space, used_space, available_space });
+    let mut seen_devices = std::collections::HashSet::new();
+    let mut buf: *mut libc::statfs = std::ptr::null_mut();
+    // getmntinfo returns a buffer owned by libc that is reused on the next call.
+    let count = unsafe { libc::getmntinfo(&mut buf, libc::MNT_NOWAIT) };
+    if count <= 0 || buf.is_null() { return disks; }
+    let entries = unsafe { std::slice::from_raw_parts(buf, count as usize) };
+    for st in entries {
+        let fs_type = c_chars_to_string(&st.f_fstypename);
+        if !filter.include_pseudo && PSEUDO_FS_TYPES.contains(&fs_type.as_str()) { continue; }
+        let device = c_chars_to_string(&st.f_mntfromname);
+        // nullfs and bind-like mounts repeat the same source device
+        if filter.collapse_bind_mounts && device.starts_with("/dev/") && !seen_devices.insert(device.clone()) { continue; }
+        let mount_point = c_chars_to_string(&st.f_mntonname);
+        let bsize = st.f_bsize as u64;
+        let total_space = st.f_blocks.saturating_mul(bsize);
+        let available_space = st.f_bavail.saturating_mul(bsize);
+        let flags = st.f_flags as u64;
+        let mut mount_options = vec![if flags & libc::MNT_RDONLY as u64 != 0 { "ro" } else { "rw" }.to_string()];
+        if flags & libc::MNT_NOSUID as u64 != 0 { mount_options.push("nosuid".into()); }
+        if flags & libc::MNT_NODEV as u64 != 0 { mount_options.push("nodev".into()); }
+        if flags & libc::MNT_NOEXEC as u64 != 0 { mount_options.push("noexec".into()); }
+        if flags & libc::MNT_LOCAL as u64 != 0 { mount_options.push("local".into()); }
+        disks.push(DiskInfo {
+            name: mount_point.clone(),
+            mount_point,
+            total_space,
+            used_space: total_space.saturating_sub(available_space),
+            available_space,
+            fs_type,
+            device,
+            mount_options,
+            read_only: flags & libc::MNT_RDONLY as u64 != 0,
+            inodes_total: st.f_files,
+            inodes_used: st.f_files.saturating_sub(st.f_ffree),
+            inodes_free: st.f_ffree,
+        });
     }
     disks
 }
 
+pub fn get_disks_impl(filter: MountFilter) -> Vec<DiskInfo> {
+    get_disk_info(&filter)
+}
+
+pub fn get_disk_activity_impl() -> Vec<DiskActivity> {
+    // Block device statistics need IOKit (IOBlockStorageDriver); not collected yet.
+    Vec::new()
+}
+
 fn get_network_info() -> NetworkInfo {
     // Placeholder implementation: returns zeros. For macOS, a proper implementation
     // could use getifaddrs + if_data to sum counters per interface.
     NetworkInfo { bytes_sent: 0, bytes_received: 0, packets_sent: 0, packets_received: 0 }
 }
 
+pub fn get_cgroup_tree_impl() -> Vec<CgroupInfo> {
+    // cgroups are Linux-only; launchd has no equivalent accounting tree.
+    Vec::new()
+}
+
+pub fn get_network_interfaces_impl() -> Vec<NetworkInterfaceInfo> {
+    // Per-interface counters need getifaddrs + AF_LINK if_data, like get_network_info.
+    Vec::new()
+}
+
 // 获取每核心使用率 (瞬时快照 busy/(busy+idle))
 fn get_per_core_load() -> Vec<f64> {
     // Placeholder: proper macOS per-core sampling requires Mach APIs which are
@@ -0,0 +1,149 @@
+use crate::api::simple::{ConnectionInfo, ConnectionState, ProcessNetworkUsage, TransportProtocol};
+use std::ffi::c_void;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use windows::Win32::Foundation::FALSE;
+use windows::Win32::NetworkManagement::IpHelper::*;
+
+const AF_INET: u32 = 2;
+const AF_INET6: u32 = 23;
+const ERROR_INSUFFICIENT_BUFFER: u32 = 122;
+
+/// 两步调用 GetExtended*Table：先取所需大小，再分配缓冲区。
+/// 用 u32 缓冲保证行结构体的对齐。
+unsafe fn fetch_table(query: impl Fn(Option<*mut c_void>, *mut u32) -> u32) -> Option<Vec<u32>> {
+    let mut size = 0u32;
+    query(None, &mut size);
+    // 两次调用之间连接数可能增长，重试几次
+    for _ in 0..3 {
+        let mut buf = vec![0u32; (size as usize).div_ceil(4)];
+        size = (buf.len() * 4) as u32;
+        match query(Some(buf.as_mut_ptr() as *mut c_void), &mut size) {
+            0 => return Some(buf),
+            ERROR_INSUFFICIENT_BUFFER => continue,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// 表头为 dwNumEntries，行数据紧随其后 (偏移 4 字节)
+unsafe fn table_rows<T>(buf: &[u32]) -> &[T] {
+    let count = buf[0] as usize;
+    std::slice::from_raw_parts(buf.as_ptr().add(1) as *const T, count)
+}
+
+/// 端口以网络字节序存放在低 16 位
+fn port(raw: u32) -> u16 {
+    u16::from_be(raw as u16)
+}
+
+/// MIB_TCP_STATE
+fn decode_state(state: u32) -> ConnectionState {
+    match state {
+        1 => ConnectionState::Closed,
+        2 => ConnectionState::Listen,
+        3 => ConnectionState::SynSent,
+        4 => ConnectionState::SynReceived,
+        5 => ConnectionState::Established,
+        6 => ConnectionState::FinWait1,
+        7 => ConnectionState::FinWait2,
+        8 => ConnectionState::CloseWait,
+        9 => ConnectionState::Closing,
+        10 => ConnectionState::LastAck,
+        11 => ConnectionState::TimeWait,
+        _ => ConnectionState::Unknown,
+    }
+}
+
+fn connection(
+    protocol: TransportProtocol,
+    ipv6: bool,
+    local: (String, u16),
+    remote: (String, u16),
+    state: ConnectionState,
+    pid: u32,
+) -> ConnectionInfo {
+    ConnectionInfo {
+        protocol,
+        ipv6,
+        local_address: local.0,
+        local_port: local.1,
+        remote_address: remote.0,
+        remote_port: remote.1,
+        state,
+        // Windows 不提供收发队列长度与 inode
+        send_queue: 0,
+        receive_queue: 0,
+        pid: if pid == 0 { None } else { Some(pid) },
+        uid: 0,
+        inode: 0,
+    }
+}
+
+/// Windows实现：获取 TCP/UDP 连接列表 (GetExtendedTcpTable / GetExtendedUdpTable)
+pub fn get_connections_impl() -> Vec<ConnectionInfo> {
+    let mut out = Vec::new();
+    let unspecified4 = || (Ipv4Addr::UNSPECIFIED.to_string(), 0);
+    let unspecified6 = || (Ipv6Addr::UNSPECIFIED.to_string(), 0);
+
+    unsafe {
+        if let Some(buf) = fetch_table(|p, size| GetExtendedTcpTable(p, size, FALSE, AF_INET, TCP_TABLE_OWNER_PID_ALL, 0)) {
+            for row in table_rows::<MIB_TCPROW_OWNER_PID>(&buf) {
+                out.push(connection(
+                    TransportProtocol::Tcp,
+                    false,
+                    (Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes()).to_string(), port(row.dwLocalPort)),
+                    (Ipv4Addr::from(row.dwRemoteAddr.to_ne_bytes()).to_string(), port(row.dwRemotePort)),
+                    decode_state(row.dwState),
+                    row.dwOwningPid,
+                ));
+            }
+        }
+        if let Some(buf) = fetch_table(|p, size| GetExtendedTcpTable(p, size, FALSE, AF_INET6, TCP_TABLE_OWNER_PID_ALL, 0)) {
+            for row in table_rows::<MIB_TCP6ROW_OWNER_PID>(&buf) {
+                out.push(connection(
+                    TransportProtocol::Tcp,
+                    true,
+                    (Ipv6Addr::from(row.ucLocalAddr).to_string(), port(row.dwLocalPort)),
+                    (Ipv6Addr::from(row.ucRemoteAddr).to_string(), port(row.dwRemotePort)),
+                    decode_state(row.dwState),
+                    row.dwOwningPid,
+                ));
+            }
+        }
+        if let Some(buf) = fetch_table(|p, size| GetExtendedUdpTable(p, size, FALSE, AF_INET, UDP_TABLE_OWNER_PID, 0)) {
+            for row in table_rows::<MIB_UDPROW_OWNER_PID>(&buf) {
+                out.push(connection(
+                    TransportProtocol::Udp,
+                    false,
+                    (Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes()).to_string(), port(row.dwLocalPort)),
+                    unspecified4(),
+                    ConnectionState::Unconnected,
+                    row.dwOwningPid,
+                ));
+            }
+        }
+        if let Some(buf) = fetch_table(|p, size| GetExtendedUdpTable(p, size, FALSE, AF_INET6, UDP_TABLE_OWNER_PID, 0)) {
+            for row in table_rows::<MIB_UDP6ROW_OWNER_PID>(&buf) {
+                out.push(connection(
+                    TransportProtocol::Udp,
+                    true,
+                    (Ipv6Addr::from(row.ucLocalAddr).to_string(), port(row.dwLocalPort)),
+                    unspecified6(),
+                    ConnectionState::Unconnected,
+                    row.dwOwningPid,
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+/// Windows实现：按进程统计网络流量
+pub fn get_process_network_usage_impl() -> Vec<ProcessNetworkUsage> {
+    // GetPerTcpConnectionEStats only reports data-transfer counters after collection has
+    // been enabled per connection, which needs administrator rights; not collected yet.
+    Vec::new()
+}
@@ -1,18 +1,110 @@
+use std::collections::{HashMap, HashSet};
 use std::mem;
 use std::ffi::OsString;
 use std::os::windows::ffi::OsStringExt;
-use crate::api::simple::ProcessInfo;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::api::simple::{
+    CpuUsageMode, IoPriority, KillOutcome, KillTreeStrategy, MemoryMaps, MemoryMetric, OpenFileInfo,
+    PathUsage, PriorityLevel, ProcessControlError, ProcessControlErrorKind, ProcessDetailError,
+    ProcessDetailField, ProcessDetails, ProcessDiskIo, ProcessInfo, ProcessKillResult, ProcessMemory,
+    ProcessPriority, ProcessSignal, ProcessState, SchedulerInfo, SecurityContext, TerminationOutcome,
+    ThreadInfo, ThreadScheduling,
+};
 
 use windows::{
     Win32::Foundation::*,
+    Win32::Security::{
+        GetLengthSid, GetTokenInformation, LookupAccountSidW, TokenElevation, TokenUser, SID_NAME_USE,
+        TOKEN_ELEVATION, TOKEN_QUERY, TOKEN_USER,
+    },
+    Win32::System::Console::*,
     Win32::System::Threading::*,
     Win32::System::Diagnostics::ToolHelp::*,
     Win32::System::ProcessStatus::*,
+    Win32::UI::WindowsAndMessaging::*,
 };
 
+// wincon.h
+const CTRL_C_EVENT: u32 = 0;
+const CTRL_BREAK_EVENT: u32 = 1;
+
+// winerror.h
+const ERROR_ACCESS_DENIED_CODE: i32 = 5;
+const ERROR_INVALID_PARAMETER_CODE: i32 = 87;
+
+lazy_static::lazy_static! {
+    static ref CPU_USAGE_MODE: Mutex<CpuUsageMode> = Mutex::new(CpuUsageMode::Machine);
+    // 通过 suspend_process 挂起的进程；SuspendThread 可叠加计数，需避免重复挂起
+    static ref SUSPENDED: Mutex<HashSet<u32>> = Mutex::new(HashSet::new());
+    // get_threads 的线程 CPU 采样，按进程保存：PID -> (采样时间, TID -> (创建时间, CPU 时间))
+    static ref THREAD_CPU_CACHE: Mutex<HashMap<u32, (Instant, HashMap<u32, (u64, u64)>)>> = Mutex::new(HashMap::new());
+    // 上次 get_processes 的 I/O 字节数：(采样时间, PID -> (读, 写))
+    static ref DISK_IO_CACHE: Mutex<(Option<Instant>, HashMap<u32, (u64, u64)>)> = Mutex::new((None, HashMap::new()));
+    // SID 字节 -> "DOMAIN\name"；LookupAccountSidW 可能需要查询域控制器，结果缓存
+    static ref ACCOUNT_NAMES: Mutex<HashMap<Vec<u8>, String>> = Mutex::new(HashMap::new());
+}
+
+/// 令牌所属用户的 "DOMAIN\name"
+unsafe fn token_account(token: HANDLE) -> Option<String> {
+    let mut len = 0u32;
+    let _ = GetTokenInformation(token, TokenUser, None, 0, &mut len);
+    if len == 0 { return None; }
+    // 以 u64 分配，保证 TOKEN_USER 对齐
+    let mut buf = vec![0u64; (len as usize).div_ceil(8)];
+    GetTokenInformation(token, TokenUser, Some(buf.as_mut_ptr() as *mut std::ffi::c_void), len, &mut len).ok()?;
+    let sid = (*(buf.as_ptr() as *const TOKEN_USER)).User.Sid;
+    let key = std::slice::from_raw_parts(sid.0 as *const u8, GetLengthSid(sid) as usize).to_vec();
+    if let Some(name) = ACCOUNT_NAMES.lock().unwrap().get(&key) { return Some(name.clone()); }
+
+    let mut name = [0u16; 256];
+    let mut domain = [0u16; 256];
+    let (mut name_len, mut domain_len) = (name.len() as u32, domain.len() as u32);
+    let mut sid_use = SID_NAME_USE::default();
+    LookupAccountSidW(
+        windows::core::PCWSTR::null(), sid,
+        windows::core::PWSTR(name.as_mut_ptr()), &mut name_len,
+        windows::core::PWSTR(domain.as_mut_ptr()), &mut domain_len,
+        &mut sid_use,
+    ).ok()?;
+    let name = String::from_utf16_lossy(&name[..name_len as usize]);
+    let domain = String::from_utf16_lossy(&domain[..domain_len as usize]);
+    let account = if domain.is_empty() { name } else { format!("{}\\{}", domain, name) };
+    ACCOUNT_NAMES.lock().unwrap().insert(key, account.clone());
+    Some(account)
+}
+
+/// 进程所属用户与令牌是否已提升 (UAC 管理员)；无权打开进程或令牌时为 None
+fn process_owner(process: HANDLE) -> Option<(String, bool)> {
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(process, TOKEN_QUERY, &mut token).ok()?;
+        let account = token_account(token);
+        let mut elevation = TOKEN_ELEVATION::default();
+        let mut len = 0u32;
+        let elevated = GetTokenInformation(
+            token, TokenElevation,
+            Some(&mut elevation as *mut TOKEN_ELEVATION as *mut std::ffi::c_void),
+            mem::size_of::<TOKEN_ELEVATION>() as u32, &mut len,
+        ).is_ok() && elevation.TokenIsElevated != 0;
+        let _ = CloseHandle(token);
+        Some((account?, elevated))
+    }
+}
+
+/// Windows实现：当前用户名，与 ProcessInfo.user 形式相同
+pub fn get_current_user_impl() -> String {
+    process_owner(unsafe { GetCurrentProcess() }).map(|(user, _)| user).unwrap_or_default()
+}
+
 /// Windows实现：获取进程列表
 pub fn get_processes_impl() -> Vec<ProcessInfo> {
     let mut processes = Vec::new();
+    let now = Instant::now();
+    let mut io_cache = DISK_IO_CACHE.lock().unwrap();
+    let io_secs = io_cache.0.map(|last| now.duration_since(last).as_secs_f64()).unwrap_or(0.0);
+    let mut io_samples = HashMap::new();
     
     unsafe {
         // 创建进程快照
@@ -39,21 +131,64 @@ pub fn get_processes_impl() -> Vec<ProcessInfo> {
                     .to_string();
                 
                 // 获取进程内存和CPU使用率
-                let (memory_usage, cpu_usage) = get_process_info(process_entry.th32ProcessID);
+                let (memory_usage, cpu_usage, priority, io) = get_process_info(process_entry.th32ProcessID);
+                let owner = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_entry.th32ProcessID).ok().and_then(|handle| {
+                    let owner = process_owner(handle);
+                    let _ = CloseHandle(handle);
+                    owner
+                });
+                let disk_io = io.map(|c| {
+                    let mut io = ProcessDiskIo {
+                        read_bytes: c.ReadTransferCount,
+                        write_bytes: c.WriteTransferCount,
+                        read_chars: c.ReadTransferCount,
+                        write_chars: c.WriteTransferCount,
+                        read_syscalls: c.ReadOperationCount,
+                        write_syscalls: c.WriteOperationCount,
+                        ..Default::default()
+                    };
+                    if let Some(&(read, write)) = io_cache.1.get(&process_entry.th32ProcessID) {
+                        if io_secs > 0.0 {
+                            io.read_bytes_per_sec = io.read_bytes.saturating_sub(read) as f64 / io_secs;
+                            io.write_bytes_per_sec = io.write_bytes.saturating_sub(write) as f64 / io_secs;
+                        }
+                    }
+                    io_samples.insert(process_entry.th32ProcessID, (io.read_bytes, io.write_bytes));
+                    io
+                });
                 
+                let state = if SUSPENDED.lock().unwrap().contains(&process_entry.th32ProcessID) {
+                    ProcessState::Suspended
+                } else {
+                    ProcessState::Running
+                };
                 processes.push(ProcessInfo {
                     pid: process_entry.th32ProcessID,
                     name,
                     cpu_usage,
                     memory_usage,
+                    memory_metric: MemoryMetric::Rss,
                     parent_pid: if process_entry.th32ParentProcessID == 0 { 
                         None 
                     } else { 
                         Some(process_entry.th32ParentProcessID) 
                     },
-                    status: "Running".to_string(),
+                    status: state.label().to_string(),
+                    state,
                     command: String::new(), // 可以通过QueryFullProcessImageNameW获取
                     start_time: 0, // 可以通过GetProcessTimes获取
+                    age: 0,
+                    priority,
+                    nice: priority.nice(),
+                    io_priority: IoPriority::default(),
+                    last_cpu: None,
+                    thread_count: process_entry.cntThreads,
+                    disk_io,
+                    user: owner.as_ref().map(|(user, _)| user.clone()),
+                    // uid/gid 为 POSIX 概念
+                    credentials: None,
+                    privileged: owner.map(|(_, elevated)| elevated).unwrap_or(false),
+                    cgroup: None,
                 });
                 
                 if Process32NextW(snapshot, &mut process_entry).is_err() {
@@ -65,15 +200,16 @@ pub fn get_processes_impl() -> Vec<ProcessInfo> {
         let _ = CloseHandle(snapshot);
     }
     
+    *io_cache = (Some(now), io_samples);
     processes
 }
 
-/// 获取单个进程的内存和CPU使用信息
-fn get_process_info(pid: u32) -> (u64, f64) {
+/// 获取单个进程的内存、CPU使用率、优先级与 I/O 计数
+fn get_process_info(pid: u32) -> (u64, f64, PriorityLevel, Option<IO_COUNTERS>) {
     unsafe {
         let handle = match OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid) {
             Ok(h) => h,
-            Err(_) => return (0, 0.0),
+            Err(_) => return (0, 0.0, PriorityLevel::Normal, None),
         };
         
         // 获取内存使用情况
@@ -86,9 +222,12 @@ fn get_process_info(pid: u32) -> (u64, f64) {
         
         // CPU使用率计算
         let cpu_usage = calculate_cpu_usage(handle);
+        let priority = priority_class_level(GetPriorityClass(handle));
+        let mut io = IO_COUNTERS::default();
+        let io = GetProcessIoCounters(handle, &mut io).ok().map(|_| io);
         
         let _ = CloseHandle(handle);
-        (memory_usage, cpu_usage)
+        (memory_usage, cpu_usage, priority, io)
     }
 }
 
@@ -119,7 +258,11 @@ unsafe fn calculate_cpu_usage(process_handle: HANDLE) -> f64 {
             0.0
         };
 
-        cpu_usage
+        // GetSystemTimes 汇总了所有核心，单核模式下按核心数放大
+        match *CPU_USAGE_MODE.lock().unwrap() {
+            CpuUsageMode::Machine => cpu_usage,
+            CpuUsageMode::SingleCore => cpu_usage * std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f64,
+        }
     } else {
         0.0
     }
@@ -145,16 +288,565 @@ fn get_system_time() -> u64 {
     }
 }
 
+/// Windows实现：设置进程CPU使用率的统计口径
+pub fn set_cpu_usage_mode_impl(mode: CpuUsageMode) {
+    *CPU_USAGE_MODE.lock().unwrap() = mode;
+}
+
+/// Windows实现：内存列始终为工作集大小（Rss），其他口径暂不支持
+pub fn set_memory_metric_impl(_metric: MemoryMetric) {}
+
+/// 工作集明细需要 QueryWorkingSetEx 逐页统计，暂不支持
+pub fn get_process_memory_impl(_pid: u32) -> Result<ProcessMemory, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "memory breakdown is not supported on this platform"))
+}
+
+/// Windows实现：能力集、seccomp 与 LSM 标签均为 Linux 概念
+pub fn get_security_context_impl(_pid: u32) -> Result<SecurityContext, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "capabilities and seccomp are Linux-only"))
+}
+
+/// 需要 VirtualQueryEx 遍历地址空间并配合 QueryWorkingSetEx 统计驻留页，暂不支持
+pub fn get_memory_maps_impl(_pid: u32) -> Result<MemoryMaps, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "memory maps are not supported on this platform"))
+}
+
+/// 将 Win32 错误转换为 ProcessControlError。
+/// OpenProcess 对不存在的 PID 返回 ERROR_INVALID_PARAMETER。
+fn win_error(pid: u32, err: windows::core::Error) -> ProcessControlError {
+    let hr = err.code().0 as u32;
+    // HRESULT_FROM_WIN32 的低 16 位即 Win32 错误码
+    let code = if hr & 0xFFFF_0000 == 0x8007_0000 { (hr & 0xFFFF) as i32 } else { hr as i32 };
+    let kind = match code {
+        ERROR_ACCESS_DENIED_CODE => ProcessControlErrorKind::PermissionDenied,
+        ERROR_INVALID_PARAMETER_CODE => ProcessControlErrorKind::NoSuchProcess,
+        _ => ProcessControlErrorKind::Other,
+    };
+    ProcessControlError::new(kind, code, format!("process {}: {}", pid, err.message()))
+}
+
+/// 打开进程句柄；System Idle (0) 与 System (4) 属于内核，不允许操作
+fn open_process(pid: u32, access: PROCESS_ACCESS_RIGHTS) -> Result<HANDLE, ProcessControlError> {
+    if pid == 0 || pid == 4 {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::ProtectedProcess, 0, format!("process {} is a system process", pid)));
+    }
+    let handle = unsafe { OpenProcess(access, false, pid) }.map_err(|e| win_error(pid, e))?;
+    // 已退出但句柄未被释放的进程仍可打开
+    if wait_for_exit(handle, 0) {
+        unsafe { let _ = CloseHandle(handle); }
+        return Err(ProcessControlError::new(ProcessControlErrorKind::NoSuchProcess, 0, format!("process {} has already exited", pid)));
+    }
+    Ok(handle)
+}
+
 /// Windows实现：结束进程
-pub fn kill_process_impl(pid: u32) -> bool {
+pub fn kill_process_impl(pid: u32) -> Result<(), ProcessControlError> {
+    let handle = open_process(pid, PROCESS_TERMINATE | PROCESS_SYNCHRONIZE)?;
+    let result = unsafe { TerminateProcess(handle, 1) }.map_err(|e| win_error(pid, e));
+    unsafe { let _ = CloseHandle(handle); }
+    result
+}
+
+// EnumWindows 回调：向属于目标进程的顶层窗口投递 WM_CLOSE
+// lparam 指向 (pid, 已投递数量)
+unsafe extern "system" fn close_window_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let state = &mut *(lparam.0 as *mut (u32, u32));
+    let mut window_pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut window_pid));
+    if window_pid == state.0 && PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0)).is_ok() {
+        state.1 += 1;
+    }
+    TRUE
+}
+
+/// 向进程的所有顶层窗口发送 WM_CLOSE，返回是否找到窗口
+fn close_windows(pid: u32) -> bool {
+    let mut state: (u32, u32) = (pid, 0);
     unsafe {
-        let handle = match OpenProcess(PROCESS_TERMINATE, TRUE, pid) {
-            Ok(h) => h,
-            Err(_) => return false,
+        let _ = EnumWindows(Some(close_window_callback), LPARAM(&mut state as *mut _ as isize));
+    }
+    state.1 > 0
+}
+
+/// 附加到目标进程的控制台并发送控制事件。
+/// 事件会发给控制台上的所有进程，因此先屏蔽本进程的处理。
+fn send_console_ctrl(pid: u32, event: u32) -> bool {
+    unsafe {
+        let _ = FreeConsole();
+        if AttachConsole(pid).is_err() {
+            return false;
+        }
+        let _ = SetConsoleCtrlHandler(None, TRUE);
+        let ok = GenerateConsoleCtrlEvent(event, 0).is_ok();
+        // 等待事件分发完毕后再恢复，否则本进程也可能收到
+        thread::sleep(Duration::from_millis(50));
+        let _ = FreeConsole();
+        let _ = SetConsoleCtrlHandler(None, FALSE);
+        ok
+    }
+}
+
+/// 终止类请求：先尝试 WM_CLOSE，再尝试控制台事件
+fn request_close(pid: u32) -> bool {
+    close_windows(pid) || send_console_ctrl(pid, CTRL_BREAK_EVENT)
+}
+
+/// Windows实现：发送信号
+/// 没有 POSIX 信号，按语义映射：终止类请求先尝试 WM_CLOSE，再尝试控制台事件
+pub fn send_signal_impl(pid: u32, signal: ProcessSignal) -> Result<(), ProcessControlError> {
+    if signal == ProcessSignal::Kill {
+        return kill_process_impl(pid);
+    }
+    // 先确认进程存在且可访问，以便给出准确的错误
+    let handle = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    unsafe { let _ = CloseHandle(handle); }
+    let delivered = match signal {
+        ProcessSignal::Terminate | ProcessSignal::Hangup | ProcessSignal::Quit => request_close(pid),
+        ProcessSignal::Interrupt => send_console_ctrl(pid, CTRL_C_EVENT),
+        // 其余信号在 Windows 上没有对应机制
+        _ => return Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, format!("{:?} is not supported on Windows", signal))),
+    };
+    if delivered {
+        Ok(())
+    } else {
+        Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, format!("process {} has no window or console to receive {:?}", pid, signal)))
+    }
+}
+
+/// 等待进程退出，返回是否在超时前退出
+fn wait_for_exit(handle: HANDLE, timeout_ms: u32) -> bool {
+    unsafe { WaitForSingleObject(handle, timeout_ms) == WAIT_OBJECT_0 }
+}
+
+/// Windows实现：先请求进程退出，超时后 TerminateProcess
+pub fn terminate_gracefully_impl(pid: u32, timeout_ms: u64) -> Result<TerminationOutcome, ProcessControlError> {
+    // 持有句柄可避免等待期间 PID 被复用
+    let handle = open_process(pid, PROCESS_SYNCHRONIZE | PROCESS_TERMINATE)?;
+    // 没有窗口或控制台的进程无法礼貌请求，直接结束
+    let outcome = if request_close(pid) && wait_for_exit(handle, timeout_ms.min(u32::MAX as u64) as u32) {
+        Ok(TerminationOutcome::Terminated)
+    } else {
+        match unsafe { TerminateProcess(handle, 1) } {
+            // 超时与 TerminateProcess 之间退出时会返回拒绝访问
+            Err(_) if wait_for_exit(handle, 0) => Ok(TerminationOutcome::Terminated),
+            Err(e) => Err(win_error(pid, e)),
+            Ok(()) if wait_for_exit(handle, 2000) => Ok(TerminationOutcome::Killed),
+            Ok(()) => Err(ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after TerminateProcess", pid))),
+        }
+    };
+    unsafe { let _ = CloseHandle(handle); }
+    outcome
+}
+
+/// 进程快照：PID -> (父进程 PID, 映像名)
+fn process_table() -> HashMap<u32, (u32, String)> {
+    let mut table = HashMap::new();
+    unsafe {
+        let snapshot = match CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) {
+            Ok(s) => s,
+            Err(_) => return table,
         };
-        
-        let result = TerminateProcess(handle, 1).is_ok();
-        let _ = CloseHandle(handle);
-        result
+        let mut entry = PROCESSENTRY32W { dwSize: mem::size_of::<PROCESSENTRY32W>() as u32, ..Default::default() };
+        if Process32FirstW(snapshot, &mut entry).is_ok() {
+            loop {
+                let name = OsString::from_wide(&entry.szExeFile[..]).to_string_lossy().trim_end_matches('\0').to_string();
+                table.insert(entry.th32ProcessID, (entry.th32ParentProcessID, name));
+                if Process32NextW(snapshot, &mut entry).is_err() { break; }
+            }
+        }
+        let _ = CloseHandle(snapshot);
+    }
+    table
+}
+
+/// 收集 root 的所有子孙进程，父进程在子进程之前
+fn descendants(root: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&pid, &(ppid, _)) in &process_table() {
+        // PID 0 的父进程也是 0，跳过以免自环
+        if pid != 0 { children.entry(ppid).or_default().push(pid); }
+    }
+    let mut out = Vec::new();
+    let mut queue = vec![root];
+    while let Some(pid) = queue.pop() {
+        for &child in children.get(&pid).map(Vec::as_slice).unwrap_or_default() {
+            // 父进程退出后其 PID 可能被复用，防止成环
+            if child != root && !out.contains(&child) {
+                out.push(child);
+                queue.push(child);
+            }
+        }
+    }
+    out
+}
+
+/// 挂起或恢复进程的所有线程（Windows 没有 SIGSTOP，也没有公开的冻结接口）
+fn suspend_threads(pid: u32, suspend: bool) -> Result<(), ProcessControlError> {
+    let handle = open_process(pid, PROCESS_SUSPEND_RESUME | PROCESS_SYNCHRONIZE)?;
+    unsafe { let _ = CloseHandle(handle); }
+    let mut done = 0;
+    let mut first_error = None;
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).map_err(|e| win_error(pid, e))?;
+        let mut entry = THREADENTRY32 { dwSize: mem::size_of::<THREADENTRY32>() as u32, ..Default::default() };
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    match OpenThread(THREAD_SUSPEND_RESUME, false, entry.th32ThreadID) {
+                        Ok(thread) => {
+                            let prev = if suspend { SuspendThread(thread) } else { ResumeThread(thread) };
+                            if prev != u32::MAX { done += 1; }
+                            let _ = CloseHandle(thread);
+                        }
+                        Err(e) => { first_error.get_or_insert(e); }
+                    }
+                }
+                if Thread32Next(snapshot, &mut entry).is_err() { break; }
+            }
+        }
+        let _ = CloseHandle(snapshot);
     }
-}
\ No newline at end of file
+    match first_error {
+        Some(e) if done == 0 => Err(win_error(pid, e)),
+        _ => Ok(()),
+    }
+}
+
+/// 对进程（及其子孙）逐个挂起或恢复；子进程的错误忽略
+fn suspend_tree(pid: u32, include_children: bool, suspend: bool) -> Result<(), ProcessControlError> {
+    let targets: Vec<u32> = std::iter::once(pid)
+        .chain(if include_children { descendants(pid) } else { Vec::new() })
+        .collect();
+    for (i, &p) in targets.iter().enumerate() {
+        // 已挂起的不再挂起，未经本接口挂起的不恢复，保证计数平衡
+        if SUSPENDED.lock().unwrap().contains(&p) == suspend { continue; }
+        match suspend_threads(p, suspend) {
+            Ok(()) => {
+                let mut suspended = SUSPENDED.lock().unwrap();
+                if suspend { suspended.insert(p); } else { suspended.remove(&p); }
+            }
+            Err(e) if i == 0 => return Err(e),
+            Err(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Windows实现：挂起进程
+pub fn suspend_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    suspend_tree(pid, include_children, true)
+}
+
+/// Windows实现：恢复进程
+pub fn resume_process_impl(pid: u32, include_children: bool) -> Result<(), ProcessControlError> {
+    suspend_tree(pid, include_children, false)
+}
+
+/// 进程创建时间 (FILETIME)，用于识别 PID 复用
+fn creation_time(handle: HANDLE) -> u64 {
+    let (mut created, mut exited, mut kernel, mut user) = Default::default();
+    unsafe {
+        if GetProcessTimes(handle, &mut created, &mut exited, &mut kernel, &mut user).is_ok() { filetime_to_u64(created) } else { 0 }
+    }
+}
+
+struct TreeMember {
+    pid: u32,
+    parent_pid: u32,
+    name: String,
+    handle: Result<HANDLE, ProcessControlError>,
+}
+
+/// 挂起根进程及其子孙，反复扫描直到没有新的子进程，得到一致的进程树快照。
+/// 挂起时即持有句柄，防止结束前 PID 被复用。
+fn freeze_tree(root: u32) -> Vec<TreeMember> {
+    let mut members: Vec<TreeMember> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut created: HashMap<u32, u64> = HashMap::new();
+    for _ in 0..10 {
+        let table = process_table();
+        let new: Vec<u32> = std::iter::once(root).chain(descendants(root)).filter(|p| !seen.contains(p)).collect();
+        if new.is_empty() { break; }
+        for pid in new {
+            seen.insert(pid);
+            let (parent_pid, name) = table.get(&pid).cloned().unwrap_or_default();
+            let handle = open_process(pid, PROCESS_TERMINATE | PROCESS_SYNCHRONIZE | PROCESS_QUERY_LIMITED_INFORMATION);
+            if let Ok(h) = handle {
+                let time = creation_time(h);
+                // 比父进程更早创建，说明父 PID 已被复用，并非真正的子进程
+                if pid != root && created.get(&parent_pid).is_some_and(|&t| time < t) {
+                    unsafe { let _ = CloseHandle(h); }
+                    continue;
+                }
+                created.insert(pid, time);
+                let _ = suspend_threads(pid, true);
+            }
+            members.push(TreeMember { pid, parent_pid, name, handle });
+        }
+    }
+    members
+}
+
+/// Windows实现：结束进程树。Windows 没有进程组，两种策略都逐个从叶子结束
+pub fn kill_process_tree_impl(root_pid: u32, _strategy: KillTreeStrategy) -> Result<Vec<ProcessKillResult>, ProcessControlError> {
+    let me = std::process::id();
+    if root_pid == me || descendants(root_pid).contains(&me) {
+        return Err(ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, ERROR_INVALID_PARAMETER_CODE, format!("process {} is an ancestor of this process", root_pid)));
+    }
+    // 先确认根进程可以结束
+    let root = open_process(root_pid, PROCESS_TERMINATE | PROCESS_SYNCHRONIZE)?;
+    unsafe { let _ = CloseHandle(root); }
+    let members = freeze_tree(root_pid);
+
+    let mut depth: HashMap<u32, usize> = HashMap::new();
+    for m in &members {
+        let d = depth.get(&m.parent_pid).map_or(0, |d| d + 1);
+        depth.insert(m.pid, d);
+    }
+    let mut order: Vec<&TreeMember> = members.iter().collect();
+    order.sort_by_key(|m| std::cmp::Reverse(depth[&m.pid]));
+
+    let result = |m: &TreeMember, outcome, error| ProcessKillResult {
+        pid: m.pid,
+        parent_pid: Some(m.parent_pid).filter(|p| *p != 0),
+        name: m.name.clone(),
+        outcome,
+        error,
+    };
+    let mut results = Vec::with_capacity(order.len());
+    let mut pending = Vec::new();
+    for m in order {
+        match &m.handle {
+            Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => results.push(result(m, KillOutcome::AlreadyExited, None)),
+            Err(e) => results.push(result(m, KillOutcome::Failed, Some(e.clone()))),
+            Ok(h) if wait_for_exit(*h, 0) => results.push(result(m, KillOutcome::AlreadyExited, None)),
+            Ok(h) => match unsafe { TerminateProcess(*h, 1) } {
+                Ok(()) => pending.push((m, *h)),
+                Err(e) => {
+                    let _ = suspend_threads(m.pid, false);
+                    results.push(result(m, KillOutcome::Failed, Some(win_error(m.pid, e))));
+                }
+            },
+        }
+    }
+    let deadline = Instant::now() + Duration::from_secs(2);
+    for (m, h) in pending {
+        let remaining = deadline.saturating_duration_since(Instant::now()).as_millis() as u32;
+        if wait_for_exit(h, remaining) {
+            results.push(result(m, KillOutcome::Killed, None));
+        } else {
+            let err = ProcessControlError::new(ProcessControlErrorKind::TimedOut, 0, format!("process {} did not exit after TerminateProcess", m.pid));
+            results.push(result(m, KillOutcome::Failed, Some(err)));
+        }
+    }
+    for m in &members {
+        if let Ok(h) = m.handle { unsafe { let _ = CloseHandle(h); } }
+    }
+    Ok(results)
+}
+
+/// 优先级类 (GetPriorityClass) 转换为统一的优先级档位；实时类归为 High
+fn priority_class_level(class: u32) -> PriorityLevel {
+    match PROCESS_CREATION_FLAGS(class) {
+        IDLE_PRIORITY_CLASS => PriorityLevel::Idle,
+        BELOW_NORMAL_PRIORITY_CLASS => PriorityLevel::BelowNormal,
+        ABOVE_NORMAL_PRIORITY_CLASS => PriorityLevel::AboveNormal,
+        HIGH_PRIORITY_CLASS | REALTIME_PRIORITY_CLASS => PriorityLevel::High,
+        _ => PriorityLevel::Normal,
+    }
+}
+
+/// Windows实现：获取进程优先级；I/O 优先级没有公开接口，返回默认值
+pub fn get_priority_impl(pid: u32) -> Result<ProcessPriority, ProcessControlError> {
+    let handle = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    let class = unsafe { GetPriorityClass(handle) };
+    let result = if class == 0 {
+        Err(win_error(pid, windows::core::Error::from_win32()))
+    } else {
+        let level = priority_class_level(class);
+        Ok(ProcessPriority { level, nice: level.nice(), io_priority: IoPriority::default() })
+    };
+    unsafe { let _ = CloseHandle(handle); }
+    result
+}
+
+/// Windows实现：设置进程优先级类
+pub fn set_priority_impl(pid: u32, level: PriorityLevel) -> Result<(), ProcessControlError> {
+    let class = match level {
+        PriorityLevel::Idle => IDLE_PRIORITY_CLASS,
+        PriorityLevel::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+        PriorityLevel::Normal => NORMAL_PRIORITY_CLASS,
+        PriorityLevel::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+        PriorityLevel::High => HIGH_PRIORITY_CLASS,
+    };
+    let handle = open_process(pid, PROCESS_SET_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    let result = unsafe { SetPriorityClass(handle, class) }.map_err(|e| win_error(pid, e));
+    unsafe { let _ = CloseHandle(handle); }
+    result
+}
+
+/// Windows实现：设置 I/O 优先级（需要未公开的 NtSetInformationProcess，暂不支持）
+pub fn set_io_priority_impl(_pid: u32, _priority: IoPriority) -> Result<(), ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "I/O priority cannot be set on Windows"))
+}
+
+/// Windows实现：获取进程亲和性掩码（仅当前处理器组，最多 64 个 CPU）
+pub fn get_cpu_affinity_impl(pid: u32) -> Result<Vec<u32>, ProcessControlError> {
+    let handle = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    let (mut process_mask, mut system_mask) = (0usize, 0usize);
+    let result = unsafe { GetProcessAffinityMask(handle, &mut process_mask, &mut system_mask) }
+        .map(|_| (0..usize::BITS).filter(|cpu| process_mask & (1 << cpu) != 0).collect())
+        .map_err(|e| win_error(pid, e));
+    unsafe { let _ = CloseHandle(handle); }
+    result
+}
+
+/// Windows实现：设置进程亲和性掩码，作用于进程的所有线程
+pub fn set_cpu_affinity_impl(pid: u32, cpus: Vec<u32>, _all_threads: bool) -> Result<(), ProcessControlError> {
+    let invalid = |msg: String| ProcessControlError::new(ProcessControlErrorKind::InvalidArgument, ERROR_INVALID_PARAMETER_CODE, msg);
+    if cpus.is_empty() { return Err(invalid("the CPU list is empty".into())); }
+    let mut mask = 0usize;
+    for cpu in cpus {
+        if cpu >= usize::BITS { return Err(invalid(format!("CPU {} is out of range", cpu))); }
+        mask |= 1 << cpu;
+    }
+    let handle = open_process(pid, PROCESS_SET_INFORMATION | PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    let result = unsafe { SetProcessAffinityMask(handle, mask) }.map_err(|e| win_error(pid, e));
+    unsafe { let _ = CloseHandle(handle); }
+    result
+}
+
+/// Windows 没有 Linux 式的调度策略，优先级由 set_priority 的优先级类控制
+fn sched_unsupported() -> ProcessControlError {
+    ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "scheduling policies are not supported on Windows")
+}
+
+pub fn get_scheduler_impl(_pid: u32) -> Result<SchedulerInfo, ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn set_scheduler_impl(_pid: u32, _scheduler: SchedulerInfo, _all_threads: bool) -> Result<(), ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+pub fn get_thread_scheduling_impl(_pid: u32) -> Result<Vec<ThreadScheduling>, ProcessControlError> {
+    Err(sched_unsupported())
+}
+
+/// 线程名称 (GetThreadDescription，Windows 10 1607 起支持)
+unsafe fn thread_description(thread: HANDLE) -> String {
+    match GetThreadDescription(thread) {
+        Ok(desc) if !desc.is_null() => {
+            let name = desc.to_string().unwrap_or_default();
+            let _ = LocalFree(HLOCAL(desc.0 as *mut _));
+            name
+        }
+        _ => String::new(),
+    }
+}
+
+/// Windows实现：列出进程的线程。没有调度状态与上下文切换次数的公开接口，状态固定为 Running
+pub fn get_threads_impl(pid: u32) -> Result<Vec<ThreadInfo>, ProcessControlError> {
+    let process = open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE)?;
+    unsafe { let _ = CloseHandle(process); }
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f64;
+    let mode = *CPU_USAGE_MODE.lock().unwrap();
+    let now = Instant::now();
+    let mut cache = THREAD_CPU_CACHE.lock().unwrap();
+    // 超过 60 秒未查询的进程不再保留采样
+    cache.retain(|_, (at, _)| now.duration_since(*at) < Duration::from_secs(60));
+    let prev = cache.get(&pid);
+    // FILETIME 以 100ns 为单位
+    let elapsed = prev.map(|(at, _)| now.duration_since(*at).as_secs_f64() * 1e7).unwrap_or(0.0);
+
+    let mut out = Vec::new();
+    let mut samples = HashMap::new();
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).map_err(|e| win_error(pid, e))?;
+        let mut entry = THREADENTRY32 { dwSize: mem::size_of::<THREADENTRY32>() as u32, ..Default::default() };
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    let tid = entry.th32ThreadID;
+                    let (mut name, mut user, mut kernel, mut created) = (String::new(), 0, 0, 0);
+                    if let Ok(thread) = OpenThread(THREAD_QUERY_LIMITED_INFORMATION, false, tid) {
+                        let (mut c, mut e, mut k, mut u) = Default::default();
+                        if GetThreadTimes(thread, &mut c, &mut e, &mut k, &mut u).is_ok() {
+                            created = filetime_to_u64(c);
+                            kernel = filetime_to_u64(k);
+                            user = filetime_to_u64(u);
+                        }
+                        name = thread_description(thread);
+                        let _ = CloseHandle(thread);
+                    }
+                    let mut cpu_usage = match prev.and_then(|(_, s)| s.get(&tid)) {
+                        Some(&(c, t)) if c == created && elapsed > 0.0 => ((user + kernel).saturating_sub(t) as f64 / elapsed * 100.0).clamp(0.0, 100.0),
+                        _ => 0.0,
+                    };
+                    if mode == CpuUsageMode::Machine { cpu_usage /= cores; }
+                    samples.insert(tid, (created, user + kernel));
+                    out.push(ThreadInfo {
+                        tid,
+                        name,
+                        state: ProcessState::Running,
+                        status: ProcessState::Running.label().to_string(),
+                        cpu_usage,
+                        user_time_ms: user / 10_000,
+                        system_time_ms: kernel / 10_000,
+                        last_cpu: None,
+                        priority: entry.tpBasePri,
+                        nice: 0,
+                        voluntary_context_switches: 0,
+                        involuntary_context_switches: 0,
+                    });
+                }
+                if Thread32Next(snapshot, &mut entry).is_err() { break; }
+            }
+        }
+        let _ = CloseHandle(snapshot);
+    }
+    cache.insert(pid, (now, samples));
+    Ok(out)
+}
+
+/// 枚举其他进程的句柄需要未公开的 NtQuerySystemInformation，暂不支持
+pub fn get_open_files_impl(_pid: u32) -> Result<Vec<OpenFileInfo>, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "listing open files is not supported on this platform"))
+}
+
+/// 同样依赖句柄枚举（或 Restart Manager），暂不支持
+pub fn find_processes_using_impl(_path: String) -> Result<Vec<PathUsage>, ProcessControlError> {
+    Err(ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "finding processes using a path is not supported on this platform"))
+}
+
+/// Windows实现：进程详情。可执行文件路径来自 QueryFullProcessImageNameW；
+/// 工作目录、命令行参数与环境变量位于目标进程的 PEB 中，需要读取其内存，暂不支持
+pub fn get_process_details_impl(pid: u32) -> Result<ProcessDetails, ProcessControlError> {
+    let mut errors = Vec::new();
+    let exe = match open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SYNCHRONIZE) {
+        Ok(handle) => {
+            let mut buf = [0u16; 32768];
+            let mut len = buf.len() as u32;
+            let result = unsafe { QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, windows::core::PWSTR(buf.as_mut_ptr()), &mut len) };
+            unsafe { let _ = CloseHandle(handle); }
+            match result {
+                Ok(()) => Some(OsString::from_wide(&buf[..len as usize]).to_string_lossy().into_owned()),
+                Err(e) => {
+                    errors.push(ProcessDetailError { field: ProcessDetailField::Executable, error: win_error(pid, e) });
+                    None
+                }
+            }
+        }
+        // 进程不存在时整个调用失败，其他错误（如权限不足）只影响该字段
+        Err(e) if e.kind == ProcessControlErrorKind::NoSuchProcess => return Err(e),
+        Err(e) => {
+            errors.push(ProcessDetailError { field: ProcessDetailField::Executable, error: e });
+            None
+        }
+    };
+    // Windows 没有进程根目录的概念，root 保持为 None
+    for field in [ProcessDetailField::WorkingDirectory, ProcessDetailField::CommandLine, ProcessDetailField::Environment] {
+        errors.push(ProcessDetailError { field, error: ProcessControlError::new(ProcessControlErrorKind::Unsupported, 0, "not available on Windows") });
+    }
+    Ok(ProcessDetails { pid, exe, exe_deleted: false, cwd: None, root: None, argv: None, environment: None, errors })
+}
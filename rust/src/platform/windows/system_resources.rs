@@ -1,4 +1,8 @@
-use crate::api::simple::{DiskInfo, NetworkInfo, SystemResourceInfo};
+use crate::api::simple::{
+    CgroupInfo, CpuTimesBreakdown, DiskActivity, DiskInfo, MountFilter, NetworkInfo, NetworkInterfaceInfo,
+    SystemResourceInfo,
+};
+use std::collections::HashMap;
 use std::mem;
 use std::time::{Duration, Instant};
 use std::sync::Mutex;
@@ -21,11 +25,11 @@ pub fn get_system_resources_impl() -> SystemResourceInfo {
         };
         let _ = GlobalMemoryStatusEx(&mut mem_status);
 
-        // 获取CPU使用率（简化实现）
-        let cpu_usage = get_cpu_usage();
+        // 获取CPU使用率及各类时间占比
+        let (cpu_usage, cpu_per_core, cpu_times, cpu_times_per_core) = sample_cpu_usage();
 
         // 获取磁盘信息
-        let disk_usage = get_disk_info();
+        let disk_usage = get_disk_info(&MountFilter::default());
 
         // 获取网络信息
         let network_usage = get_network_info();
@@ -75,10 +79,11 @@ pub fn get_system_resources_impl() -> SystemResourceInfo {
             swap_free = swap_total.saturating_sub(swap_used);
         }
 
-        let cpu_per_core = get_per_core_usage();
         SystemResourceInfo {
             cpu_usage,
             cpu_per_core,
+            cpu_times,
+            cpu_times_per_core,
             memory_total: mem_status.ullTotalPhys,
             memory_used: mem_status.ullTotalPhys - mem_status.ullAvailPhys,
             memory_available: mem_status.ullAvailPhys,
@@ -129,6 +134,8 @@ struct CpuSampleCache {
     last_per_core: Vec<SystemProcessorPerformanceInformation>,
     last_total_pct: f64,
     last_per_core_pct: Vec<f64>,
+    last_times: CpuTimesBreakdown,
+    last_per_core_times: Vec<CpuTimesBreakdown>,
 }
 
 lazy_static::lazy_static! {
@@ -173,49 +180,76 @@ fn query_per_core_raw() -> Option<Vec<SystemProcessorPerformanceInformation>> {
     }
 }
 
-fn get_cpu_usage() -> f64 {
-    let (total, _) = sample_cpu_usage();
-    total
+// 将一个核心的时间增量映射到 CpuTimesBreakdown。
+// KernelTime 包含 IdleTime，DPC 与中断时间也计入 KernelTime，需逐项扣除。
+fn breakdown_from_delta(idle: f64, kernel: f64, user: f64, dpc: f64, interrupt: f64) -> CpuTimesBreakdown {
+    let total = kernel + user;
+    if total <= 0.0 {
+        return CpuTimesBreakdown::default();
+    }
+    let pct = |v: f64| (v / total * 100.0).clamp(0.0, 100.0);
+    CpuTimesBreakdown {
+        user: pct(user),
+        system: pct((kernel - idle - dpc - interrupt).max(0.0)),
+        idle: pct(idle),
+        irq: pct(interrupt),
+        softirq: pct(dpc),
+        ..Default::default()
+    }
 }
 
-fn get_per_core_usage() -> Vec<f64> {
-    let (_, per_core) = sample_cpu_usage();
-    per_core
-}
+type CpuSample = (f64, Vec<f64>, CpuTimesBreakdown, Vec<CpuTimesBreakdown>);
 
-fn sample_cpu_usage() -> (f64, Vec<f64>) {
+fn sample_cpu_usage() -> CpuSample {
     const MIN_INTERVAL: Duration = Duration::from_millis(400); // 避免过于频繁
     let mut cache = CPU_CACHE.lock().unwrap();
     let now = Instant::now();
 
     if let Some(ref existing) = *cache {
         if now.duration_since(existing.last_instant) < MIN_INTERVAL {
-            return (existing.last_total_pct, existing.last_per_core_pct.clone());
+            return (
+                existing.last_total_pct,
+                existing.last_per_core_pct.clone(),
+                existing.last_times.clone(),
+                existing.last_per_core_times.clone(),
+            );
         }
     }
 
     let cur_raw = match query_per_core_raw() {
         Some(v) => v,
-        None => return (0.0, Vec::new()),
+        None => return (0.0, Vec::new(), CpuTimesBreakdown::default(), Vec::new()),
     };
 
     if let Some(prev_cache) = cache.as_ref() {
         let prev_raw = &prev_cache.last_per_core;
         let len = cur_raw.len().min(prev_raw.len());
-        if len == 0 { return (0.0, Vec::new()); }
+        if len == 0 { return (0.0, Vec::new(), CpuTimesBreakdown::default(), Vec::new()); }
         let mut per_core = Vec::with_capacity(len);
+        let mut per_core_times = Vec::with_capacity(len);
         let mut active_sum = 0.0;
         let mut total_sum = 0.0;
+        // 汇总各核心的增量，用于计算整机的时间占比
+        let (mut idle_sum, mut kernel_sum, mut user_sum, mut dpc_sum, mut interrupt_sum) = (0.0, 0.0, 0.0, 0.0, 0.0);
         for i in 0..len {
             let prev = &prev_raw[i];
             let cur = &cur_raw[i];
             let idle_delta = (cur.idle_time - prev.idle_time) as f64;
             let kernel_delta = (cur.kernel_time - prev.kernel_time) as f64;
             let user_delta = (cur.user_time - prev.user_time) as f64;
+            let dpc_delta = ((cur.dpc_time - prev.dpc_time) as f64).max(0.0);
+            let interrupt_delta = ((cur.interrupt_time - prev.interrupt_time) as f64).max(0.0);
             if idle_delta < 0.0 || kernel_delta < 0.0 || user_delta < 0.0 {
                 per_core.push(0.0);
+                per_core_times.push(CpuTimesBreakdown::default());
                 continue;
             }
+            per_core_times.push(breakdown_from_delta(idle_delta, kernel_delta, user_delta, dpc_delta, interrupt_delta));
+            idle_sum += idle_delta;
+            kernel_sum += kernel_delta;
+            user_sum += user_delta;
+            dpc_sum += dpc_delta;
+            interrupt_sum += interrupt_delta;
             let active = (kernel_delta - idle_delta).max(0.0) + user_delta;
             let total = active + idle_delta;
             if total > 0.0 {
@@ -228,18 +262,37 @@ fn sample_cpu_usage() -> (f64, Vec<f64>) {
             }
         }
         let total_pct = if total_sum > 0.0 { (active_sum / total_sum * 100.0).clamp(0.0, 100.0) } else { 0.0 };
+        let times = breakdown_from_delta(idle_sum, kernel_sum, user_sum, dpc_sum, interrupt_sum);
         // 更新缓存
-        *cache = Some(CpuSampleCache { last_instant: now, last_per_core: cur_raw, last_total_pct: total_pct, last_per_core_pct: per_core.clone() });
-        (total_pct, per_core)
+        *cache = Some(CpuSampleCache {
+            last_instant: now,
+            last_per_core: cur_raw,
+            last_total_pct: total_pct,
+            last_per_core_pct: per_core.clone(),
+            last_times: times.clone(),
+            last_per_core_times: per_core_times.clone(),
+        });
+        (total_pct, per_core, times, per_core_times)
     } else {
         // 首次采样，无法计算 delta，存缓存返回 0
-        *cache = Some(CpuSampleCache { last_instant: now, last_per_core: cur_raw, last_total_pct: 0.0, last_per_core_pct: Vec::new() });
-        (0.0, Vec::new())
+        *cache = Some(CpuSampleCache {
+            last_instant: now,
+            last_per_core: cur_raw,
+            last_total_pct: 0.0,
+            last_per_core_pct: Vec::new(),
+            last_times: CpuTimesBreakdown::default(),
+            last_per_core_times: Vec::new(),
+        });
+        (0.0, Vec::new(), CpuTimesBreakdown::default(), Vec::new())
     }
 }
 
+// FILE_READ_ONLY_VOLUME (winnt.h)，未启用 SystemServices feature，这里直接定义
+const FILE_READ_ONLY_VOLUME: u32 = 0x0008_0000;
+
 /// 获取磁盘信息
-fn get_disk_info() -> Vec<DiskInfo> {
+/// Windows 盘符不存在伪文件系统和绑定挂载，过滤条件无需处理
+fn get_disk_info(_filter: &MountFilter) -> Vec<DiskInfo> {
     let mut disks = Vec::new();
 
     unsafe {
@@ -266,12 +319,40 @@ fn get_disk_info() -> Vec<DiskInfo> {
                 )
                 .is_ok()
                 {
+                    // 文件系统名称与只读标志
+                    let mut fs_name_buf = [0u16; 64];
+                    let mut fs_flags = 0u32;
+                    let fs_type = if GetVolumeInformationW(
+                        PCWSTR(drive_path_wide.as_ptr()),
+                        None,
+                        None,
+                        None,
+                        Some(&mut fs_flags),
+                        Some(&mut fs_name_buf),
+                    )
+                    .is_ok()
+                    {
+                        let len = fs_name_buf.iter().position(|&c| c == 0).unwrap_or(fs_name_buf.len());
+                        String::from_utf16_lossy(&fs_name_buf[..len])
+                    } else {
+                        String::new()
+                    };
+                    let read_only = fs_flags & FILE_READ_ONLY_VOLUME != 0;
+
                     disks.push(DiskInfo {
                         name: drive_letter.clone(),
                         mount_point: drive_path,
                         total_space: total_bytes,
                         used_space: total_bytes - free_bytes,
                         available_space: free_bytes,
+                        fs_type,
+                        device: drive_letter.clone(),
+                        mount_options: vec![if read_only { "ro" } else { "rw" }.to_string()],
+                        read_only,
+                        // NTFS 没有固定的 inode 表
+                        inodes_total: 0,
+                        inodes_used: 0,
+                        inodes_free: 0,
                     });
                 }
             }
@@ -281,6 +362,17 @@ fn get_disk_info() -> Vec<DiskInfo> {
     disks
 }
 
+/// Windows实现：按过滤条件获取磁盘列表
+pub fn get_disks_impl(filter: MountFilter) -> Vec<DiskInfo> {
+    get_disk_info(&filter)
+}
+
+/// Windows实现：获取磁盘活动
+pub fn get_disk_activity_impl() -> Vec<DiskActivity> {
+    // 磁盘活动需要 IOCTL_DISK_PERFORMANCE 或 PDH 计数器，暂未实现
+    Vec::new()
+}
+
 /// 获取网络信息
 fn get_network_info() -> NetworkInfo {
     let mut network_info = NetworkInfo {
@@ -312,4 +404,119 @@ fn get_network_info() -> NetworkInfo {
     network_info
 }
 
+// IF_TYPE_* (ipifcons.h)
+const IF_TYPE_ETHERNET_CSMACD: u32 = 6;
+const IF_TYPE_SOFTWARE_LOOPBACK: u32 = 24;
+const IF_TYPE_IEEE80211: u32 = 71;
+const IF_TYPE_TUNNEL: u32 = 131;
+
+// 上一次各接口的收发字节数，用于计算速率 (按 InterfaceIndex 索引)
+struct NetRateCache {
+    last_instant: Instant,
+    last_bytes: HashMap<u32, (u64, u64)>,
+}
+
+lazy_static::lazy_static! {
+    static ref NET_RATE_CACHE: Mutex<Option<NetRateCache>> = Mutex::new(None);
+}
+
+fn oper_status_name(status: IF_OPER_STATUS) -> String {
+    match status.0 {
+        1 => "up",
+        2 => "down",
+        3 => "testing",
+        5 => "dormant",
+        6 => "notpresent",
+        7 => "lowerlayerdown",
+        _ => "unknown",
+    }
+    .to_string()
+}
+
+fn wide_to_string(buf: &[u16]) -> String {
+    let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    String::from_utf16_lossy(&buf[..len])
+}
+
+/// Windows实现：cgroup 为 Linux 特有（Job Object 不构成可遍历的层级）
+pub fn get_cgroup_tree_impl() -> Vec<CgroupInfo> {
+    Vec::new()
+}
+
+/// Windows实现：获取各网络接口统计
+/// IP 地址需要 GetAdaptersAddresses (依赖 WinSock feature)，暂不填充
+pub fn get_network_interfaces_impl() -> Vec<NetworkInterfaceInfo> {
+    let mut out = Vec::new();
+    let mut current = HashMap::new();
+    let now = Instant::now();
+    let mut cache = NET_RATE_CACHE.lock().unwrap();
+    let prev = cache.as_ref();
+    let elapsed = prev.map(|c| now.duration_since(c.last_instant).as_secs_f64()).unwrap_or(0.0);
+    let rate = |cur: u64, last: u64| if elapsed > 0.0 { cur.saturating_sub(last) as f64 / elapsed } else { 0.0 };
+
+    unsafe {
+        let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
+        if GetIfTable2(&mut table).is_ok() {
+            let num_entries = (*table).NumEntries;
+            let entries = std::slice::from_raw_parts((*table).Table.as_ptr(), num_entries as usize);
+
+            for entry in entries {
+                // HardwareInterface 是 InterfaceAndOperStatusFlags 的第 0 位
+                let hardware = entry.InterfaceAndOperStatusFlags._bitfield & 1 != 0;
+                let is_loopback = entry.Type == IF_TYPE_SOFTWARE_LOOPBACK;
+                let kind = match entry.Type {
+                    IF_TYPE_SOFTWARE_LOOPBACK => "loopback",
+                    IF_TYPE_IEEE80211 => "wireless",
+                    IF_TYPE_TUNNEL => "tun",
+                    IF_TYPE_ETHERNET_CSMACD if hardware => "ethernet",
+                    _ => "virtual",
+                };
+                let mac_len = (entry.PhysicalAddressLength as usize).min(entry.PhysicalAddress.len());
+                let mac_address = entry.PhysicalAddress[..mac_len]
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<Vec<_>>()
+                    .join(":");
+                let (last_rx, last_tx) = prev
+                    .and_then(|c| c.last_bytes.get(&entry.InterfaceIndex))
+                    .copied()
+                    .unwrap_or((entry.InOctets, entry.OutOctets));
+                current.insert(entry.InterfaceIndex, (entry.InOctets, entry.OutOctets));
+
+                out.push(NetworkInterfaceInfo {
+                    name: wide_to_string(&entry.Alias),
+                    kind: kind.to_string(),
+                    is_loopback,
+                    is_virtual: !hardware,
+                    operstate: oper_status_name(entry.OperStatus),
+                    // TransmitLinkSpeed 单位为 bit/s
+                    link_speed_mbps: if entry.TransmitLinkSpeed > 0 && entry.TransmitLinkSpeed != u64::MAX {
+                        Some(entry.TransmitLinkSpeed / 1_000_000)
+                    } else {
+                        None
+                    },
+                    mtu: entry.Mtu,
+                    mac_address,
+                    ipv4_addresses: Vec::new(),
+                    ipv6_addresses: Vec::new(),
+                    bytes_received: entry.InOctets,
+                    bytes_sent: entry.OutOctets,
+                    packets_received: entry.InUcastPkts + entry.InNUcastPkts,
+                    packets_sent: entry.OutUcastPkts + entry.OutNUcastPkts,
+                    receive_errors: entry.InErrors,
+                    send_errors: entry.OutErrors,
+                    receive_dropped: entry.InDiscards,
+                    send_dropped: entry.OutDiscards,
+                    receive_bytes_per_sec: rate(entry.InOctets, last_rx),
+                    send_bytes_per_sec: rate(entry.OutOctets, last_tx),
+                });
+            }
+            FreeMibTable(table as *const _);
+        }
+    }
+
+    *cache = Some(NetRateCache { last_instant: now, last_bytes: current });
+    out
+}
+
 // ===== 结束 CPU 采集实现 =====